@@ -0,0 +1,186 @@
+//! The cache of the files that are known to be formatted.
+//!
+//! Each file of the cache is stored with a key computed from its content, its path, the syntax
+//! it's parsed with, the options of the formatter, and the version and the build of Rome. The commands skip a file when its
+//! current key matches the cached one, since formatting it wouldn't change it.
+//!
+//! There's one cache file per working directory, inside the directory given by the
+//! `ROME_CACHE_DIR` environment variable, or else inside the cache directory of the user.
+use rome_core::fs::FileSystem;
+use rome_core::normalize;
+use rome_formatter::FormatOptions;
+use rslint_parser::Syntax;
+use std::collections::HashMap;
+use std::env;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The start of the first line of a cache file, followed by the build the cache belongs to
+const HEADER: &str = "rome format cache";
+
+pub(crate) struct Cache {
+	path: PathBuf,
+	/// See [build_id]
+	build_id: u64,
+	/// The key of each formatted file, by normalized path
+	entries: HashMap<PathBuf, u64>,
+	/// Whether the entries changed since the cache was loaded
+	changed: bool,
+}
+
+impl Cache {
+	/// Loads the cache of the files found from the working directory.
+	///
+	/// The cache is empty if its file doesn't exist, can't be read, or was written by another
+	/// build of Rome. Returns `None` if there's nowhere to store the cache.
+	pub(crate) fn load(fs: &dyn FileSystem) -> Option<Self> {
+		let path = cache_path()?;
+		let build_id = build_id();
+		let content = fs.read_to_string(&path).unwrap_or_default();
+		let mut lines = content.lines();
+
+		let header = format!("{} {:016x}", HEADER, build_id);
+		let entries = if lines.next() == Some(header.as_str()) {
+			lines.filter_map(parse_entry).collect()
+		} else {
+			HashMap::new()
+		};
+
+		Some(Self {
+			path,
+			build_id,
+			entries,
+			changed: false,
+		})
+	}
+
+	/// Computes the key of a file, from everything that decides how it's formatted.
+	///
+	/// `syntax` is the syntax the file is parsed with, since it can change without the file
+	/// changing, for example when the `"type"` of the nearest `package.json` changes.
+	pub(crate) fn key(
+		&self,
+		path: &Path,
+		source: &str,
+		syntax: Option<Syntax>,
+		options: &FormatOptions,
+	) -> u64 {
+		let mut hasher = StableHasher::default();
+		self.build_id.hash(&mut hasher);
+		normalize(path).hash(&mut hasher);
+		syntax.hash(&mut hasher);
+		options.hash(&mut hasher);
+		source.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	/// Returns `true` if the file at `path` was formatted when its key was `key`
+	pub(crate) fn is_formatted(&self, path: &Path, key: u64) -> bool {
+		self.entries.get(&normalize(path)) == Some(&key)
+	}
+
+	/// Records the key of a formatted file, or removes the file from the cache if `key` is `None`
+	pub(crate) fn update(&mut self, path: &Path, key: Option<u64>) {
+		let path = normalize(path);
+		let previous = match key {
+			Some(key) => self.entries.insert(path, key),
+			None => self.entries.remove(&path),
+		};
+		self.changed |= previous != key;
+	}
+
+	/// Writes the cache file, if the entries changed since the cache was loaded or saved
+	pub(crate) fn save(&mut self, fs: &dyn FileSystem) -> io::Result<()> {
+		if !self.changed {
+			return Ok(());
+		}
+
+		// The entries are sorted so that the file doesn't change when the entries don't
+		let mut entries: Vec<_> = self.entries.iter().collect();
+		entries.sort();
+
+		let mut content = format!("{} {:016x}\n", HEADER, self.build_id);
+		for (path, key) in entries {
+			content.push_str(&format!("{:016x} {}\n", key, path.display()));
+		}
+
+		if let Some(directory) = self.path.parent() {
+			fs.create_dir_all(directory)?;
+		}
+		fs.write(&self.path, &content)?;
+		self.changed = false;
+		Ok(())
+	}
+}
+
+/// The path of the cache file of the working directory
+pub(crate) fn cache_path() -> Option<PathBuf> {
+	let current_dir = env::current_dir().ok()?;
+	let mut hasher = StableHasher::default();
+	current_dir.hash(&mut hasher);
+
+	Some(cache_dir()?.join(format!("{:016x}", hasher.finish())))
+}
+
+/// The directory of the cache files: `ROME_CACHE_DIR`, or else the `rome` directory inside the
+/// cache directory of the user, or inside the temporary directory
+fn cache_dir() -> Option<PathBuf> {
+	if let Some(directory) = env::var_os("ROME_CACHE_DIR") {
+		return Some(PathBuf::from(directory));
+	}
+
+	env::var_os("XDG_CACHE_HOME")
+		.map(PathBuf::from)
+		.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+		.filter(|directory| directory.is_absolute())
+		.or_else(|| Some(env::temp_dir()))
+		.map(|directory| directory.join("rome"))
+}
+
+/// Identifies the build of Rome, so that another build doesn't reuse the cache, even when it has
+/// the same version
+fn build_id() -> u64 {
+	let mut hasher = StableHasher::default();
+	env!("CARGO_PKG_VERSION").hash(&mut hasher);
+
+	let executable = env::current_exe().and_then(|path| path.metadata());
+	if let Ok(metadata) = executable {
+		metadata.len().hash(&mut hasher);
+		if let Ok(modified) = metadata.modified() {
+			modified.hash(&mut hasher);
+		}
+	}
+
+	hasher.finish()
+}
+
+/// Parses a line of the cache file: the key and the path of a file
+fn parse_entry(line: &str) -> Option<(PathBuf, u64)> {
+	let (key, path) = line.split_once(' ')?;
+	let key = u64::from_str_radix(key, 16).ok()?;
+	Some((PathBuf::from(path), key))
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hasher of the standard library is guaranteed to give
+/// the same hashes from one build to another
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+	fn default() -> Self {
+		Self(0xcbf2_9ce4_8422_2325)
+	}
+}
+
+impl Hasher for StableHasher {
+	fn finish(&self) -> u64 {
+		self.0
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.0 ^= u64::from(*byte);
+			self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+}
@@ -0,0 +1,45 @@
+use clap::ArgMatches;
+use rome_core::App;
+use std::io;
+
+use crate::cache::cache_path;
+use crate::ExitCode;
+
+/// Handler for the "cache" command of the Rome CLI
+pub(crate) fn cache(matches: &ArgMatches, app: &App) -> Result<(), ExitCode> {
+	match matches.subcommand() {
+		Some(("clean", _)) => clean(app),
+		// Thanks to the settings AppSettings::SubcommandRequiredElseHelp we should not be there
+		_ => clap::Error::with_description(
+			"Sub command not found".to_string(),
+			clap::ErrorKind::InvalidSubcommand,
+		)
+		.exit(),
+	}
+}
+
+/// Deletes the cache of the working directory
+fn clean(app: &App) -> Result<(), ExitCode> {
+	let path = match cache_path() {
+		Some(path) => path,
+		None => {
+			println!("There's no cache to remove");
+			return Ok(());
+		}
+	};
+
+	match app.fs().remove_file(&path) {
+		Ok(()) => {
+			println!("Removed the cache at {}", path.display());
+			Ok(())
+		}
+		Err(err) if err.kind() == io::ErrorKind::NotFound => {
+			println!("There's no cache to remove");
+			Ok(())
+		}
+		Err(err) => {
+			eprintln!("Failed to remove {}: {}", path.display(), err);
+			Err(ExitCode::InternalError)
+		}
+	}
+}
@@ -0,0 +1,15 @@
+use clap::ArgMatches;
+use rome_core::App;
+
+use crate::commands::format::format_inputs;
+use crate::reporter::Reporter;
+use crate::ExitCode;
+
+/// Handler for the "check" command of the Rome CLI
+pub(crate) fn check(
+	matches: &ArgMatches,
+	app: &App,
+	reporter: &mut Reporter,
+) -> Result<(), ExitCode> {
+	format_inputs(matches, app, reporter, true)
+}
@@ -0,0 +1,59 @@
+use clap::ArgMatches;
+use rome_core::traversal::ignore_reason;
+use rome_core::App;
+use rome_formatter::{FormatOptions, IndentStyle};
+use std::path::Path;
+
+/// Handler for the "explain-config" command of the Rome CLI
+pub(crate) fn explain_config(matches: &ArgMatches, app: &App) {
+	let path = Path::new(matches.value_of("path").unwrap());
+	let settings = app.file_settings(path);
+	let options = FormatOptions::from(&settings.formatter);
+
+	println!("File: {}", path.display());
+	match app.configuration_path() {
+		Some(configuration_path) => println!("Configuration: {}", configuration_path.display()),
+		None => println!("Configuration: none, using the defaults"),
+	}
+	println!("Language: {:?}", settings.language);
+	// The same reason as the one printed when the file is skipped
+	match ignore_reason(app, path) {
+		Some(reason) => println!("Ignored: yes, {}", reason),
+		None => println!("Ignored: no"),
+	}
+	println!("Format: {}", yes_no(settings.capabilities.format));
+	println!("Lint: {}", yes_no(settings.capabilities.lint));
+
+	println!("Formatter:");
+	match options.indent_style {
+		IndentStyle::Tab => println!("  indentStyle: tab"),
+		IndentStyle::Space(size) => {
+			println!("  indentStyle: space");
+			println!("  indentSize: {}", size);
+		}
+	}
+	println!("  lineWidth: {}", options.line_width);
+
+	if settings.overrides.is_empty() {
+		println!("Overrides: none");
+	} else {
+		println!("Overrides:");
+		for index in settings.overrides {
+			let include = app.configuration().overrides[index]
+				.include
+				.iter()
+				.map(|pattern| format!("\"{}\"", pattern))
+				.collect::<Vec<_>>()
+				.join(", ");
+			println!("  overrides[{}], include: [{}]", index, include);
+		}
+	}
+}
+
+fn yes_no(value: bool) -> &'static str {
+	if value {
+		"yes"
+	} else {
+		"no"
+	}
+}
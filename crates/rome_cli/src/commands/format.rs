@@ -0,0 +1,665 @@
+use clap::ArgMatches;
+use rayon::prelude::*;
+use rome_core::configuration::{
+	FileSettings, FormatterConfiguration, IndentKind, OverrideConfiguration,
+};
+use rome_core::traversal::{collect_files, CollectedFiles, SkipReason, TraversalError};
+use rome_core::watch::{WatchError, Watcher};
+use rome_core::{normalize, App};
+use rome_formatter::{format_source_lenient, FormatError, FormatOptions, Formatted};
+use rome_path::RomePath;
+use rslint_errors::file::FileId;
+use rslint_errors::{Applicability, Diagnostic, Severity};
+use rslint_parser::Syntax;
+use similar::TextDiff;
+use std::collections::HashSet;
+use std::env;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::cache::Cache;
+use crate::git::Repository;
+use crate::reporter::Reporter;
+use crate::ExitCode;
+
+/// The outcome of formatting a single file
+enum FormatStatus {
+	/// The formatted code differs from the original one and the file was written
+	Changed,
+	/// The formatted code differs from the original one, but the file wasn't written because
+	/// the command runs in check mode
+	Unformatted { source: String, formatted: String },
+	/// The file was already formatted
+	Unchanged,
+	/// The file was already formatted according to the cache, so it wasn't formatted again
+	Cached,
+	/// The file couldn't be formatted. Stores the content of the file, which is empty if it
+	/// couldn't be read.
+	Failed(FormatError, String),
+}
+
+/// The warnings of a file that was formatted despite its syntax errors, with the content of the
+/// file they point to
+struct FormatWarnings {
+	diagnostics: Vec<Diagnostic>,
+	source: String,
+}
+
+impl FormatWarnings {
+	/// The parts of the file that contain syntax errors are printed as they are, so the syntax
+	/// errors are reported as warnings along with the parts that were left untouched
+	fn new(formatted: &Formatted, source: &str) -> Option<Self> {
+		if formatted.diagnostics().is_empty() {
+			return None;
+		}
+
+		let diagnostics = formatted
+			.diagnostics()
+			.iter()
+			.cloned()
+			.map(|mut diagnostic| {
+				diagnostic.severity = Severity::Warning;
+				diagnostic
+			})
+			.collect();
+
+		Some(Self {
+			diagnostics,
+			source: source.to_string(),
+		})
+	}
+}
+
+/// Counts how many files ended up in each [FormatStatus]
+#[derive(Debug, Default)]
+struct FormatSummary {
+	changed: usize,
+	unchanged: usize,
+	failed: usize,
+	/// How many of the failed files couldn't be formatted for another reason than syntax errors
+	internal_errors: usize,
+	/// How many files contain syntax errors, including the ones formatted around their errors
+	syntax_errors: usize,
+	/// The files that were written
+	written: Vec<PathBuf>,
+}
+
+impl FormatSummary {
+	fn total(&self) -> usize {
+		self.changed + self.unchanged + self.failed
+	}
+
+	fn print(&self, is_check: bool) {
+		if is_check {
+			println!(
+				"Checked {} files: {} unformatted, {} formatted, {} failed",
+				self.total(),
+				self.changed,
+				self.unchanged,
+				self.failed
+			);
+		} else {
+			println!(
+				"Processed {} files: {} changed, {} unchanged, {} failed",
+				self.total(),
+				self.changed,
+				self.unchanged,
+				self.failed
+			);
+		}
+	}
+
+	/// The most severe outcome decides the exit code
+	fn exit_code(&self, is_check: bool) -> Result<(), ExitCode> {
+		if self.internal_errors > 0 {
+			Err(ExitCode::InternalError)
+		} else if self.syntax_errors > 0 {
+			Err(ExitCode::SyntaxErrors)
+		} else if is_check && self.changed > 0 {
+			Err(ExitCode::Unformatted)
+		} else {
+			Ok(())
+		}
+	}
+}
+
+/// The arguments shared by the commands that format files
+struct FormatCommand {
+	overrides: FormatterConfiguration,
+	is_check: bool,
+	is_verbose: bool,
+	/// The files known to be formatted, `None` with `--no-cache`
+	cache: Option<Cache>,
+}
+
+/// Handler for the "format" command of the Rome CLI
+pub(crate) fn format(
+	matches: &ArgMatches,
+	app: &App,
+	reporter: &mut Reporter,
+) -> Result<(), ExitCode> {
+	if matches.is_present("stdin") {
+		let stdin_file_path = matches.value_of("stdin_file_path").unwrap();
+		return format_stdin(app, stdin_file_path, &cli_configuration(matches), reporter);
+	}
+
+	format_inputs(matches, app, reporter, matches.is_present("check"))
+}
+
+/// Formats the inputs of the command, or only checks them if `is_check` is `true`.
+///
+/// With `--watch`, keeps processing the files that change until the process is stopped. With
+/// `--changed` or `--staged`, only the files that git lists are processed, among the ones found
+/// from the inputs.
+pub(crate) fn format_inputs(
+	matches: &ArgMatches,
+	app: &App,
+	reporter: &mut Reporter,
+	is_check: bool,
+) -> Result<(), ExitCode> {
+	let mut command = FormatCommand {
+		overrides: cli_configuration(matches),
+		is_check,
+		is_verbose: matches.is_present("verbose"),
+		cache: if matches.is_present("no_cache") {
+			None
+		} else {
+			Cache::load(app.fs())
+		},
+	};
+	let inputs: Vec<&str> = matches
+		.values_of("input")
+		.map_or_else(|| vec!["."], |inputs| inputs.collect());
+	let git_files = git_files(matches)?;
+
+	if matches.is_present("watch") {
+		let (watcher, collected) = match Watcher::new(app, inputs) {
+			Ok(result) => result,
+			Err(WatchError::Traversal(err)) => invalid_inputs(err),
+			Err(err) => {
+				eprintln!("{}", err);
+				return Err(ExitCode::InternalError);
+			}
+		};
+		let summary = format_files(app, collected, &mut command, reporter);
+		watch(app, watcher, summary, &mut command, reporter);
+	}
+
+	let mut collected = match collect_files(app, inputs) {
+		Ok(collected) => collected,
+		Err(err) => invalid_inputs(err),
+	};
+	if let Some(git_files) = &git_files {
+		retain_git_files(&mut collected, git_files);
+	}
+
+	format_files(app, collected, &mut command, reporter).exit_code(is_check)
+}
+
+/// Returns the files listed by git with `--changed` or `--staged`, by absolute path
+fn git_files(matches: &ArgMatches) -> Result<Option<HashSet<PathBuf>>, ExitCode> {
+	let files = if matches.is_present("staged") {
+		Repository::open()
+			.and_then(|repository| repository.staged_files())
+			.map(|files| files.into_iter().map(|(path, _)| path).collect())
+	} else if matches.is_present("changed") {
+		Repository::open()
+			.and_then(|repository| repository.changed_files(matches.value_of("changed")))
+			.map(|files| files.into_iter().collect())
+	} else {
+		return Ok(None);
+	};
+
+	match files {
+		Ok(files) => Ok(Some(files)),
+		Err(err) => {
+			eprintln!("Failed to list the files from git: {}", err);
+			Err(ExitCode::InvalidUsage)
+		}
+	}
+}
+
+/// Keeps the files listed by git, and the skipped paths that contain any of them
+fn retain_git_files(collected: &mut CollectedFiles, git_files: &HashSet<PathBuf>) {
+	let current_dir = env::current_dir().unwrap_or_default();
+	let absolute = |path: &Path| normalize(&current_dir.join(path));
+
+	collected
+		.files
+		.retain(|path| git_files.contains(&absolute(path)));
+	collected.skipped.retain(|skipped| {
+		let path = absolute(&skipped.path);
+		git_files.iter().any(|file| file.starts_with(&path))
+	});
+}
+
+fn invalid_inputs(err: TraversalError) -> ! {
+	clap::Error::with_description(err.to_string(), clap::ErrorKind::ValueValidation).exit()
+}
+
+/// Processes the files that change, after the first run of the command, until the process is
+/// stopped. The diagnostics are reported after each batch of changes.
+fn watch(
+	app: &App,
+	mut watcher: Watcher,
+	mut summary: FormatSummary,
+	command: &mut FormatCommand,
+	reporter: &mut Reporter,
+) -> ! {
+	loop {
+		// Writing the files would otherwise count as a change
+		watcher.refresh(&summary.written);
+		if let Err(err) = reporter.flush() {
+			eprintln!("Failed to print the report: {}", err);
+		}
+		if reporter.is_pretty() {
+			println!("Watching for changes...");
+		}
+
+		summary = loop {
+			let changes = watcher.wait();
+			if command.is_verbose && reporter.is_pretty() {
+				for path in &changes.removed {
+					println!("Removed {}", path.display());
+				}
+			}
+
+			// The files that were only deleted don't need to be processed
+			if !changes.changed.is_empty() {
+				let collected = CollectedFiles {
+					files: changes.changed,
+					skipped: vec![],
+				};
+				break format_files(app, collected, command, reporter);
+			}
+		};
+	}
+}
+
+/// Formats the files, prints the progress and reports the diagnostics.
+///
+/// The files that the cache knows to be formatted are reported as unchanged without being
+/// formatted again.
+fn format_files(
+	app: &App,
+	collected: CollectedFiles,
+	command: &mut FormatCommand,
+	reporter: &mut Reporter,
+) -> FormatSummary {
+	let mut skipped: Vec<(PathBuf, String)> = collected
+		.skipped
+		.into_iter()
+		.map(|skipped| (skipped.path, skipped.reason.to_string()))
+		.collect();
+	let mut files: Vec<(PathBuf, FileId, FileSettings)> = Vec::with_capacity(collected.files.len());
+
+	for path in collected.files {
+		let settings = app.file_settings(&path);
+		let rome_path = RomePath::new(path.clone()).deduce_handler(app);
+
+		match exclusion_reason(app, &rome_path, &settings) {
+			Some(reason) => skipped.push((path, reason)),
+			None => {
+				// The ids are given before formatting, so that the syntax errors point to the file
+				let file_id = reporter.workspace_mut().add(&path);
+				files.push((path, file_id, settings));
+			}
+		}
+	}
+
+	// The machine readable reports replace the progress printed to the standard output
+	let is_pretty = reporter.is_pretty();
+
+	if command.is_verbose && is_pretty {
+		skipped.sort();
+		for (path, reason) in skipped {
+			println!("Skipped {}: {}", path.display(), reason);
+		}
+	}
+
+	// The results are collected in the same order as the files, so the output doesn't depend
+	// on how the files are scheduled between the threads
+	let cache = command.cache.as_ref();
+	type FormatResult = (
+		PathBuf,
+		FileId,
+		FormatStatus,
+		Option<u64>,
+		Option<FormatWarnings>,
+	);
+	let results: Vec<FormatResult> = files
+		.into_par_iter()
+		.map(|(path, file_id, settings)| {
+			let mut rome_path = RomePath::new(path.clone())
+				.deduce_handler(app)
+				.with_file_id(file_id);
+			let options = format_options(&settings, &command.overrides);
+			let (status, key, warnings) =
+				format_path(&mut rome_path, options, command.is_check, cache);
+			(path, file_id, status, key, warnings)
+		})
+		.collect();
+
+	let mut summary = FormatSummary::default();
+
+	for (path, file_id, status, key, warnings) in results {
+		if let Some(cache) = &mut command.cache {
+			cache.update(&path, key);
+		}
+
+		match status {
+			FormatStatus::Changed => {
+				summary.changed += 1;
+				if is_pretty {
+					println!("Formatted {}", path.display());
+				}
+				summary.written.push(path);
+			}
+			FormatStatus::Unformatted { source, formatted } => {
+				summary.changed += 1;
+				if is_pretty {
+					println!("Unformatted {}", path.display());
+					print!("{}", unified_diff(&path, &source, &formatted));
+				} else {
+					let diagnostic = unformatted_diagnostic(file_id, &source, &formatted);
+					reporter.workspace_mut().set_source(file_id, source);
+					reporter.report(vec![diagnostic]);
+				}
+			}
+			FormatStatus::Unchanged => {
+				summary.unchanged += 1;
+				if is_pretty {
+					println!("Unchanged {}", path.display());
+				}
+			}
+			FormatStatus::Cached => {
+				summary.unchanged += 1;
+				if is_pretty && command.is_verbose {
+					println!("Unchanged {} (cached)", path.display());
+				} else if is_pretty {
+					println!("Unchanged {}", path.display());
+				}
+			}
+			FormatStatus::Failed(err, source) => {
+				summary.failed += 1;
+				if matches!(err, FormatError::SyntaxErrors(_)) {
+					summary.syntax_errors += 1;
+				} else {
+					summary.internal_errors += 1;
+				}
+				if is_pretty {
+					println!("Failed {}", path.display());
+				}
+				reporter.workspace_mut().set_source(file_id, source);
+				reporter.report(err.into_diagnostics(file_id));
+			}
+		}
+
+		if let Some(warnings) = warnings {
+			summary.syntax_errors += 1;
+			reporter
+				.workspace_mut()
+				.set_source(file_id, warnings.source);
+			reporter.report(warnings.diagnostics);
+		}
+	}
+
+	if is_pretty {
+		summary.print(command.is_check);
+	}
+
+	if let Some(cache) = &mut command.cache {
+		// The cache only makes the next runs faster, so the command doesn't fail without it
+		if let Err(err) = cache.save(app.fs()) {
+			eprintln!("Failed to write the cache: {}", err);
+		}
+	}
+
+	summary
+}
+
+/// Formats a file, and writes the formatted code back to disk unless `is_check` is `true`.
+///
+/// The parts of the file that contain syntax errors are left as they are, and returned as
+/// warnings. With a cache, also returns the cache key of the file when it ends up formatted
+/// without any warning, so that the warnings are reported again on the next runs.
+fn format_path(
+	rome_path: &mut RomePath,
+	options: FormatOptions,
+	is_check: bool,
+	cache: Option<&Cache>,
+) -> (FormatStatus, Option<u64>, Option<FormatWarnings>) {
+	let source = match rome_path.read_to_string() {
+		Ok(source) => source,
+		Err(err) => return (FormatStatus::Failed(err.into(), String::new()), None, None),
+	};
+
+	let key = cache.map(|cache| {
+		cache.key(
+			rome_path,
+			&source,
+			file_syntax(rome_path, &source),
+			&options,
+		)
+	});
+	if let (Some(cache), Some(key)) = (cache, key) {
+		if cache.is_formatted(rome_path, key) {
+			return (FormatStatus::Cached, Some(key), None);
+		}
+	}
+
+	let formatted = match format_source_lenient(rome_path, &source, options.clone()) {
+		Ok(formatted) => formatted,
+		Err(err) => return (FormatStatus::Failed(err, source), None, None),
+	};
+	let warnings = FormatWarnings::new(&formatted, &source);
+	let key = key.filter(|_| warnings.is_none());
+
+	if formatted.code() == &source {
+		(FormatStatus::Unchanged, key, warnings)
+	} else if is_check {
+		let status = FormatStatus::Unformatted {
+			formatted: formatted.code().clone(),
+			source,
+		};
+		(status, None, warnings)
+	} else {
+		match rome_path.save(formatted.code()) {
+			Ok(()) => {
+				let key = cache.filter(|_| warnings.is_none()).map(|cache| {
+					let syntax = file_syntax(rome_path, formatted.code());
+					cache.key(rome_path, formatted.code(), syntax, &options)
+				});
+				(FormatStatus::Changed, key, warnings)
+			}
+			Err(err) => (FormatStatus::Failed(err.into(), source), None, None),
+		}
+	}
+}
+
+/// Returns the syntax the file is parsed with, or `None` if it isn't parsed as JavaScript
+fn file_syntax(rome_path: &RomePath, source: &str) -> Option<Syntax> {
+	rome_path
+		.get_handler()
+		.and_then(|handler| handler.syntax(rome_path.fs(), rome_path, source))
+}
+
+/// Reads the settings of the formatter passed as arguments of the command, which take precedence
+/// over the ones of the configuration file
+fn cli_configuration(matches: &ArgMatches) -> FormatterConfiguration {
+	FormatterConfiguration {
+		indent_style: matches.value_of("indent_style").map(|style| match style {
+			"space" => IndentKind::Space,
+			_ => IndentKind::Tab,
+		}),
+		indent_size: matches
+			.value_of("indent_size")
+			.and_then(|size| size.parse().ok()),
+		line_width: matches
+			.value_of("line_width")
+			.and_then(|width| width.parse().ok()),
+	}
+}
+
+/// Returns the options used to format a file: the arguments of the command, then the
+/// configuration of the file, then the defaults of the formatter
+fn format_options(settings: &FileSettings, overrides: &FormatterConfiguration) -> FormatOptions {
+	FormatOptions::from(&settings.formatter.merge_with(overrides))
+}
+
+/// Returns why the configuration excludes the file from formatting, either entirely or by
+/// turning off the format capability of its handler.
+///
+/// The exclusion is read from the resolved settings of the file, the overrides are only looked
+/// up to name the one responsible. The files whose handler can't format them aren't excluded,
+/// so that they're reported as failures.
+fn exclusion_reason(app: &App, rome_path: &RomePath, settings: &FileSettings) -> Option<String> {
+	let handler_can_format = rome_path
+		.get_handler()
+		.map_or(false, |handler| handler.capabilities().format);
+	let overrides = &app.configuration().overrides;
+	// The last override that applies to the file is the one that decided its settings
+	let responsible_override = |is_responsible: fn(&OverrideConfiguration) -> bool| {
+		settings
+			.overrides
+			.iter()
+			.rev()
+			.copied()
+			.find(|index| is_responsible(&overrides[*index]))
+	};
+
+	if settings.ignored {
+		let reason = match responsible_override(|configuration| configuration.ignore) {
+			Some(index) => SkipReason::Override(index).to_string(),
+			None => String::from("ignored by the configuration"),
+		};
+		Some(reason)
+	} else if handler_can_format && !settings.capabilities.format {
+		let index =
+			responsible_override(|configuration| configuration.capabilities.format == Some(false));
+		let reason = match index {
+			Some(index) => format!(
+				"formatting is disabled by overrides[{}] of the configuration",
+				index
+			),
+			None => String::from("formatting is disabled by the configuration"),
+		};
+		Some(reason)
+	} else {
+		None
+	}
+}
+
+/// Formats the code read from the standard input and prints the result on the standard output.
+///
+/// `stdin_file_path` is a virtual path used to pick the handler of the code, nothing is read from
+/// or written to disk.
+fn format_stdin(
+	app: &App,
+	stdin_file_path: &str,
+	overrides: &FormatterConfiguration,
+	reporter: &mut Reporter,
+) -> Result<(), ExitCode> {
+	let file_id = reporter.workspace_mut().add(stdin_file_path);
+	let mut source = String::new();
+	if let Err(err) = io::stdin().read_to_string(&mut source) {
+		reporter.report(FormatError::from(err).into_diagnostics(file_id));
+		return Err(ExitCode::InternalError);
+	}
+
+	let rome_path = RomePath::new(stdin_file_path)
+		.deduce_handler(app)
+		.with_file_id(file_id);
+	let settings = app.file_settings(&rome_path);
+
+	// The code of the files excluded by the configuration is printed back untouched
+	if exclusion_reason(app, &rome_path, &settings).is_some() {
+		return write_stdout(&source);
+	}
+
+	let options = format_options(&settings, overrides);
+
+	match format_source_lenient(&rome_path, &source, options) {
+		Ok(formatted) => {
+			write_stdout(formatted.code())?;
+			match FormatWarnings::new(&formatted, &source) {
+				Some(warnings) => {
+					reporter
+						.workspace_mut()
+						.set_source(file_id, warnings.source);
+					reporter.report(warnings.diagnostics);
+					Err(ExitCode::SyntaxErrors)
+				}
+				None => Ok(()),
+			}
+		}
+		Err(err) => {
+			let exit_code = if matches!(err, FormatError::SyntaxErrors(_)) {
+				ExitCode::SyntaxErrors
+			} else {
+				ExitCode::InternalError
+			};
+			reporter.workspace_mut().set_source(file_id, source);
+			reporter.report(err.into_diagnostics(file_id));
+			Err(exit_code)
+		}
+	}
+}
+
+fn write_stdout(code: &str) -> Result<(), ExitCode> {
+	io::stdout().write_all(code.as_bytes()).map_err(|err| {
+		eprintln!("Failed to write to the standard output: {}", err);
+		ExitCode::InternalError
+	})
+}
+
+/// Returns the unified diff between the content of a file and its formatted code
+fn unified_diff(path: &Path, original: &str, formatted: &str) -> String {
+	let path = path.display().to_string();
+
+	TextDiff::from_lines(original, formatted)
+		.unified_diff()
+		.context_radius(3)
+		.header(&path, &path)
+		.to_string()
+}
+
+/// Creates the diagnostic reported by the machine readable reporters for a file that isn't
+/// formatted, with a suggestion for each group of changed lines
+fn unformatted_diagnostic(file_id: FileId, source: &str, formatted: &str) -> Diagnostic {
+	let source_lines = line_offsets(source);
+	let formatted_lines = line_offsets(formatted);
+	let mut diagnostic = Diagnostic::error(file_id, "Unformatted", "the file isn't formatted");
+
+	for group in TextDiff::from_lines(source, formatted).grouped_ops(0) {
+		let (first, last) = match (group.first(), group.last()) {
+			(Some(first), Some(last)) => (first, last),
+			_ => continue,
+		};
+		let range = source_lines[first.old_range().start]..source_lines[last.old_range().end];
+		let replacement = &formatted
+			[formatted_lines[first.new_range().start]..formatted_lines[last.new_range().end]];
+
+		if diagnostic.primary.is_none() {
+			diagnostic = diagnostic.primary(range.clone(), "this code isn't formatted");
+		}
+		diagnostic = diagnostic.suggestion_full(
+			range,
+			"format the code",
+			replacement,
+			Applicability::Always,
+		);
+	}
+
+	diagnostic
+}
+
+/// Returns the byte offsets of the start of each line, followed by the length of the text
+fn line_offsets(text: &str) -> Vec<usize> {
+	let mut offsets = vec![0];
+	offsets.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+	if offsets.last() != Some(&text.len()) {
+		offsets.push(text.len());
+	}
+	offsets
+}
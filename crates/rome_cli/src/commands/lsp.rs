@@ -0,0 +1,18 @@
+use rome_core::App;
+use rome_lsp::Server;
+use std::io;
+
+use crate::ExitCode;
+
+/// Handler for the "lsp" command of the Rome CLI
+pub(crate) fn lsp(app: &App) -> Result<(), ExitCode> {
+	let stdin = io::stdin();
+	let stdout = io::stdout();
+
+	if let Err(err) = Server::new(app).run(stdin.lock(), stdout.lock()) {
+		eprintln!("The language server stopped: {}", err);
+		return Err(ExitCode::InternalError);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,5 @@
+pub(crate) mod cache;
+pub(crate) mod check;
+pub(crate) mod explain_config;
+pub(crate) mod format;
+pub(crate) mod lsp;
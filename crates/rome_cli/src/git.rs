@@ -0,0 +1,290 @@
+//! The files that `--changed` and `--staged` read from the git repository of the working
+//! directory, through the git command line.
+//!
+//! With `--staged`, the staged files are read from the index instead of the working tree, see
+//! [StagedFileSystem].
+use rome_core::fs::{DirEntry, FileSystem, Metadata, OsFileSystem};
+use rome_core::normalize;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// The modes of the regular files inside the index. The symbolic links and the submodules
+/// aren't formatted.
+const FILE_MODES: [&str; 2] = ["100644", "100755"];
+
+/// Errors that can happen while reading the files from git
+#[derive(Debug)]
+pub(crate) enum GitError {
+	/// git couldn't be started
+	Spawn(io::Error),
+
+	/// git exited with an error, stores what it printed on the standard error
+	Failed(String),
+}
+
+impl Display for GitError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			GitError::Spawn(error) => write!(f, "couldn't run git: {}", error),
+			GitError::Failed(stderr) => write!(f, "{}", stderr.trim()),
+		}
+	}
+}
+
+impl std::error::Error for GitError {}
+
+impl From<GitError> for io::Error {
+	fn from(error: GitError) -> Self {
+		match error {
+			GitError::Spawn(error) => error,
+			error => io::Error::new(io::ErrorKind::Other, error.to_string()),
+		}
+	}
+}
+
+/// The git repository that contains the working directory
+#[derive(Debug)]
+pub(crate) struct Repository {
+	/// The root of the working tree, as an absolute path. It's derived from the working directory
+	/// rather than read from git, so that it compares with the paths of the files even when
+	/// the working directory is behind a symbolic link.
+	root: PathBuf,
+}
+
+/// A file of the index
+#[derive(Debug, Clone)]
+pub(crate) struct StagedFile {
+	/// The path of the file inside the repository, as printed by git
+	name: String,
+	mode: String,
+	/// The id of the staged content
+	blob: String,
+}
+
+impl Repository {
+	/// Finds the repository of the working directory
+	pub(crate) fn open() -> Result<Self, GitError> {
+		let current_dir = env::current_dir().unwrap_or_default();
+		let output = run(Command::new("git").args(["rev-parse", "--show-cdup"]), None)?;
+		let parents = String::from_utf8_lossy(&output);
+
+		Ok(Self {
+			root: normalize(&current_dir.join(parents.trim_end())),
+		})
+	}
+
+	/// Returns the absolute path of a file from its path inside the repository
+	fn path(&self, name: &str) -> PathBuf {
+		normalize(&self.root.join(name))
+	}
+
+	/// Returns the files that were added or modified since `base`, or since `HEAD` if `base` is
+	/// `None`, including the changes that aren't staged and the untracked files that aren't
+	/// ignored. The deleted files aren't returned.
+	///
+	/// When `base` is another branch, the files are compared with the commit where the current
+	/// branch forked from it, so that only the changes of the current branch are returned.
+	pub(crate) fn changed_files(&self, base: Option<&str>) -> Result<Vec<PathBuf>, GitError> {
+		let base = match base {
+			Some(base) => {
+				let output = self.git(&["merge-base", base, "HEAD"], None)?;
+				String::from_utf8_lossy(&output).trim().to_string()
+			}
+			None => String::from("HEAD"),
+		};
+
+		let changed = self.git(
+			&[
+				"diff",
+				"--name-only",
+				"-z",
+				"--no-renames",
+				"--diff-filter=d",
+				&base,
+				"--",
+			],
+			None,
+		)?;
+		let untracked = self.git(&["ls-files", "--others", "--exclude-standard", "-z"], None)?;
+
+		Ok(split_nul(&changed)
+			.chain(split_nul(&untracked))
+			.map(|name| self.path(&name))
+			.collect())
+	}
+
+	/// Returns the staged files that were added or modified since `HEAD`, by absolute path
+	pub(crate) fn staged_files(&self) -> Result<HashMap<PathBuf, StagedFile>, GitError> {
+		let output = self.git(
+			&[
+				"diff",
+				"--cached",
+				"--raw",
+				"-z",
+				"--no-abbrev",
+				"--no-renames",
+				"--diff-filter=d",
+			],
+			None,
+		)?;
+
+		// Each file is printed as `:<old mode> <new mode> <old id> <new id> <status>`, then its path
+		let mut fields = split_nul(&output);
+		let mut files = HashMap::new();
+		while let (Some(status), Some(name)) = (fields.next(), fields.next()) {
+			let status: Vec<&str> = status.split(' ').collect();
+			if let [_, mode, _, blob, _] = status.as_slice() {
+				if FILE_MODES.contains(mode) {
+					let file = StagedFile {
+						name: name.clone(),
+						mode: mode.to_string(),
+						blob: blob.to_string(),
+					};
+					files.insert(self.path(&name), file);
+				}
+			}
+		}
+
+		Ok(files)
+	}
+
+	/// Reads the content of a blob of the repository
+	fn read_blob(&self, blob: &str) -> io::Result<String> {
+		let content = self.git(&["cat-file", "blob", blob], None)?;
+		String::from_utf8(content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+	}
+
+	/// Replaces the staged content of a file
+	fn stage(&self, file: &StagedFile, content: &str) -> io::Result<()> {
+		let output = self.git(
+			&["hash-object", "-w", "--stdin", "--path", &file.name],
+			Some(content),
+		)?;
+		let blob = String::from_utf8_lossy(&output);
+		let cache_info = format!("{},{},{}", file.mode, blob.trim(), file.name);
+		self.git(&["update-index", "--cacheinfo", &cache_info], None)?;
+		Ok(())
+	}
+
+	/// Runs a git command from the root of the repository, and returns its standard output
+	fn git(&self, args: &[&str], input: Option<&str>) -> Result<Vec<u8>, GitError> {
+		run(
+			Command::new("git").arg("-C").arg(&self.root).args(args),
+			input,
+		)
+	}
+}
+
+/// Runs a command, writing `input` to its standard input, and returns its standard output
+fn run(command: &mut Command, input: Option<&str>) -> Result<Vec<u8>, GitError> {
+	let mut child = command
+		.stdin(if input.is_some() {
+			Stdio::piped()
+		} else {
+			Stdio::null()
+		})
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.map_err(GitError::Spawn)?;
+
+	if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+		stdin.write_all(input.as_bytes()).map_err(GitError::Spawn)?;
+	}
+
+	let output = child.wait_with_output().map_err(GitError::Spawn)?;
+	if output.status.success() {
+		Ok(output.stdout)
+	} else {
+		Err(GitError::Failed(
+			String::from_utf8_lossy(&output.stderr).to_string(),
+		))
+	}
+}
+
+/// Splits the output of a git command printed with `-z`
+fn split_nul(output: &[u8]) -> impl Iterator<Item = String> + '_ {
+	output
+		.split(|byte| *byte == 0)
+		.filter(|field| !field.is_empty())
+		.map(|field| String::from_utf8_lossy(field).to_string())
+}
+
+/// A file system that reads the staged files from the index of the repository.
+///
+/// Writing a staged file stages the new content, and writes it to the working tree too unless the
+/// file has changes that aren't staged, which are kept. The other files, and the other operations,
+/// go to the working tree.
+pub(crate) struct StagedFileSystem {
+	repository: Repository,
+	files: HashMap<PathBuf, StagedFile>,
+	/// Only one git command can update the index at a time
+	index_lock: Mutex<()>,
+}
+
+impl StagedFileSystem {
+	pub(crate) fn open() -> Result<Self, GitError> {
+		let repository = Repository::open()?;
+		let files = repository.staged_files()?;
+
+		Ok(Self {
+			repository,
+			files,
+			index_lock: Mutex::new(()),
+		})
+	}
+
+	fn staged_file(&self, path: &Path) -> Option<&StagedFile> {
+		let path = normalize(&env::current_dir().ok()?.join(path));
+		self.files.get(&path)
+	}
+}
+
+impl FileSystem for StagedFileSystem {
+	fn read_to_string(&self, path: &Path) -> io::Result<String> {
+		match self.staged_file(path) {
+			Some(file) => self.repository.read_blob(&file.blob),
+			None => OsFileSystem.read_to_string(path),
+		}
+	}
+
+	fn write(&self, path: &Path, content: &str) -> io::Result<()> {
+		let file = match self.staged_file(path) {
+			Some(file) => file,
+			None => return OsFileSystem.write(path, content),
+		};
+
+		let _lock = self.index_lock.lock().unwrap();
+		let staged = self.repository.read_blob(&file.blob)?;
+		let is_fully_staged = OsFileSystem
+			.read_to_string(path)
+			.map_or(false, |working| working == staged);
+
+		self.repository.stage(file, content)?;
+		if is_fully_staged {
+			OsFileSystem.write(path, content)?;
+		}
+		Ok(())
+	}
+
+	fn remove_file(&self, path: &Path) -> io::Result<()> {
+		OsFileSystem.remove_file(path)
+	}
+
+	fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+		OsFileSystem.create_dir_all(path)
+	}
+
+	fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+		OsFileSystem.metadata(path)
+	}
+
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+		OsFileSystem.read_dir(path)
+	}
+}
@@ -1,8 +1,40 @@
-use clap::{crate_version, App, AppSettings, Arg};
+use clap::{crate_version, App, AppSettings, Arg, ArgMatches};
+use git::StagedFileSystem;
+use reporter::{Reporter, ReporterKind, REPORTERS};
+use rome_core::configuration::{find_configuration_file, Configuration};
 use rome_core::create_app;
-use rome_formatter::{format_file_and_save, FormatOptions, IndentStyle};
-use rome_path::RomePath;
-use std::{path::PathBuf, str::FromStr};
+use rome_core::fs::FileSystem;
+use rome_formatter::IndentStyle;
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+mod cache;
+mod commands;
+mod git;
+mod reporter;
+
+/// The status codes the CLI exits with, other than `0` for success.
+///
+/// When a command has several outcomes, it exits with the most severe one, which is the one with
+/// the highest code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ExitCode {
+	/// The check mode found files that aren't formatted
+	Unformatted = 1,
+	/// The arguments or the configuration are invalid, the same code clap uses for its errors
+	InvalidUsage = 2,
+	/// Some files contain syntax errors
+	SyntaxErrors = 3,
+	/// Some files couldn't be read, written or formatted
+	InternalError = 4,
+}
+
+impl ExitCode {
+	pub(crate) fn exit(self) -> ! {
+		std::process::exit(self as i32)
+	}
+}
 
 /// Main function to run Rome CLI
 pub fn run_cli() {
@@ -10,74 +42,252 @@ pub fn run_cli() {
 		.about("The official Rome CLI")
 		.version(crate_version!())
 		.setting(AppSettings::SubcommandRequiredElseHelp)
+		.arg(
+			Arg::new("reporter")
+				.long("reporter")
+				.about("The format of the diagnostics. The machine readable formats are printed to the standard output once the command is done.")
+				.value_name("FORMAT")
+				.possible_values(&REPORTERS)
+				.default_value("pretty")
+				.global(true),
+		)
 		.subcommand(
 			App::new("format")
-				.about("Format a file")
+				.about("Format files and directories")
+				.after_help(
+					"EXIT CODES:
+    0    Success
+    1    The check mode found files that aren't formatted
+    2    The arguments or the configuration are invalid
+    3    Some files contain syntax errors
+    4    Some files couldn't be read, written or formatted",
+				)
+				.args(formatter_args())
+				.arg(
+					Arg::new("check")
+						.long("check")
+						.about("Print a diff of the files that aren't formatted instead of writing them, and exit with an error if there's any"),
+				)
+				.args(traversal_args())
 				.arg(
-					Arg::new("indent_style")
-						.long("indent-style")
-						.about("The style of indentation")
-						.value_name("tab|space")
-						.default_value("tab")
-						.validator(|value| IndentStyle::from_str(value).map(|_| ())),
+					Arg::new("stdin")
+						.long("stdin")
+						.about("Format the code read from the standard input and print the result to the standard output")
+						.requires("stdin_file_path")
+						.conflicts_with_all(&["input", "check", "watch", "changed", "staged"]),
 				)
 				.arg(
-					Arg::new("indent_size")
-						.long("indent-size")
-						.about("The size of the indent.")
-						.value_name("NUMBER")
-						.default_value("2")
-						.validator(|value| {
-							value
-								.parse::<u8>()
-								.map_err(|_| "Invalid indent-size value. Try using a number")
-						}),
+					Arg::new("stdin_file_path")
+						.long("stdin-file-path")
+						.about("The path of the code read from the standard input, used to find its language. The file doesn't need to exist.")
+						.value_name("PATH"),
 				)
 				.arg(
 					Arg::new("input")
-						.about("File to format")
-						.required(true)
-						.validator(|value| {
-							let path = PathBuf::from(&value);
-							if !path.exists() {
-								return Err(format!("The file \"{}\" doesn't exist.", value));
-							}
-							Ok(())
-						}),
+						.about("Files, directories or glob patterns to format, the working directory by default with --changed or --staged")
+						.required_unless_present_any(&["stdin", "changed", "staged"])
+						.multiple_values(true),
+				),
+		)
+		.subcommand(
+			App::new("check")
+				.about("Check that files are formatted and don't contain syntax errors, without writing them")
+				.after_help(
+					"EXIT CODES:
+    0    Success
+    1    Some files aren't formatted
+    2    The arguments or the configuration are invalid
+    3    Some files contain syntax errors
+    4    Some files couldn't be read or formatted",
+				)
+				.args(formatter_args())
+				.args(traversal_args())
+				.arg(
+					Arg::new("input")
+						.about("Files, directories or glob patterns to check, the working directory by default with --changed or --staged")
+						.required_unless_present_any(&["changed", "staged"])
+						.multiple_values(true),
+				),
+		)
+		.subcommand(
+			App::new("lsp")
+				.about("Start the language server, which communicates through the standard input and output"),
+		)
+		.subcommand(
+			App::new("cache")
+				.about("Manage the cache of the files known to be formatted")
+				.setting(AppSettings::SubcommandRequiredElseHelp)
+				.subcommand(
+					App::new("clean")
+						.about("Delete the cache of the working directory"),
+				),
+		)
+		.subcommand(
+			App::new("explain-config")
+				.about("Print the settings that apply to a file, once the configuration is resolved")
+				.arg(
+					Arg::new("path")
+						.about("The path of the file. The file doesn't need to exist.")
+						.required(true),
 				),
 		)
 		.try_get_matches();
-	let subcommand_matches = match &matches {
-		Ok(r) => r.subcommand(),
+	let (subcommand, matches) = match &matches {
+		Ok(r) => match r.subcommand() {
+			Some(subcommand) => subcommand,
+			// Thanks to the settings AppSettings::SubcommandRequiredElseHelp we should not be there
+			None => clap::Error::with_description(
+				"Sub command not found".to_string(),
+				clap::ErrorKind::InvalidSubcommand,
+			)
+			.exit(),
+		},
 		Err(err) => err.exit(),
 	};
 
+	// The report can't share the standard output with the result of the command
+	let use_stderr = subcommand != "format" || matches.is_present("stdin");
+	let kind = ReporterKind::from_str(matches.value_of("reporter").unwrap()).unwrap();
+	let mut reporter = Reporter::new(kind, use_stderr);
+
+	let app = match open_app(subcommand, matches) {
+		Ok(app) => app,
+		Err(exit_code) => exit_code.exit(),
+	};
+	let result = load_configuration(app.fs(), &mut reporter).and_then(|configuration| {
+		let app = match configuration {
+			Some((configuration, path)) => app.with_configuration(configuration, path),
+			None => app,
+		};
+
+		match subcommand {
+			"format" => commands::format::format(matches, &app, &mut reporter),
+			"check" => commands::check::check(matches, &app, &mut reporter),
+			"lsp" => commands::lsp::lsp(&app),
+			"cache" => commands::cache::cache(matches, &app),
+			"explain-config" => {
+				commands::explain_config::explain_config(matches, &app);
+				Ok(())
+			}
+			_ => clap::Error::with_description(
+				"Sub command not found".to_string(),
+				clap::ErrorKind::InvalidSubcommand,
+			)
+			.exit(),
+		}
+	});
+
+	if let Err(err) = reporter.finish() {
+		eprintln!("Failed to print the report: {}", err);
+		ExitCode::InternalError.exit();
+	}
+
+	if let Err(exit_code) = result {
+		exit_code.exit();
+	}
+}
+
+/// The arguments that override the settings of the formatter
+fn formatter_args<'help>() -> [Arg<'help>; 3] {
+	[
+		Arg::new("indent_style")
+			.long("indent-style")
+			.about("The style of indentation")
+			.value_name("tab|space")
+			.validator(|value| IndentStyle::from_str(value).map(|_| ())),
+		Arg::new("indent_size")
+			.long("indent-size")
+			.about("The size of the indent.")
+			.value_name("NUMBER")
+			.validator(|value| {
+				value
+					.parse::<u8>()
+					.map_err(|_| "Invalid indent-size value. Try using a number")
+			}),
+		Arg::new("line_width")
+			.long("line-width")
+			.about("The width at which lines are wrapped.")
+			.value_name("NUMBER")
+			.validator(|value| {
+				value
+					.parse::<u16>()
+					.map_err(|_| "Invalid line-width value. Try using a number")
+			}),
+	]
+}
+
+/// The arguments of the commands that process the files matched by their inputs
+fn traversal_args<'help>() -> [Arg<'help>; 5] {
+	[
+		Arg::new("verbose")
+			.long("verbose")
+			.about("Print the files and directories that were skipped, and why"),
+		Arg::new("watch")
+			.long("watch")
+			.about("Keep running, and process the files again when they change"),
+		Arg::new("no_cache")
+			.long("no-cache")
+			.about("Process all the files, even the ones that the cache knows to be formatted, and don't update the cache"),
+		Arg::new("changed")
+			.long("changed")
+			.about("Only process the files that changed since BASE, or since the last commit without BASE, according to git. With a branch, the files are compared with the commit the current branch forked from.")
+			.value_name("BASE")
+			.takes_value(true)
+			.min_values(0)
+			.require_equals(true)
+			.conflicts_with_all(&["staged", "watch"]),
+		Arg::new("staged")
+			.long("staged")
+			.about("Only process the files staged in git, using their staged content. Formatting a file stages the formatted code, and writes it to the file too unless the file has changes that aren't staged.")
+			.conflicts_with("watch"),
+	]
+}
+
+/// Creates the app, which reads the staged files from the index of git with `--staged`
+fn open_app(subcommand: &str, matches: &ArgMatches) -> Result<rome_core::App, ExitCode> {
 	let app = create_app();
+	if !matches!(subcommand, "format" | "check") || !matches.is_present("staged") {
+		return Ok(app);
+	}
 
-	match subcommand_matches {
-		Some(("format", matches)) => {
-			let size = matches.value_of("indent_size");
-			let style = matches.value_of("indent_style");
-			let input = matches.value_of("input").unwrap();
-			let options: IndentStyle = style
-				.map(|s| match s {
-					"tab" => IndentStyle::Tab,
-					"space" => {
-						let size = size.unwrap_or("2");
-						IndentStyle::Space(size.parse::<u8>().unwrap_or(2))
-					}
-					_ => IndentStyle::default(),
-				})
-				.unwrap_or_default();
-
-			let mut file = RomePath::new(input).deduce_handler(&app);
-			format_file_and_save(&mut file, FormatOptions::new(options));
+	match StagedFileSystem::open() {
+		Ok(fs) => Ok(app.with_file_system(fs)),
+		Err(err) => {
+			eprintln!("Failed to read the staged files: {}", err);
+			Err(ExitCode::InvalidUsage)
+		}
+	}
+}
+
+/// Loads the configuration file of the project, and returns it with its path.
+///
+/// Reports the diagnostics and returns an error if the configuration file is invalid.
+fn load_configuration(
+	fs: &dyn FileSystem,
+	reporter: &mut Reporter,
+) -> Result<Option<(Configuration, PathBuf)>, ExitCode> {
+	let path = match env::current_dir()
+		.ok()
+		.and_then(|directory| find_configuration_file(fs, &directory))
+	{
+		Some(path) => path,
+		None => return Ok(None),
+	};
+
+	let source = match fs.read_to_string(&path) {
+		Ok(source) => source,
+		Err(err) => {
+			eprintln!("Failed to read {}: {}", path.display(), err);
+			return Err(ExitCode::InternalError);
+		}
+	};
+
+	let file_id = reporter.workspace_mut().insert(&path, source.as_str());
+	match Configuration::from_json(&source, file_id) {
+		Ok(configuration) => Ok(Some((configuration, path))),
+		Err(diagnostics) => {
+			reporter.report(diagnostics);
+			Err(ExitCode::InvalidUsage)
 		}
-		// Thanks to the settings AppSettings::SubcommandRequiredElseHelp we should not be there
-		_ => clap::Error::with_description(
-			"Sub command not found".to_string(),
-			clap::ErrorKind::InvalidSubcommand,
-		)
-		.exit(),
 	}
 }
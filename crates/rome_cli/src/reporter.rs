@@ -0,0 +1,122 @@
+use rome_core::workspace::Workspace;
+use rslint_errors::{
+	CheckstyleFormatter, Diagnostic, Emitter, Formatter, GithubFormatter, GitlabFormatter,
+	JsonFormatter, SarifFormatter,
+};
+use std::io;
+use std::str::FromStr;
+
+/// The names of the reporters accepted by the `--reporter` argument
+pub(crate) const REPORTERS: [&str; 6] =
+	["pretty", "json", "sarif", "checkstyle", "github", "gitlab"];
+
+/// The format of the diagnostics printed by the CLI
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ReporterKind {
+	/// Diagnostics with code snippets, printed to the standard error as soon as they're reported
+	Pretty,
+	/// A JSON document, see [JsonFormatter]
+	Json,
+	/// A SARIF 2.1.0 log, see [SarifFormatter]
+	Sarif,
+	/// A Checkstyle XML report, see [CheckstyleFormatter]
+	Checkstyle,
+	/// GitHub Actions workflow commands, see [GithubFormatter]
+	Github,
+	/// A GitLab Code Quality report, see [GitlabFormatter]
+	Gitlab,
+}
+
+impl FromStr for ReporterKind {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"pretty" => Ok(Self::Pretty),
+			"json" => Ok(Self::Json),
+			"sarif" => Ok(Self::Sarif),
+			"checkstyle" => Ok(Self::Checkstyle),
+			"github" => Ok(Self::Github),
+			"gitlab" => Ok(Self::Gitlab),
+			_ => Err("Value not supported for reporter"),
+		}
+	}
+}
+
+/// Collects the diagnostics of a command.
+///
+/// The machine readable reporters print a single document once the command is done, by calling
+/// [Reporter::finish]. It goes to the standard output, unless the command prints its own result
+/// there, in which case the document goes to the standard error.
+///
+/// The diagnostics point to the files of the [Workspace] of the reporter, the commands add the
+/// files to it before reporting their diagnostics.
+pub(crate) struct Reporter {
+	kind: ReporterKind,
+	use_stderr: bool,
+	workspace: Workspace,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl Reporter {
+	pub(crate) fn new(kind: ReporterKind, use_stderr: bool) -> Self {
+		Self {
+			kind,
+			use_stderr,
+			workspace: Workspace::new(),
+			diagnostics: vec![],
+		}
+	}
+
+	/// Whether the output is meant for humans, in which case the commands print their progress
+	/// to the standard output
+	pub(crate) fn is_pretty(&self) -> bool {
+		self.kind == ReporterKind::Pretty
+	}
+
+	/// The files that the diagnostics point to
+	pub(crate) fn workspace_mut(&mut self) -> &mut Workspace {
+		&mut self.workspace
+	}
+
+	/// Reports diagnostics pointing to files of the workspace
+	pub(crate) fn report(&mut self, diagnostics: Vec<Diagnostic>) {
+		if self.is_pretty() {
+			let mut emitter = Emitter::new(&self.workspace);
+			let color = atty::is(atty::Stream::Stderr);
+
+			for diagnostic in &diagnostics {
+				// Nothing else can be reported if the standard error can't be written to
+				let _ = emitter.emit_stderr(diagnostic, color);
+			}
+		} else {
+			self.diagnostics.extend(diagnostics);
+		}
+	}
+
+	/// Prints the document of the machine readable reporters
+	pub(crate) fn finish(mut self) -> io::Result<()> {
+		self.flush()
+	}
+
+	/// Prints the document of the machine readable reporters with the diagnostics reported so
+	/// far, and starts a new one. Used by the commands that run until they're stopped.
+	pub(crate) fn flush(&mut self) -> io::Result<()> {
+		let mut formatter: Box<dyn Formatter> = match self.kind {
+			ReporterKind::Pretty => return Ok(()),
+			ReporterKind::Json => Box::new(JsonFormatter),
+			ReporterKind::Sarif => Box::new(SarifFormatter::new("rome", env!("CARGO_PKG_VERSION"))),
+			ReporterKind::Checkstyle => Box::new(CheckstyleFormatter),
+			ReporterKind::Github => Box::new(GithubFormatter),
+			ReporterKind::Gitlab => Box::new(GitlabFormatter),
+		};
+
+		let diagnostics = std::mem::take(&mut self.diagnostics);
+
+		if self.use_stderr {
+			formatter.emit_stderr(&diagnostics, &self.workspace)
+		} else {
+			formatter.emit_stdout(&diagnostics, &self.workspace)
+		}
+	}
+}
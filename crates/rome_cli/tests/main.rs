@@ -1,4 +1,12 @@
-use std::{env, path::PathBuf, process::Command};
+use std::{
+	env, fs,
+	io::{BufRead, BufReader, Write},
+	path::{Path, PathBuf},
+	process::{self, Command, Stdio},
+	sync::mpsc,
+	thread,
+	time::Duration,
+};
 
 fn cargo_bin(name: &str) -> PathBuf {
 	env::current_exe()
@@ -13,11 +21,324 @@ fn cargo_bin(name: &str) -> PathBuf {
 		.expect("cannot get current exe")
 }
 
+/// The command of the CLI. The cache of the commands is stored inside the cargo temporary
+/// directory, and isn't shared with the other runs of the tests.
+fn cli() -> Command {
+	let mut command = Command::new(cargo_bin("cli"));
+	let cache_dir =
+		PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("cache-{}", process::id()));
+	command.env("ROME_CACHE_DIR", cache_dir);
+	command
+}
+
 #[test]
-#[ignore = "The CLI for now, doesn't print anything"]
 fn test_format_cli() {
-	let res = Command::new(cargo_bin("cli"))
-		.args(&["format", "fixtures/input.json"])
+	// The check mode doesn't write the fixture
+	let res = cli()
+		.args(&["format", "--check", "fixtures/input.json"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(
+		res.status.code(),
+		Some(1),
+		"cli format --check should exit with 1 when a file isn't formatted"
+	);
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(
+		output,
+		r#"Unformatted fixtures/input.json
+--- fixtures/input.json
++++ fixtures/input.json
+@@ -1,8 +1 @@
+-{
+-	"string": "foo",
+-	"boolean": false,
+-	"number": 15,
+-	"object": {
+-		"something": 15
+-	}
+-}
++{"string": "foo", "boolean": false, "number": 15, "object": {"something": 15}}
+Checked 1 files: 1 unformatted, 0 formatted, 0 failed
+"#
+	);
+}
+
+/// Creates a clean directory inside the cargo temporary directory that contains the given files
+fn create_test_directory(name: &str, files: &[(&str, &str)]) -> PathBuf {
+	let root = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+	if root.exists() {
+		fs::remove_dir_all(&root).expect("cannot clean the test directory");
+	}
+
+	for (path, content) in files {
+		let path = root.join(path);
+		fs::create_dir_all(path.parent().unwrap()).expect("cannot create the test directory");
+		fs::write(path, content).expect("cannot write the test file");
+	}
+
+	root
+}
+
+#[test]
+fn test_format_directory() {
+	let root = create_test_directory(
+		"test_format_directory",
+		&[
+			("src/unformatted.js", "let a  =  1"),
+			("src/nested/formatted.js", "let b = 2;\n"),
+			("src/README.md", "# Not handled"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "src"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(
+		output,
+		"Unchanged src/nested/formatted.js
+Formatted src/unformatted.js
+Processed 2 files: 1 changed, 1 unchanged, 0 failed
+"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/unformatted.js")).unwrap(),
+		"let a = 1;\n"
+	);
+}
+
+#[test]
+fn test_format_module_kinds() {
+	let root = create_test_directory(
+		"test_format_module_kinds",
+		&[
+			("package.json", r#"{ "type": "module" }"#),
+			("src/index.js", "import a from 'a'\nexport default a"),
+			("src/bin.cjs", "if (done) return"),
+			("src/legacy/package.json", r#"{ "type": "commonjs" }"#),
+			("src/legacy/index.js", "if (done) return"),
+			("src/no-type/package.json", r#"{ "name": "no-type" }"#),
+			("src/no-type/index.js", "export { a }"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "src"])
+		.output()
+		.expect("fail to run cli format");
+
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert!(
+		res.status.success(),
+		"cli format command failed: {}",
+		output
+	);
+
+	assert_eq!(
+		fs::read_to_string(root.join("src/index.js")).unwrap(),
+		"import a from \"a\";\nexport default a;\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/bin.cjs")).unwrap(),
+		"if (done) return;\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/legacy/index.js")).unwrap(),
+		"if (done) return;\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/no-type/index.js")).unwrap(),
+		"export { a };\n"
+	);
+}
+
+#[test]
+fn test_format_check() {
+	let root = create_test_directory(
+		"test_format_check",
+		&[
+			("src/unformatted.js", "let a  =  1\nlet b = 2;\n"),
+			("src/formatted.js", "let b = 2;\n"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "--check", "src"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(
+		!res.status.success(),
+		"cli format --check should fail when a file isn't formatted"
+	);
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(
+		output,
+		"Unchanged src/formatted.js
+Unformatted src/unformatted.js
+--- src/unformatted.js
++++ src/unformatted.js
+@@ -1,2 +1,2 @@
+-let a  =  1
++let a = 1;
+ let b = 2;
+Checked 2 files: 1 unformatted, 1 formatted, 0 failed
+"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/unformatted.js")).unwrap(),
+		"let a  =  1\nlet b = 2;\n",
+		"cli format --check must not write the files"
+	);
+}
+
+#[test]
+fn test_format_stdin() {
+	let mut child = cli()
+		.args(&[
+			"format",
+			"--stdin",
+			"--stdin-file-path",
+			"does/not/exist.js",
+		])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.spawn()
+		.expect("fail to run cli format");
+
+	child
+		.stdin
+		.take()
+		.unwrap()
+		.write_all(b"let a  =  1")
+		.expect("cannot write to stdin");
+
+	let res = child.wait_with_output().expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format --stdin command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(output, "let a = 1;\n");
+}
+
+#[test]
+fn test_format_configuration() {
+	let root = create_test_directory(
+		"test_format_configuration",
+		&[
+			(
+				"rome.json",
+				r#"{ "formatter": { "indentStyle": "space", "indentSize": 4 }, "json": { "formatter": { "indentSize": 1, "lineWidth": 8 } } }"#,
+			),
+			("src/index.js", "function f() { return 1; }"),
+			("src/data.json", r#"{ "a": 1, "b": 2 }"#),
+		],
+	);
+
+	// The configuration is discovered from the parent directories
+	let res = cli()
+		.current_dir(root.join("src"))
+		.args(&["format", "index.js", "data.json"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	assert_eq!(
+		fs::read_to_string(root.join("src/index.js")).unwrap(),
+		"function f() {\n    return 1;\n}\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/data.json")).unwrap(),
+		"{\n \"a\": 1,\n \"b\": 2\n}\n"
+	);
+
+	// The arguments of the command take precedence over the configuration
+	let res = cli()
+		.current_dir(root.join("src"))
+		.args(&["format", "--indent-size", "2", "index.js"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	assert_eq!(
+		fs::read_to_string(root.join("src/index.js")).unwrap(),
+		"function f() {\n  return 1;\n}\n"
+	);
+}
+
+#[test]
+fn test_format_invalid_configuration() {
+	let root = create_test_directory(
+		"test_format_invalid_configuration",
+		&[
+			(
+				"rome.json",
+				r#"{ "formatter": { "indentStile": "space" } }"#,
+			),
+			("index.js", "let a  =  1"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "index.js"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(
+		!res.status.success(),
+		"cli format should fail when the configuration is invalid"
+	);
+	let output = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+
+	assert!(
+		output.contains("unknown key \"indentStile\""),
+		"unexpected output: {}",
+		output
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("index.js")).unwrap(),
+		"let a  =  1",
+		"cli format must not write the files when the configuration is invalid"
+	);
+}
+
+#[test]
+fn test_format_overrides() {
+	let root = create_test_directory(
+		"test_format_overrides",
+		&[
+			(
+				"rome.json",
+				r#"{
+					"overrides": [
+						{ "include": ["legacy/**"], "formatter": { "indentStyle": "space", "indentSize": 4 } },
+						{ "include": ["legacy/vendor/**"], "capabilities": { "format": false } },
+						{ "include": ["generated/**"], "ignore": true }
+					]
+				}"#,
+			),
+			("src/index.js", "function f() { return 1; }"),
+			("legacy/index.js", "function f() { return 1; }"),
+			("legacy/vendor/lib.js", "function f() { return 1; }"),
+			("generated/data.json", r#"{ "a": 1 }"#),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "src", "legacy", "generated"])
 		.output()
 		.expect("fail to run cli format");
 
@@ -26,11 +347,728 @@ fn test_format_cli() {
 
 	assert_eq!(
 		output,
-		r#"Running formatter to:
-- file "fixtures/input.json"
-- with options Tab
-{"string": "foo", "boolean": false, "number": 15, "object": {"something": 15}}
+		"Formatted legacy/index.js
+Formatted src/index.js
+Processed 2 files: 2 changed, 0 unchanged, 0 failed
+"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("legacy/index.js")).unwrap(),
+		"function f() {\n    return 1;\n}\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/index.js")).unwrap(),
+		"function f() {\n\treturn 1;\n}\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("legacy/vendor/lib.js")).unwrap(),
+		"function f() { return 1; }"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("generated/data.json")).unwrap(),
+		r#"{ "a": 1 }"#
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&[
+			"check",
+			"--verbose",
+			"legacy/vendor/lib.js",
+			"generated/data.json",
+		])
+		.output()
+		.expect("fail to run cli check");
+
+	assert!(res.status.success(), "cli check command failed");
+	assert_eq!(
+		String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant"),
+		"Skipped generated/data.json: ignored by overrides[2] of the configuration
+Skipped legacy/vendor/lib.js: formatting is disabled by overrides[1] of the configuration
+Checked 0 files: 0 unformatted, 0 formatted, 0 failed
+"
+	);
+
+	let res = cli()
+		.current_dir(root.join("legacy"))
+		.args(&["explain-config", "vendor/lib.js"])
+		.output()
+		.expect("fail to run cli explain-config");
+
+	assert!(res.status.success(), "cli explain-config command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(
+		output,
+		format!(
+			"File: vendor/lib.js
+Configuration: {}
+Language: Js
+Ignored: no
+Format: no
+Lint: yes
+Formatter:
+  indentStyle: space
+  indentSize: 4
+  lineWidth: 80
+Overrides:
+  overrides[0], include: [\"legacy/**\"]
+  overrides[1], include: [\"legacy/vendor/**\"]
+",
+			root.join("rome.json").display()
+		)
+	);
+}
+
+#[test]
+fn test_format_ignore_files() {
+	let root = create_test_directory(
+		"test_format_ignore_files",
+		&[
+			("rome.json", r#"{ "ignore": ["scripts/*.generated.js"] }"#),
+			(".gitignore", "node_modules\ndist/\n"),
+			(".romeignore", "vendor\n"),
+			("src/.gitignore", "*.tmp.js\n!keep.tmp.js\n"),
+			("src/index.js", "let a = 1;\n"),
+			("src/draft.tmp.js", "let a  =  1"),
+			("src/keep.tmp.js", "let a = 1;\n"),
+			("src/node_modules/lib/index.js", "let a  =  1"),
+			("dist/index.js", "let a  =  1"),
+			("vendor/lib.js", "let a  =  1"),
+			("scripts/build.js", "let a = 1;\n"),
+			("scripts/data.generated.js", "let a  =  1"),
+			(".git/hooks/pre-commit.js", "let a  =  1"),
+			("README.md", "# Not handled"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "--verbose", "."])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(
+		output,
+		"Skipped .git: it's a version control directory
+Skipped .gitignore: the extension of the file isn't supported
+Skipped .romeignore: the extension of the file isn't supported
+Skipped README.md: the extension of the file isn't supported
+Skipped dist: matches \"dist/\" in .gitignore
+Skipped scripts/data.generated.js: matches \"scripts/*.generated.js\" in the ignore setting of the configuration
+Skipped src/.gitignore: the extension of the file isn't supported
+Skipped src/draft.tmp.js: matches \"*.tmp.js\" in src/.gitignore
+Skipped src/node_modules: matches \"node_modules\" in .gitignore
+Skipped vendor: matches \"vendor\" in .romeignore
+Formatted rome.json
+Unchanged scripts/build.js
+Unchanged src/index.js
+Unchanged src/keep.tmp.js
+Processed 4 files: 1 changed, 3 unchanged, 0 failed
+"
+	);
+
+	// The paths given explicitly are skipped too when one of their parents is ignored
+	let res = cli()
+		.current_dir(root.join("src"))
+		.args(&["format", "--verbose", "node_modules/lib/index.js"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+
+	assert_eq!(
+		output,
+		format!(
+			"Skipped node_modules/lib/index.js: matches \"node_modules\" in {}
+Processed 0 files: 0 changed, 0 unchanged, 0 failed
+",
+			root.join(".gitignore").display()
+		)
+	);
+
+	// explain-config gives the same reasons as the ones the files are skipped for
+	for (path, reason) in [
+		("dist/index.js", "matches \"dist/\" in .gitignore"),
+		("vendor/lib.js", "matches \"vendor\" in .romeignore"),
+		(
+			"scripts/data.generated.js",
+			"matches \"scripts/*.generated.js\" in the ignore setting of the configuration",
+		),
+		("src/index.js", ""),
+	] {
+		let res = cli()
+			.current_dir(&root)
+			.args(&["explain-config", path])
+			.output()
+			.expect("fail to run cli explain-config");
+
+		assert!(res.status.success(), "cli explain-config command failed");
+		let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+		let expected = if reason.is_empty() {
+			String::from("Ignored: no\n")
+		} else {
+			format!("Ignored: yes, {}\n", reason)
+		};
+		assert!(output.contains(&expected), "unexpected output: {}", output);
+	}
+}
+
+#[test]
+fn test_format_syntax_errors() {
+	let root = create_test_directory(
+		"test_format_syntax_errors",
+		&[
+			(
+				"invalid.js",
+				"let  a  =  1;\nlet b =  ;\nfunction  f ( )  {  return  a  }",
+			),
+			("valid.js", "let a  =  1"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "."])
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(
+		res.status.code(),
+		Some(3),
+		"cli format should exit with 3 when a file contains syntax errors"
+	);
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(
+		output,
+		"Formatted invalid.js
+Formatted valid.js
+Processed 2 files: 2 changed, 0 unchanged, 0 failed
+"
+	);
+
+	let errors = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert_eq!(
+		errors,
+		"warning[SyntaxError]: Expected an expression, but found none
+  ┌─ invalid.js:2:10
+  │
+2 │ let b =  ;
+  │          ^ Expected an expression here
+
+warning[Format]: this code couldn't be formatted
+  ┌─ invalid.js:2:1
+  │
+2 │ let b =  ;
+  │ ---------- this code contains syntax errors, it was left as is
+
+"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("invalid.js")).unwrap(),
+		"let a = 1;\nlet b =  ;\nfunction f() {\n\treturn a;\n}\n",
+		"cli format should only leave the statement with syntax errors as it is"
+	);
+}
 
+#[test]
+fn test_format_syntax_errors_of_several_files() {
+	let root = create_test_directory(
+		"test_format_syntax_errors_of_several_files",
+		&[
+			("a.js", "let a = ;\n"),
+			("b.js", "let b = 1;\nlet c = 2;\nlet d = ;\n"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "."])
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(res.status.code(), Some(3));
+	// Each diagnostic points to the source and the lines of its own file
+	let errors = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert_eq!(
+		errors,
+		"warning[SyntaxError]: Expected an expression, but found none
+  ┌─ a.js:1:9
+  │
+1 │ let a = ;
+  │         ^ Expected an expression here
+
+warning[Format]: this code couldn't be formatted
+  ┌─ a.js:1:1
+  │
+1 │ let a = ;
+  │ --------- this code contains syntax errors, it was left as is
+
+warning[SyntaxError]: Expected an expression, but found none
+  ┌─ b.js:3:9
+  │
+3 │ let d = ;
+  │         ^ Expected an expression here
+
+warning[Format]: this code couldn't be formatted
+  ┌─ b.js:3:1
+  │
+3 │ let d = ;
+  │ --------- this code contains syntax errors, it was left as is
+
+"
+	);
+}
+
+#[test]
+fn test_format_internal_errors() {
+	let res = cli()
+		.args(&["format", "fixtures/input.unknown"])
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(
+		res.status.code(),
+		Some(4),
+		"cli format should exit with 4 when a file can't be formatted"
+	);
+	let errors = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert!(
+		errors.starts_with("error[Format]: formatting is disabled for the file"),
+		"unexpected output: {}",
+		errors
+	);
+}
+
+#[test]
+fn test_format_reporter_checkstyle() {
+	let root = create_test_directory(
+		"test_format_reporter_checkstyle",
+		&[
+			("invalid.js", "let a = ;\n"),
+			("unformatted.js", "let a  =  1;\nlet b = 2;\n"),
+			("valid.js", "let a = 1;\n"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "--check", "--reporter", "checkstyle", "."])
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(
+		res.status.code(),
+		Some(3),
+		"cli format should exit with the most severe outcome whatever the reporter"
+	);
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(
+		output,
+		r#"<?xml version="1.0" encoding="utf-8"?>
+<checkstyle version="4.3">
+  <file name="invalid.js">
+    <error line="1" column="9" severity="warning" message="Expected an expression, but found none: Expected an expression here" source="SyntaxError"/>
+    <error line="1" column="1" severity="warning" message="this code couldn&apos;t be formatted: this code contains syntax errors, it was left as is" source="Format"/>
+  </file>
+  <file name="unformatted.js">
+    <error line="1" column="1" severity="error" message="the file isn&apos;t formatted: this code isn&apos;t formatted" source="Unformatted"/>
+    <error line="1" column="1" severity="info" message="format the code, replace with: let a = 1;&#10;" source="Unformatted"/>
+  </file>
+</checkstyle>
 "#
 	);
+	assert_eq!(
+		String::from_utf8(res.stderr).unwrap(),
+		"",
+		"the diagnostics should only be part of the report"
+	);
+}
+
+#[test]
+fn test_format_reporter_sarif() {
+	let root = create_test_directory(
+		"test_format_reporter_sarif",
+		&[("rome.json", r#"{ "formatter": { "lineWidth": "wide" } }"#)],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["--reporter", "sarif", "explain-config", "index.js"])
+		.output()
+		.expect("fail to run cli explain-config");
+
+	assert_eq!(
+		res.status.code(),
+		Some(2),
+		"cli explain-config should exit with 2 when the configuration is invalid"
+	);
+	assert_eq!(String::from_utf8(res.stdout).unwrap(), "");
+
+	// explain-config prints its result on the standard output, so the report goes to stderr
+	let errors = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert!(errors.contains(r#""version": "2.1.0""#), "{}", errors);
+	assert!(
+		errors.contains(r#""ruleId": "Configuration""#),
+		"{}",
+		errors
+	);
+	assert!(errors.contains(r#""startColumn": 31"#), "{}", errors);
+	assert!(errors.contains(r#""level": "error""#), "{}", errors);
+}
+
+#[test]
+fn test_format_reporter_github() {
+	let root = create_test_directory(
+		"test_format_reporter_github",
+		&[("invalid.js", "let a = ;\n"), ("valid.js", "let a = 1;\n")],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "--check", "--reporter", "github", "."])
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(res.status.code(), Some(3));
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(
+		output,
+		"::warning file=invalid.js,line=1,endLine=1,col=9,endColumn=10,title=SyntaxError::Expected an expression, but found none%0AExpected an expression here
+::warning file=invalid.js,line=1,endLine=1,col=1,endColumn=10,title=Format::this code couldn't be formatted%0Athis code contains syntax errors, it was left as is
+"
+	);
+}
+
+#[test]
+fn test_format_cache() {
+	let root = create_test_directory(
+		"test_format_cache",
+		&[("a.js", "let a = 1;\n"), ("b.js", "let b  =  2;\n")],
+	);
+	let run = |args: &[&str]| {
+		let res = cli()
+			.current_dir(&root)
+			.args(args)
+			.output()
+			.expect("fail to run cli");
+		assert_eq!(res.status.code(), Some(0), "{:?}", res);
+		String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant")
+	};
+
+	assert_eq!(
+		run(&["format", "--verbose", "."]),
+		"Unchanged a.js\nFormatted b.js\nProcessed 2 files: 1 changed, 1 unchanged, 0 failed\n"
+	);
+	// The formatted files are cached, including the ones that were just written
+	assert_eq!(
+		run(&["check", "--verbose", "."]),
+		"Unchanged a.js (cached)\nUnchanged b.js (cached)\nChecked 2 files: 0 unformatted, 2 formatted, 0 failed\n"
+	);
+	assert_eq!(
+		run(&["check", "--verbose", "--no-cache", "."]),
+		"Unchanged a.js\nUnchanged b.js\nChecked 2 files: 0 unformatted, 2 formatted, 0 failed\n"
+	);
+
+	// The cache is invalidated by the changes of the content and of the options
+	fs::write(root.join("a.js"), "let a = 3;\n").unwrap();
+	assert_eq!(
+		run(&["format", "--verbose", "."]),
+		"Unchanged a.js\nUnchanged b.js (cached)\nProcessed 2 files: 0 changed, 2 unchanged, 0 failed\n"
+	);
+	assert_eq!(
+		run(&["format", "--verbose", "--line-width", "100", "."]),
+		"Unchanged a.js\nUnchanged b.js\nProcessed 2 files: 0 changed, 2 unchanged, 0 failed\n"
+	);
+
+	assert!(run(&["cache", "clean"]).starts_with("Removed the cache at "));
+	assert_eq!(run(&["cache", "clean"]), "There's no cache to remove\n");
+	assert_eq!(
+		run(&["check", "--verbose", "."]),
+		"Unchanged a.js\nUnchanged b.js\nChecked 2 files: 0 unformatted, 2 formatted, 0 failed\n"
+	);
+}
+
+#[test]
+fn test_format_cache_module_kind() {
+	let root = create_test_directory(
+		"test_format_cache_module_kind",
+		&[
+			("package.json", "{ \"type\": \"commonjs\" }\n"),
+			("main.js", "return;\n"),
+		],
+	);
+	let run = |args: &[&str]| {
+		cli()
+			.current_dir(&root)
+			.args(args)
+			.output()
+			.expect("fail to run cli")
+	};
+
+	let res = run(&["check", "--verbose", "main.js"]);
+	assert_eq!(res.status.code(), Some(0), "{:?}", res);
+	// The paths are normalized, so both spellings of the path share the same entry
+	let res = run(&["check", "--verbose", "./main.js"]);
+	assert_eq!(res.status.code(), Some(0), "{:?}", res);
+	assert_eq!(
+		String::from_utf8(res.stdout).unwrap(),
+		"Unchanged ./main.js (cached)\nChecked 1 files: 0 unformatted, 1 formatted, 0 failed\n"
+	);
+
+	// A return outside of a function is only valid in a CommonJS module
+	fs::write(root.join("package.json"), "{ \"type\": \"module\" }\n").unwrap();
+	let res = run(&["check", "--verbose", "main.js"]);
+	assert_eq!(res.status.code(), Some(3), "{:?}", res);
+	assert!(String::from_utf8(res.stderr)
+		.unwrap()
+		.contains("Illegal return statement outside of a function"));
+}
+
+/// Runs a git command inside `root`, which must succeed
+fn git(root: &Path, args: &[&str]) -> String {
+	let output = Command::new("git")
+		.current_dir(root)
+		.args(["-c", "user.name=Rome", "-c", "user.email=rome@example.com"])
+		.args(args)
+		.output()
+		.expect("fail to run git");
+	assert!(output.status.success(), "{:?}", output);
+	String::from_utf8(output.stdout).expect("cannot read stdout, not utf8 compliant")
+}
+
+#[test]
+fn test_check_changed_files() {
+	let root = create_test_directory(
+		"test_check_changed_files",
+		&[
+			(".gitignore", "dist\n"),
+			("src/committed.js", "let a  =  1"),
+			("src/modified.js", "let b = 2;\n"),
+			("src/branch.js", "let c = 3;\n"),
+		],
+	);
+	git(&root, &["init", "--quiet", "--initial-branch", "main"]);
+	git(&root, &["add", "."]);
+	git(&root, &["commit", "--quiet", "--message", "base"]);
+	git(&root, &["checkout", "--quiet", "-b", "feature"]);
+	fs::write(root.join("src/branch.js"), "let c  =  3").unwrap();
+	git(
+		&root,
+		&["commit", "--quiet", "--all", "--message", "feature"],
+	);
+	fs::write(root.join("src/modified.js"), "let b  =  2\n").unwrap();
+	fs::write(root.join("src/untracked.js"), "let d = 4;\n").unwrap();
+	fs::create_dir(root.join("dist")).unwrap();
+	fs::write(root.join("dist/ignored.js"), "let e  =  5").unwrap();
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["check", "--changed", "--verbose"])
+		.output()
+		.expect("fail to run cli");
+	assert_eq!(res.status.code(), Some(1));
+	let stdout = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(
+		stdout,
+		"Unformatted src/modified.js
+--- src/modified.js
++++ src/modified.js
+@@ -1 +1 @@
+-let b  =  2
++let b = 2;
+Unchanged src/untracked.js
+Checked 2 files: 1 unformatted, 1 formatted, 0 failed
+"
+	);
+
+	// The changes of the branch are included, and the inputs still limit the files
+	let res = cli()
+		.current_dir(&root)
+		.args(&[
+			"check",
+			"--changed=main",
+			"src/branch.js",
+			"src/untracked.js",
+		])
+		.output()
+		.expect("fail to run cli");
+	assert_eq!(res.status.code(), Some(1));
+	let stdout = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert!(stdout.starts_with("Unformatted src/branch.js\n"));
+	assert!(stdout.ends_with(
+		"Unchanged src/untracked.js\nChecked 2 files: 1 unformatted, 1 formatted, 0 failed\n"
+	));
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["check", "--changed=missing"])
+		.output()
+		.expect("fail to run cli");
+	assert_eq!(res.status.code(), Some(2));
+}
+
+#[test]
+fn test_format_staged_files() {
+	let root = create_test_directory(
+		"test_format_staged_files",
+		&[
+			("staged.js", "let a  =  1"),
+			("partially_staged.js", "let b  =  2"),
+			("unstaged.js", "let c  =  3"),
+		],
+	);
+	git(&root, &["init", "--quiet"]);
+	git(&root, &["add", "staged.js", "partially_staged.js"]);
+	fs::write(root.join("partially_staged.js"), "let b  =  2\nlet d  =  4").unwrap();
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["format", "--staged"])
+		.output()
+		.expect("fail to run cli");
+	assert_eq!(res.status.code(), Some(0), "{:?}", res);
+	assert_eq!(
+		String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant"),
+		"Formatted partially_staged.js\nFormatted staged.js\nProcessed 2 files: 2 changed, 0 unchanged, 0 failed\n"
+	);
+
+	// The changes that aren't staged are kept
+	assert_eq!(git(&root, &["show", ":staged.js"]), "let a = 1;\n");
+	assert_eq!(
+		fs::read_to_string(root.join("staged.js")).unwrap(),
+		"let a = 1;\n"
+	);
+	assert_eq!(
+		git(&root, &["show", ":partially_staged.js"]),
+		"let b = 2;\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("partially_staged.js")).unwrap(),
+		"let b  =  2\nlet d  =  4"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("unstaged.js")).unwrap(),
+		"let c  =  3"
+	);
+}
+
+#[test]
+fn test_check() {
+	let root = create_test_directory(
+		"test_check",
+		&[
+			("unformatted.js", "let a  =  1"),
+			("valid.js", "let a = 1;\n"),
+		],
+	);
+
+	let res = cli()
+		.current_dir(&root)
+		.args(&["check", "."])
+		.output()
+		.expect("fail to run cli check");
+
+	assert_eq!(res.status.code(), Some(1));
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(
+		output,
+		"Unformatted unformatted.js
+--- unformatted.js
++++ unformatted.js
+@@ -1 +1 @@
+-let a  =  1
+\\ No newline at end of file
++let a = 1;
+Unchanged valid.js
+Checked 2 files: 1 unformatted, 1 formatted, 0 failed
+"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("unformatted.js")).unwrap(),
+		"let a  =  1",
+		"cli check must not write the files"
+	);
+}
+
+#[test]
+fn test_format_watch() {
+	let root = create_test_directory(
+		"test_format_watch",
+		&[("a.js", "let a  =  1;\n"), ("b.js", "let b = 2;\n")],
+	);
+
+	let mut child = cli()
+		.current_dir(&root)
+		.args(&["format", "--watch", "."])
+		.stdout(Stdio::piped())
+		.spawn()
+		.expect("fail to run cli format");
+
+	let (sender, receiver) = mpsc::channel();
+	let stdout = child.stdout.take().unwrap();
+	thread::spawn(move || {
+		for line in BufReader::new(stdout).lines() {
+			if sender.send(line.unwrap()).is_err() {
+				break;
+			}
+		}
+	});
+
+	// Reads the output until the command waits for changes
+	let next_batch = || {
+		let mut lines = vec![];
+		loop {
+			let line = receiver
+				.recv_timeout(Duration::from_secs(10))
+				.expect("cli format --watch should print the results of each batch");
+			if line == "Watching for changes..." {
+				return lines;
+			}
+			lines.push(line);
+		}
+	};
+
+	assert_eq!(
+		next_batch(),
+		[
+			"Formatted a.js",
+			"Unchanged b.js",
+			"Processed 2 files: 1 changed, 1 unchanged, 0 failed",
+		]
+	);
+
+	fs::write(root.join("b.js"), "let b  =  3;\n").unwrap();
+	let modified = next_batch();
+
+	fs::write(root.join("c.js"), "let c  =  4;\n").unwrap();
+	let created = next_batch();
+
+	child.kill().unwrap();
+	child.wait().unwrap();
+
+	assert_eq!(
+		modified,
+		[
+			"Formatted b.js",
+			"Processed 1 files: 1 changed, 0 unchanged, 0 failed",
+		],
+		"only the files that changed should be processed again"
+	);
+	assert_eq!(
+		created,
+		[
+			"Formatted c.js",
+			"Processed 1 files: 1 changed, 0 unchanged, 0 failed",
+		],
+		"the files created after the command started should be processed"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("b.js")).unwrap(),
+		"let b = 3;\n"
+	);
 }
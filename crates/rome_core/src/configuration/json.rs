@@ -0,0 +1,298 @@
+//! A small JSON model built on top of the JavaScript parser.
+//!
+//! Every value remembers the range it was parsed from, so that the diagnostics emitted
+//! while validating the configuration can point to the offending part of the file.
+use rslint_errors::{file::FileId, Diagnostic};
+use rslint_parser::ast::{
+	JsAnyArrayElement, JsAnyExpression, JsAnyLiteralExpression, JsAnyObjectMember,
+	JsAnyObjectMemberName, JsArrayExpression, JsObjectExpression,
+};
+use rslint_parser::{parse_expr, AstNode, AstSeparatedList, TextRange, TextSize, T};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JsonValue {
+	pub(crate) kind: JsonValueKind,
+	pub(crate) range: TextRange,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValueKind {
+	Null,
+	Bool(bool),
+	Number(f64),
+	String(String),
+	Array(Vec<JsonValue>),
+	Object(Vec<JsonMember>),
+}
+
+impl JsonValueKind {
+	/// The name of the type of the value, as used in diagnostics
+	pub(crate) fn type_name(&self) -> &'static str {
+		match self {
+			JsonValueKind::Null => "null",
+			JsonValueKind::Bool(_) => "a boolean",
+			JsonValueKind::Number(_) => "a number",
+			JsonValueKind::String(_) => "a string",
+			JsonValueKind::Array(_) => "an array",
+			JsonValueKind::Object(_) => "an object",
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JsonMember {
+	pub(crate) key: String,
+	pub(crate) key_range: TextRange,
+	pub(crate) value: JsonValue,
+}
+
+/// Parses the source of a JSON document.
+///
+/// Returns the syntax errors of the document, or a diagnostic pointing to the first
+/// JavaScript construct that isn't valid JSON.
+pub(crate) fn parse_json(source: &str, file_id: FileId) -> Result<JsonValue, Vec<Diagnostic>> {
+	let parse = parse_expr(source, file_id);
+
+	if !parse.errors().is_empty() {
+		return Err(parse.errors().to_vec());
+	}
+
+	let root = parse.tree();
+	let root_end = root.range().end();
+	let source_end = TextSize::of(source.trim_end());
+
+	if root_end < source_end {
+		return Err(vec![Diagnostic::error(
+			file_id,
+			"SyntaxError",
+			"expected the end of the file",
+		)
+		.primary(TextRange::new(root_end, source_end), "unexpected content")]);
+	}
+
+	convert_expression(root, file_id).map_err(|diagnostic| vec![diagnostic])
+}
+
+fn convert_expression(
+	expression: JsAnyExpression,
+	file_id: FileId,
+) -> Result<JsonValue, Diagnostic> {
+	let range = expression.range();
+
+	let kind = match expression {
+		JsAnyExpression::JsAnyLiteralExpression(literal) => match literal {
+			JsAnyLiteralExpression::JsNullLiteralExpression(_) => JsonValueKind::Null,
+			JsAnyLiteralExpression::JsBooleanLiteralExpression(boolean) => {
+				let value = boolean
+					.value_token()
+					.map_err(|_| invalid_json(file_id, range))?;
+				JsonValueKind::Bool(value.kind() == T![true])
+			}
+			JsAnyLiteralExpression::JsNumberLiteralExpression(number) => {
+				let token = number
+					.value_token()
+					.map_err(|_| invalid_json(file_id, range))?;
+				// JSON numbers are a subset of the numbers accepted by Rust
+				let value = token
+					.text_trimmed()
+					.parse()
+					.map_err(|_| invalid_json(file_id, range))?;
+				JsonValueKind::Number(value)
+			}
+			JsAnyLiteralExpression::JsStringLiteralExpression(string) => {
+				let token = string
+					.value_token()
+					.map_err(|_| invalid_json(file_id, range))?;
+				JsonValueKind::String(unquote(token.text_trimmed(), file_id, range)?)
+			}
+			_ => return Err(invalid_json(file_id, range)),
+		},
+		JsAnyExpression::JsUnaryExpression(unary) => {
+			let is_minus = unary
+				.operator()
+				.map_or(false, |operator| operator.kind() == T![-]);
+			let argument = unary.argument().map_err(|_| invalid_json(file_id, range))?;
+
+			match convert_expression(argument, file_id)?.kind {
+				JsonValueKind::Number(number) if is_minus => JsonValueKind::Number(-number),
+				_ => return Err(invalid_json(file_id, range)),
+			}
+		}
+		JsAnyExpression::JsArrayExpression(array) => convert_array(array, file_id)?,
+		JsAnyExpression::JsObjectExpression(object) => convert_object(object, file_id)?,
+		_ => return Err(invalid_json(file_id, range)),
+	};
+
+	Ok(JsonValue { kind, range })
+}
+
+fn convert_array(array: JsArrayExpression, file_id: FileId) -> Result<JsonValueKind, Diagnostic> {
+	let mut elements = Vec::new();
+
+	for element in array.elements().iter() {
+		let element = element.map_err(|_| invalid_json(file_id, array.range()))?;
+		match element {
+			JsAnyArrayElement::JsAnyExpression(expression) => {
+				elements.push(convert_expression(expression, file_id)?)
+			}
+			element => return Err(invalid_json(file_id, element.range())),
+		}
+	}
+
+	Ok(JsonValueKind::Array(elements))
+}
+
+fn convert_object(
+	object: JsObjectExpression,
+	file_id: FileId,
+) -> Result<JsonValueKind, Diagnostic> {
+	let mut members = Vec::new();
+
+	for member in object.members().iter() {
+		let member = member.map_err(|_| invalid_json(file_id, object.range()))?;
+		let property = match member {
+			JsAnyObjectMember::JsPropertyObjectMember(property) => property,
+			member => return Err(invalid_json(file_id, member.range())),
+		};
+
+		let name = match property.name() {
+			Ok(JsAnyObjectMemberName::JsLiteralMemberName(name)) => name,
+			_ => return Err(invalid_json(file_id, property.range())),
+		};
+		let key_token = name
+			.value()
+			.map_err(|_| invalid_json(file_id, name.range()))?;
+		if key_token.kind() != rslint_parser::SyntaxKind::JS_STRING_LITERAL {
+			return Err(Diagnostic::error(
+				file_id,
+				"SyntaxError",
+				"property names must be double quoted strings",
+			)
+			.primary(name.range(), ""));
+		}
+
+		let value = property
+			.value()
+			.map_err(|_| invalid_json(file_id, property.range()))?;
+
+		members.push(JsonMember {
+			key: unquote(key_token.text_trimmed(), file_id, name.range())?,
+			key_range: name.range(),
+			value: convert_expression(value, file_id)?,
+		});
+	}
+
+	Ok(JsonValueKind::Object(members))
+}
+
+/// Removes the quotes around a string literal and resolves its escape sequences
+fn unquote(text: &str, file_id: FileId, range: TextRange) -> Result<String, Diagnostic> {
+	if !text.starts_with('"') {
+		return Err(Diagnostic::error(
+			file_id,
+			"SyntaxError",
+			"JSON strings must use double quotes",
+		)
+		.primary(range, ""));
+	}
+
+	let inner = &text[1..text.len() - 1];
+	let mut result = String::with_capacity(inner.len());
+	let mut chars = inner.chars();
+
+	while let Some(char) = chars.next() {
+		if char != '\\' {
+			result.push(char);
+			continue;
+		}
+
+		match chars.next() {
+			Some('n') => result.push('\n'),
+			Some('t') => result.push('\t'),
+			Some('r') => result.push('\r'),
+			Some('b') => result.push('\u{8}'),
+			Some('f') => result.push('\u{c}'),
+			Some('u') => {
+				let code: String = chars.by_ref().take(4).collect();
+				let char = u32::from_str_radix(&code, 16)
+					.ok()
+					.and_then(char::from_u32)
+					.ok_or_else(|| {
+						Diagnostic::error(file_id, "SyntaxError", "invalid unicode escape sequence")
+							.primary(range, "")
+					})?;
+				result.push(char);
+			}
+			Some(other) => result.push(other),
+			None => {}
+		}
+	}
+
+	Ok(result)
+}
+
+fn invalid_json(file_id: FileId, range: TextRange) -> Diagnostic {
+	Diagnostic::error(file_id, "SyntaxError", "expected a JSON value")
+		.primary(range, "this is not valid JSON")
+}
+
+#[cfg(test)]
+mod test {
+	use super::{parse_json, JsonValueKind};
+
+	#[test]
+	fn parses_nested_values() {
+		let value =
+			parse_json(r#"{ "a": [1, -2.5, true, null], "b": { "c": "x\"y" } }"#, 0).unwrap();
+
+		let members = match value.kind {
+			JsonValueKind::Object(members) => members,
+			_ => panic!("expected an object"),
+		};
+
+		assert_eq!(members[0].key, "a");
+		assert_eq!(
+			members[0].value.kind,
+			JsonValueKind::Array(vec![
+				super::JsonValue {
+					kind: JsonValueKind::Number(1.0),
+					range: rslint_parser::TextRange::new(8.into(), 9.into())
+				},
+				super::JsonValue {
+					kind: JsonValueKind::Number(-2.5),
+					range: rslint_parser::TextRange::new(11.into(), 15.into())
+				},
+				super::JsonValue {
+					kind: JsonValueKind::Bool(true),
+					range: rslint_parser::TextRange::new(17.into(), 21.into())
+				},
+				super::JsonValue {
+					kind: JsonValueKind::Null,
+					range: rslint_parser::TextRange::new(23.into(), 27.into())
+				},
+			])
+		);
+		assert_eq!(members[1].key, "b");
+		match &members[1].value.kind {
+			JsonValueKind::Object(members) => {
+				assert_eq!(members[0].value.kind, JsonValueKind::String("x\"y".into()))
+			}
+			_ => panic!("expected an object"),
+		}
+	}
+
+	#[test]
+	fn rejects_javascript_expressions() {
+		let diagnostics = parse_json(r#"{ "a": foo }"#, 0).unwrap_err();
+
+		assert_eq!(diagnostics[0].title, "expected a JSON value");
+		assert_eq!(diagnostics[0].primary.as_ref().unwrap().span.range, 7..10);
+	}
+
+	#[test]
+	fn rejects_trailing_content() {
+		let diagnostics = parse_json(r#"{ "a": 1 } garbage"#, 0).unwrap_err();
+
+		assert_eq!(diagnostics[0].title, "expected the end of the file");
+	}
+}
@@ -0,0 +1,687 @@
+//! Loading and validation of the `rome.json` configuration file.
+//!
+//! The configuration is discovered by walking up the directories, starting from the working
+//! directory, until a `rome.json` file is found. A configuration looks like this:
+//!
+//! ```json
+//! {
+//!   "formatter": {
+//!     "indentStyle": "space",
+//!     "indentSize": 4,
+//!     "lineWidth": 100
+//!   },
+//!   "json": {
+//!     "formatter": {
+//!       "indentSize": 2
+//!     }
+//!   },
+//!   "ignore": ["**/dist", "scripts/*.generated.js"],
+//!   "overrides": [
+//!     {
+//!       "include": ["legacy/**/*.js"],
+//!       "formatter": { "indentStyle": "space", "indentSize": 4 }
+//!     },
+//!     {
+//!       "include": ["generated/**"],
+//!       "ignore": true
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! The settings of the `javascript` and `json` sections take precedence over the global ones for
+//! the files of the respective language.
+//!
+//! The files and directories matched by one of the `ignore` glob patterns, relative to the
+//! directory of the configuration file, are skipped when looking for the files to process.
+//!
+//! Each block of `overrides` applies to the files matched by one of its `include` glob patterns,
+//! which are relative to the directory of the configuration file. A block can change the settings
+//! of the formatter, disable some [Capabilities] with `"capabilities": { "format": false }`, or
+//! exclude the files entirely with `"ignore": true`. When multiple blocks match a file, they're
+//! applied in order, so the last one wins.
+pub(crate) mod json;
+
+use crate::file_handlers::{Capabilities, Language};
+use crate::fs::FileSystem;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use json::{parse_json, JsonMember, JsonValue, JsonValueKind};
+use rslint_errors::{file::FileId, Diagnostic};
+use std::path::{Path, PathBuf};
+
+/// The name of the configuration file
+pub const CONFIGURATION_FILE_NAME: &str = "rome.json";
+
+/// The code of the diagnostics emitted while validating the configuration
+const DIAGNOSTIC_CODE: &str = "Configuration";
+
+#[derive(Debug, Default, Clone)]
+pub struct Configuration {
+	/// Formatter settings that apply to all languages
+	pub formatter: FormatterConfiguration,
+
+	/// Settings that only apply to JavaScript and TypeScript files
+	pub javascript: LanguageConfiguration,
+
+	/// Settings that only apply to JSON files
+	pub json: LanguageConfiguration,
+
+	/// Glob patterns of the files and directories that are skipped, as written in the configuration
+	pub ignore: Vec<String>,
+
+	/// Matches the glob patterns of `ignore`
+	ignore_matcher: GlobSet,
+
+	/// Settings that only apply to the files matched by some glob patterns
+	pub overrides: Vec<OverrideConfiguration>,
+}
+
+/// Settings that are specific to a language
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct LanguageConfiguration {
+	pub formatter: FormatterConfiguration,
+}
+
+/// Settings of the formatter. Settings that are `None` fall back to the defaults of the formatter.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct FormatterConfiguration {
+	/// Whether to indent with tabs or spaces
+	pub indent_style: Option<IndentKind>,
+
+	/// How many spaces to use per indentation level, only used when indenting with spaces
+	pub indent_size: Option<u8>,
+
+	/// The width at which the formatter wraps lines
+	pub line_width: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IndentKind {
+	Tab,
+	Space,
+}
+
+/// A block of settings that applies to the files matched by its glob patterns
+#[derive(Debug, Clone)]
+pub struct OverrideConfiguration {
+	/// The glob patterns, as written in the configuration
+	pub include: Vec<String>,
+
+	/// Matches the glob patterns of `include`
+	matcher: GlobSet,
+
+	/// Whether the files should be excluded entirely
+	pub ignore: bool,
+
+	/// Capabilities that are turned on or off for the files
+	pub capabilities: CapabilitiesConfiguration,
+
+	/// Formatter settings that take precedence over the ones of the language of the files
+	pub formatter: FormatterConfiguration,
+}
+
+impl OverrideConfiguration {
+	/// Returns `true` if the block applies to the file at `path`, relative to the directory of the
+	/// configuration file
+	pub fn is_match(&self, path: &Path) -> bool {
+		self.matcher.is_match(path)
+	}
+}
+
+/// Capabilities turned on or off by an override. Capabilities that are `None` are left untouched.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct CapabilitiesConfiguration {
+	pub format: Option<bool>,
+	pub lint: Option<bool>,
+}
+
+/// The settings that apply to a single file, once the configuration has been resolved
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileSettings {
+	/// The language of the file
+	pub language: Language,
+
+	/// Whether the file is excluded entirely
+	pub ignored: bool,
+
+	/// The capabilities of the handler of the file, possibly turned off by an override
+	pub capabilities: Capabilities,
+
+	/// The settings of the formatter for the file
+	pub formatter: FormatterConfiguration,
+
+	/// The indices of the overrides that apply to the file, in the order they were applied
+	pub overrides: Vec<usize>,
+}
+
+impl FormatterConfiguration {
+	/// Returns a new configuration where the settings of `other` that are set take precedence
+	/// over the settings of `self`.
+	pub fn merge_with(&self, other: &FormatterConfiguration) -> FormatterConfiguration {
+		FormatterConfiguration {
+			indent_style: other.indent_style.or(self.indent_style),
+			indent_size: other.indent_size.or(self.indent_size),
+			line_width: other.line_width.or(self.line_width),
+		}
+	}
+}
+
+impl Configuration {
+	/// Parses and validates the content of a configuration file.
+	///
+	/// Returns all the diagnostics found in the file if it's not a valid configuration.
+	pub fn from_json(source: &str, file_id: FileId) -> Result<Self, Vec<Diagnostic>> {
+		let root = parse_json(source, file_id)?;
+		let mut visitor = Visitor::new(file_id);
+		let mut configuration = Configuration::default();
+
+		if let Some(members) = visitor.object(&root) {
+			for member in members {
+				match member.key.as_str() {
+					"formatter" => {
+						configuration.formatter = visitor.formatter(&member.value);
+					}
+					"javascript" => {
+						configuration.javascript = visitor.language(&member.value);
+					}
+					"json" => {
+						configuration.json = visitor.language(&member.value);
+					}
+					"ignore" => {
+						if let Some((ignore, matcher)) = visitor.globs(&member.value) {
+							configuration.ignore = ignore;
+							configuration.ignore_matcher = matcher;
+						}
+					}
+					"overrides" => {
+						configuration.overrides = visitor.overrides(&member.value);
+					}
+					_ => visitor.unknown_key(
+						member,
+						&["formatter", "javascript", "json", "ignore", "overrides"],
+					),
+				}
+			}
+		}
+
+		visitor.finish(configuration)
+	}
+
+	/// Returns the formatter settings for the files of the given language
+	pub fn formatter_for(&self, language: &Language) -> FormatterConfiguration {
+		match language {
+			Language::Js | Language::Ts => self.formatter.merge_with(&self.javascript.formatter),
+			Language::Json => self.formatter.merge_with(&self.json.formatter),
+			Language::Unknown => self.formatter,
+		}
+	}
+
+	/// Returns the first pattern of `ignore` that matches `path`, relative to the directory of the
+	/// configuration file
+	pub fn ignore_pattern(&self, path: &Path) -> Option<&str> {
+		self.ignore_matcher
+			.matches(path)
+			.first()
+			.map(|index| self.ignore[*index].as_str())
+	}
+
+	/// Resolves the settings of a file.
+	///
+	/// `path` is relative to the directory of the configuration file, or `None` if the file is
+	/// outside of it, in which case no override applies. `capabilities` are the ones of the
+	/// handler of the file.
+	pub fn settings_for(
+		&self,
+		path: Option<&Path>,
+		language: Language,
+		capabilities: Capabilities,
+	) -> FileSettings {
+		let mut settings = FileSettings {
+			language,
+			ignored: false,
+			capabilities,
+			formatter: self.formatter_for(&language),
+			overrides: vec![],
+		};
+
+		let path = match path {
+			Some(path) => path,
+			None => return settings,
+		};
+
+		for (index, override_configuration) in self.overrides.iter().enumerate() {
+			if !override_configuration.is_match(path) {
+				continue;
+			}
+
+			// An override can only turn off the capabilities that the handler supports
+			let capabilities = &override_configuration.capabilities;
+			settings.capabilities.format &= capabilities.format.unwrap_or(true);
+			settings.capabilities.lint &= capabilities.lint.unwrap_or(true);
+			settings.ignored |= override_configuration.ignore;
+			settings.formatter = settings
+				.formatter
+				.merge_with(&override_configuration.formatter);
+			settings.overrides.push(index);
+		}
+
+		settings
+	}
+}
+
+/// Walks up the directories, starting from `directory`, and returns the path of the first
+/// configuration file that it finds.
+pub fn find_configuration_file(fs: &dyn FileSystem, directory: &Path) -> Option<PathBuf> {
+	directory
+		.ancestors()
+		.map(|directory| directory.join(CONFIGURATION_FILE_NAME))
+		.find(|path| fs.is_file(path))
+}
+
+/// Validates the JSON values of a configuration and collects the diagnostics of the invalid ones
+struct Visitor {
+	file_id: FileId,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl Visitor {
+	fn new(file_id: FileId) -> Self {
+		Self {
+			file_id,
+			diagnostics: vec![],
+		}
+	}
+
+	fn finish(self, configuration: Configuration) -> Result<Configuration, Vec<Diagnostic>> {
+		if self.diagnostics.is_empty() {
+			Ok(configuration)
+		} else {
+			Err(self.diagnostics)
+		}
+	}
+
+	fn language(&mut self, value: &JsonValue) -> LanguageConfiguration {
+		let mut language = LanguageConfiguration::default();
+
+		if let Some(members) = self.object(value) {
+			for member in members {
+				match member.key.as_str() {
+					"formatter" => language.formatter = self.formatter(&member.value),
+					_ => self.unknown_key(member, &["formatter"]),
+				}
+			}
+		}
+
+		language
+	}
+
+	fn formatter(&mut self, value: &JsonValue) -> FormatterConfiguration {
+		let mut formatter = FormatterConfiguration::default();
+
+		if let Some(members) = self.object(value) {
+			for member in members {
+				match member.key.as_str() {
+					"indentStyle" => {
+						formatter.indent_style = match self.string(&member.value) {
+							Some("tab") => Some(IndentKind::Tab),
+							Some("space") => Some(IndentKind::Space),
+							Some(_) => {
+								self.invalid_value(&member.value, "expected \"tab\" or \"space\"");
+								None
+							}
+							None => None,
+						}
+					}
+					"indentSize" => {
+						formatter.indent_size = self
+							.integer(&member.value, 1, u8::MAX as u64)
+							.map(|size| size as u8)
+					}
+					"lineWidth" => {
+						formatter.line_width = self
+							.integer(&member.value, 1, u16::MAX as u64)
+							.map(|width| width as u16)
+					}
+					_ => self.unknown_key(member, &["indentStyle", "indentSize", "lineWidth"]),
+				}
+			}
+		}
+
+		formatter
+	}
+
+	fn overrides(&mut self, value: &JsonValue) -> Vec<OverrideConfiguration> {
+		self.array(value)
+			.map(|values| {
+				values
+					.iter()
+					.filter_map(|value| self.override_configuration(value))
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+
+	fn override_configuration(&mut self, value: &JsonValue) -> Option<OverrideConfiguration> {
+		let members = self.object(value)?;
+		let mut include = None;
+		let mut ignore = false;
+		let mut capabilities = CapabilitiesConfiguration::default();
+		let mut formatter = FormatterConfiguration::default();
+
+		for member in members {
+			match member.key.as_str() {
+				"include" => include = self.globs(&member.value),
+				"ignore" => ignore = self.boolean(&member.value).unwrap_or_default(),
+				"capabilities" => capabilities = self.capabilities(&member.value),
+				"formatter" => formatter = self.formatter(&member.value),
+				_ => self.unknown_key(member, &["include", "ignore", "capabilities", "formatter"]),
+			}
+		}
+
+		match include {
+			Some((include, matcher)) => Some(OverrideConfiguration {
+				include,
+				matcher,
+				ignore,
+				capabilities,
+				formatter,
+			}),
+			None => {
+				if !members.iter().any(|member| member.key == "include") {
+					self.diagnostics.push(
+						Diagnostic::error(self.file_id, DIAGNOSTIC_CODE, "missing key \"include\"")
+							.primary(
+								value.range,
+								"an override needs the glob patterns of the files it applies to",
+							),
+					);
+				}
+				None
+			}
+		}
+	}
+
+	/// Validates an array of glob patterns and compiles them into a single matcher
+	fn globs(&mut self, value: &JsonValue) -> Option<(Vec<String>, GlobSet)> {
+		let values = self.array(value)?;
+		let mut patterns = Vec::with_capacity(values.len());
+		let mut builder = GlobSetBuilder::new();
+		let mut is_valid = true;
+
+		for value in values {
+			let pattern = match self.string(value) {
+				Some(pattern) => pattern,
+				None => {
+					is_valid = false;
+					continue;
+				}
+			};
+
+			match GlobBuilder::new(pattern).literal_separator(true).build() {
+				Ok(glob) => {
+					builder.add(glob);
+					patterns.push(pattern.to_string());
+				}
+				Err(error) => {
+					self.invalid_value(value, format!("invalid glob pattern: {}", error.kind()));
+					is_valid = false;
+				}
+			}
+		}
+
+		if !is_valid {
+			return None;
+		}
+
+		match builder.build() {
+			Ok(matcher) => Some((patterns, matcher)),
+			Err(error) => {
+				self.invalid_value(value, format!("invalid glob patterns: {}", error));
+				None
+			}
+		}
+	}
+
+	fn capabilities(&mut self, value: &JsonValue) -> CapabilitiesConfiguration {
+		let mut capabilities = CapabilitiesConfiguration::default();
+
+		if let Some(members) = self.object(value) {
+			for member in members {
+				match member.key.as_str() {
+					"format" => capabilities.format = self.boolean(&member.value),
+					"lint" => capabilities.lint = self.boolean(&member.value),
+					_ => self.unknown_key(member, &["format", "lint"]),
+				}
+			}
+		}
+
+		capabilities
+	}
+
+	fn object<'a>(&mut self, value: &'a JsonValue) -> Option<&'a [JsonMember]> {
+		match &value.kind {
+			JsonValueKind::Object(members) => Some(members),
+			kind => {
+				self.invalid_type(value, "an object", kind);
+				None
+			}
+		}
+	}
+
+	fn array<'a>(&mut self, value: &'a JsonValue) -> Option<&'a [JsonValue]> {
+		match &value.kind {
+			JsonValueKind::Array(values) => Some(values),
+			kind => {
+				self.invalid_type(value, "an array", kind);
+				None
+			}
+		}
+	}
+
+	fn boolean(&mut self, value: &JsonValue) -> Option<bool> {
+		match &value.kind {
+			JsonValueKind::Bool(value) => Some(*value),
+			kind => {
+				self.invalid_type(value, "a boolean", kind);
+				None
+			}
+		}
+	}
+
+	fn string<'a>(&mut self, value: &'a JsonValue) -> Option<&'a str> {
+		match &value.kind {
+			JsonValueKind::String(string) => Some(string),
+			kind => {
+				self.invalid_type(value, "a string", kind);
+				None
+			}
+		}
+	}
+
+	/// Validates that the value is an integer between `min` and `max` (inclusive)
+	fn integer(&mut self, value: &JsonValue, min: u64, max: u64) -> Option<u64> {
+		match &value.kind {
+			JsonValueKind::Number(number)
+				if number.fract() == 0.0 && *number >= min as f64 && *number <= max as f64 =>
+			{
+				Some(*number as u64)
+			}
+			JsonValueKind::Number(_) => {
+				self.invalid_value(
+					value,
+					format!("expected an integer between {} and {}", min, max),
+				);
+				None
+			}
+			kind => {
+				self.invalid_type(value, "a number", kind);
+				None
+			}
+		}
+	}
+
+	fn unknown_key(&mut self, member: &JsonMember, known_keys: &[&str]) {
+		let known_keys = known_keys
+			.iter()
+			.map(|key| format!("\"{}\"", key))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		self.diagnostics.push(
+			Diagnostic::error(
+				self.file_id,
+				DIAGNOSTIC_CODE,
+				format!("unknown key \"{}\"", member.key),
+			)
+			.primary(member.key_range, "this key isn't supported")
+			.footer_help(format!("the supported keys are: {}", known_keys)),
+		);
+	}
+
+	fn invalid_type(&mut self, value: &JsonValue, expected: &str, found: &JsonValueKind) {
+		self.diagnostics.push(
+			Diagnostic::error(
+				self.file_id,
+				DIAGNOSTIC_CODE,
+				format!("expected {}, found {}", expected, found.type_name()),
+			)
+			.primary(value.range, format!("expected {}", expected)),
+		);
+	}
+
+	fn invalid_value(&mut self, value: &JsonValue, message: impl Into<String>) {
+		let message = message.into();
+		self.diagnostics.push(
+			Diagnostic::error(self.file_id, DIAGNOSTIC_CODE, "invalid value")
+				.primary(value.range, message),
+		);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Configuration, FormatterConfiguration, IndentKind};
+	use crate::file_handlers::{Capabilities, Language};
+	use std::path::Path;
+
+	#[test]
+	fn parses_the_formatter_settings() {
+		let configuration = Configuration::from_json(
+			r#"{
+				"formatter": { "indentStyle": "space", "indentSize": 4, "lineWidth": 100 },
+				"json": { "formatter": { "indentSize": 2 } }
+			}"#,
+			0,
+		)
+		.unwrap();
+
+		assert_eq!(
+			configuration.formatter_for(&Language::Js),
+			FormatterConfiguration {
+				indent_style: Some(IndentKind::Space),
+				indent_size: Some(4),
+				line_width: Some(100),
+			}
+		);
+		assert_eq!(
+			configuration.formatter_for(&Language::Json),
+			FormatterConfiguration {
+				indent_style: Some(IndentKind::Space),
+				indent_size: Some(2),
+				line_width: Some(100),
+			}
+		);
+	}
+
+	#[test]
+	fn reports_unknown_keys_and_invalid_values() {
+		let source =
+			r#"{ "formater": {}, "formatter": { "indentStyle": "tabs", "lineWidth": "80" } }"#;
+		let diagnostics = Configuration::from_json(source, 0).unwrap_err();
+
+		let titles: Vec<_> = diagnostics.iter().map(|d| d.title.as_str()).collect();
+		assert_eq!(
+			titles,
+			vec![
+				"unknown key \"formater\"",
+				"invalid value",
+				"expected a number, found a string"
+			]
+		);
+
+		let ranges: Vec<_> = diagnostics
+			.iter()
+			.map(|d| &source[d.primary.as_ref().unwrap().span.range.clone()])
+			.collect();
+		assert_eq!(ranges, vec!["\"formater\"", "\"tabs\"", "\"80\""]);
+	}
+
+	#[test]
+	fn applies_the_matching_overrides_in_order() {
+		let configuration = Configuration::from_json(
+			r#"{
+				"formatter": { "indentStyle": "tab" },
+				"overrides": [
+					{ "include": ["legacy/**/*.js"], "formatter": { "indentStyle": "space", "indentSize": 4 } },
+					{ "include": ["legacy/vendor/**"], "capabilities": { "format": false } },
+					{ "include": ["generated/**", "*.min.js"], "ignore": true }
+				]
+			}"#,
+			0,
+		)
+		.unwrap();
+		let capabilities = Capabilities {
+			format: true,
+			lint: true,
+		};
+
+		let settings = configuration.settings_for(
+			Some(Path::new("legacy/vendor/lib.js")),
+			Language::Js,
+			capabilities,
+		);
+		assert_eq!(settings.overrides, vec![0, 1]);
+		assert!(!settings.ignored);
+		assert_eq!(
+			settings.capabilities,
+			Capabilities {
+				format: false,
+				lint: true,
+			}
+		);
+		assert_eq!(settings.formatter.indent_style, Some(IndentKind::Space));
+		assert_eq!(settings.formatter.indent_size, Some(4));
+
+		let settings =
+			configuration.settings_for(Some(Path::new("src/index.js")), Language::Js, capabilities);
+		assert!(settings.overrides.is_empty());
+		assert_eq!(settings.formatter.indent_style, Some(IndentKind::Tab));
+
+		// `*` doesn't match the separators, so only the files at the root are matched
+		let settings =
+			configuration.settings_for(Some(Path::new("a.min.js")), Language::Js, capabilities);
+		assert!(settings.ignored);
+		let settings =
+			configuration.settings_for(Some(Path::new("src/a.min.js")), Language::Js, capabilities);
+		assert!(!settings.ignored);
+
+		// No override applies to the files outside of the directory of the configuration
+		let settings = configuration.settings_for(None, Language::Js, capabilities);
+		assert!(settings.overrides.is_empty());
+	}
+
+	#[test]
+	fn reports_invalid_overrides() {
+		let source = r#"{ "overrides": [{ "ignore": true }, { "include": ["a/[b"] }] }"#;
+		let diagnostics = Configuration::from_json(source, 0).unwrap_err();
+
+		let titles: Vec<_> = diagnostics.iter().map(|d| d.title.as_str()).collect();
+		assert_eq!(titles, vec!["missing key \"include\"", "invalid value"]);
+
+		let ranges: Vec<_> = diagnostics
+			.iter()
+			.map(|d| &source[d.primary.as_ref().unwrap().span.range.clone()])
+			.collect();
+		assert_eq!(ranges, vec!["{ \"ignore\": true }", "\"a/[b\""]);
+	}
+}
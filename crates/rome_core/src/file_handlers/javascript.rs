@@ -1,7 +1,79 @@
 use super::{ExtensionHandler, Mime};
+use crate::configuration::json::{parse_json, JsonValueKind};
+use crate::fs::{FileKind, FileSystem};
+use crate::normalize;
+use rslint_parser::{tokenize, FileKind as SyntaxFileKind, Syntax, SyntaxKind, T};
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct JsFileHandler {}
+/// How the JavaScript code of a file is loaded, which decides the grammar used to parse it
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ModuleKind {
+	/// An ECMAScript module, it can use `import` and `export` declarations
+	Module,
+	/// A CommonJS module, a script that can `return` at the top level
+	CommonJs,
+	/// A classic script
+	Script,
+}
+
+impl ModuleKind {
+	/// The syntax used to parse the files of this kind
+	pub fn syntax(self) -> Syntax {
+		match self {
+			ModuleKind::Module => Syntax::default().module(),
+			ModuleKind::CommonJs => Syntax::default().global_return(),
+			ModuleKind::Script => Syntax::default(),
+		}
+	}
+
+	/// The syntax used to parse the TypeScript files of this kind, only the modules can use
+	/// `await` at the top level
+	pub fn typescript_syntax(self) -> Syntax {
+		match self {
+			ModuleKind::Module => Syntax::default().typescript(),
+			ModuleKind::CommonJs | ModuleKind::Script => Syntax {
+				file_kind: SyntaxFileKind::TypeScript,
+				..Syntax::default()
+			},
+		}
+	}
+}
+
+/// Handler of the JavaScript files.
+///
+/// The extensions `.mjs` and `.cjs` decide the kind of module of a file. For the other extensions,
+/// the kind is read from the `"type"` field of the nearest `package.json`, and when it isn't set,
+/// it's deduced from the content of the file, see [JsFileHandler::module_kind].
+#[derive(Debug, Default)]
+pub struct JsFileHandler {
+	module_kind: Option<ModuleKind>,
+	manifests: PackageManifests,
+}
+
+impl JsFileHandler {
+	/// A handler for the files that are always loaded as `module_kind`, like `.mjs` files
+	pub fn with_module_kind(module_kind: ModuleKind) -> Self {
+		Self {
+			module_kind: Some(module_kind),
+			manifests: PackageManifests::default(),
+		}
+	}
+
+	/// Returns the kind of module of the file at `path`, from the first of:
+	/// - the kind of the handler, when its extension decides it;
+	/// - the `"type"` field of the nearest `package.json`, either `"module"` or `"commonjs"`;
+	/// - the content of the file: a file with `import` or `export` declarations is a module,
+	///   an executable file starting with a shebang is a CommonJS module, any other file is a script.
+	pub fn module_kind(&self, fs: &dyn FileSystem, path: &Path, source: &str) -> ModuleKind {
+		self.module_kind
+			.or_else(|| self.manifests.module_kind(fs, path))
+			.unwrap_or_else(|| sniff_module_kind(source))
+	}
+}
 
 impl ExtensionHandler for JsFileHandler {
 	fn capabilities(&self) -> super::Capabilities {
@@ -22,4 +94,222 @@ impl ExtensionHandler for JsFileHandler {
 	fn may_use_tabs(&self) -> bool {
 		true
 	}
+
+	fn syntax(&self, fs: &dyn FileSystem, path: &Path, source: &str) -> Option<Syntax> {
+		Some(self.module_kind(fs, path, source).syntax())
+	}
+}
+
+/// The kinds of module read from the `package.json` files, by directory.
+///
+/// The files are formatted in parallel and most of them share the same manifests, so a manifest is
+/// only read and parsed again when its size or its time of modification changed. The edits of the
+/// manifests are still seen by the commands that watch the files and by the language server.
+#[derive(Debug, Default)]
+pub(crate) struct PackageManifests {
+	manifests: RwLock<HashMap<PathBuf, PackageManifest>>,
+}
+
+/// What was read from the `package.json` of a directory
+#[derive(Debug, Clone, Copy)]
+struct PackageManifest {
+	modified: Option<SystemTime>,
+	len: u64,
+	module_kind: Option<ModuleKind>,
+}
+
+impl PackageManifests {
+	/// Reads the kind of module from the `package.json` closest to `path`.
+	///
+	/// Returns `None` if there's no `package.json`, or if the closest one doesn't have a valid `"type"`.
+	pub(crate) fn module_kind(&self, fs: &dyn FileSystem, path: &Path) -> Option<ModuleKind> {
+		let path = normalize(&env::current_dir().ok()?.join(path));
+
+		path.ancestors()
+			.skip(1)
+			.find_map(|directory| self.read(fs, directory))
+			.flatten()
+	}
+
+	/// Returns the kind of module set by the `package.json` of `directory`, or `None` if the
+	/// directory doesn't have a `package.json`
+	fn read(&self, fs: &dyn FileSystem, directory: &Path) -> Option<Option<ModuleKind>> {
+		let path = directory.join("package.json");
+		let metadata = fs.metadata(&path).ok()?;
+		if metadata.kind != FileKind::File {
+			return None;
+		}
+
+		let cached = self.manifests.read().unwrap().get(directory).copied();
+		if let Some(manifest) = cached {
+			if manifest.modified == metadata.modified && manifest.len == metadata.len {
+				return Some(manifest.module_kind);
+			}
+		}
+
+		let module_kind = manifest_module_kind(&fs.read_to_string(&path).ok()?);
+		self.manifests.write().unwrap().insert(
+			directory.to_path_buf(),
+			PackageManifest {
+				modified: metadata.modified,
+				len: metadata.len,
+				module_kind,
+			},
+		);
+		Some(module_kind)
+	}
+}
+
+/// Returns the kind of module set by the `"type"` field of the source of a `package.json`
+fn manifest_module_kind(manifest: &str) -> Option<ModuleKind> {
+	let members = match parse_json(manifest, 0).ok()?.kind {
+		JsonValueKind::Object(members) => members,
+		_ => return None,
+	};
+
+	let member = members.into_iter().find(|member| member.key == "type")?;
+	match member.value.kind {
+		JsonValueKind::String(kind) if kind == "module" => Some(ModuleKind::Module),
+		JsonValueKind::String(kind) if kind == "commonjs" => Some(ModuleKind::CommonJs),
+		_ => None,
+	}
+}
+
+/// Deduces the kind of module from the tokens of the source.
+///
+/// An `export` or an `import` that isn't a dynamic import, outside of any bracket, makes the file
+/// a module, as does `import.meta` anywhere in the file.
+pub(crate) fn sniff_module_kind(source: &str) -> ModuleKind {
+	let (tokens, _) = tokenize(source, 0);
+	let mut kinds = tokens
+		.iter()
+		.map(|token| token.kind)
+		.filter(|kind| !kind.is_trivia());
+
+	let mut depth = 0usize;
+	let mut previous = None;
+
+	while let Some(kind) = kinds.next() {
+		let is_member = matches!(previous, Some(T![.]) | Some(T![?.]));
+
+		match kind {
+			T!['('] | T!['['] | T!['{'] | SyntaxKind::DOLLAR_CURLY => depth += 1,
+			T![')'] | T![']'] | T!['}'] => depth = depth.saturating_sub(1),
+			T![export] if depth == 0 && !is_member => return ModuleKind::Module,
+			T![import] if !is_member => match kinds.next() {
+				Some(T![.]) => return ModuleKind::Module,
+				Some(T!['(']) => depth += 1,
+				_ if depth == 0 => return ModuleKind::Module,
+				_ => {}
+			},
+			_ => {}
+		}
+
+		previous = Some(kind);
+	}
+
+	if source.starts_with("#!") {
+		ModuleKind::CommonJs
+	} else {
+		ModuleKind::Script
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{manifest_module_kind, sniff_module_kind, ModuleKind, PackageManifests};
+	use crate::fs::MemoryFileSystem;
+	use std::path::Path;
+
+	#[test]
+	fn sniffs_module_declarations() {
+		assert_eq!(sniff_module_kind("import a from 'a';"), ModuleKind::Module);
+		assert_eq!(sniff_module_kind("import 'a';"), ModuleKind::Module);
+		assert_eq!(sniff_module_kind("export const a = 1;"), ModuleKind::Module);
+		assert_eq!(
+			sniff_module_kind("function f() { return import.meta.url; }"),
+			ModuleKind::Module
+		);
+	}
+
+	#[test]
+	fn sniffs_scripts() {
+		assert_eq!(sniff_module_kind("let a = 1;"), ModuleKind::Script);
+		assert_eq!(
+			sniff_module_kind("import('a').then(f);"),
+			ModuleKind::Script
+		);
+		assert_eq!(
+			sniff_module_kind("a.export(); a.import;"),
+			ModuleKind::Script
+		);
+		assert_eq!(
+			sniff_module_kind("let a = { import: 1, export: 2 };"),
+			ModuleKind::Script
+		);
+		assert_eq!(
+			sniff_module_kind("// export a\nlet a = `${import('a')}`;"),
+			ModuleKind::Script
+		);
+		assert_eq!(
+			sniff_module_kind("#!/usr/bin/env node\nreturn;"),
+			ModuleKind::CommonJs
+		);
+		assert_eq!(
+			sniff_module_kind("#!/usr/bin/env node\nimport a from 'a';"),
+			ModuleKind::Module
+		);
+	}
+
+	#[test]
+	fn reads_the_type_of_the_manifest() {
+		assert_eq!(
+			manifest_module_kind(r#"{ "name": "a", "type": "module" }"#),
+			Some(ModuleKind::Module)
+		);
+		assert_eq!(
+			manifest_module_kind(r#"{ "type": "commonjs" }"#),
+			Some(ModuleKind::CommonJs)
+		);
+		assert_eq!(manifest_module_kind(r#"{ "type": "other" }"#), None);
+		assert_eq!(manifest_module_kind(r#"{ "name": "a" }"#), None);
+		assert_eq!(manifest_module_kind("{ invalid"), None);
+	}
+
+	#[test]
+	fn uses_the_nearest_manifest() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("project/package.json", r#"{ "type": "module" }"#);
+		fs.insert(
+			"project/packages/nested/package.json",
+			r#"{ "type": "commonjs" }"#,
+		);
+
+		let manifests = PackageManifests::default();
+		assert_eq!(
+			manifests.module_kind(&fs, Path::new("project/src/index.js")),
+			Some(ModuleKind::Module)
+		);
+		assert_eq!(
+			manifests.module_kind(&fs, Path::new("project/packages/nested/src/index.js")),
+			Some(ModuleKind::CommonJs)
+		);
+	}
+
+	#[test]
+	fn reads_the_manifests_again_when_they_change() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("project/package.json", r#"{ "type": "module" }"#);
+
+		let manifests = PackageManifests::default();
+		let path = Path::new("project/src/index.js");
+		assert_eq!(manifests.module_kind(&fs, path), Some(ModuleKind::Module));
+		assert_eq!(manifests.module_kind(&fs, path), Some(ModuleKind::Module));
+
+		fs.insert("project/package.json", r#"{ "type": "commonjs" }"#);
+		assert_eq!(manifests.module_kind(&fs, path), Some(ModuleKind::CommonJs));
+
+		fs.insert("project/package.json", r#"{ "name": "project" }"#);
+		assert_eq!(manifests.module_kind(&fs, path), None);
+	}
 }
@@ -1,7 +1,13 @@
 pub mod javascript;
 pub mod json;
+pub mod typescript;
 pub mod unknown;
 
+use crate::fs::FileSystem;
+use rslint_parser::Syntax;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Language {
 	Js,
 	Json,
@@ -11,6 +17,7 @@ pub enum Language {
 
 pub enum Mime {
 	Javascript,
+	Typescript,
 	Json,
 	Css,
 	Text,
@@ -22,19 +29,22 @@ impl std::fmt::Display for Mime {
 			Mime::Css => write!(f, "text/css"),
 			Mime::Json => write!(f, "application/json"),
 			Mime::Javascript => write!(f, "application/javascript"),
+			Mime::Typescript => write!(f, "application/typescript"),
 			Mime::Text => write!(f, "text/plain"),
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct Capabilities {
 	pub lint: bool,
 	pub format: bool,
 }
 
 /// Main trait to use to add a new language to Rome
-pub trait ExtensionHandler {
+///
+/// Handlers are shared between the threads that process the files, that's why they must be [Send] and [Sync].
+pub trait ExtensionHandler: Send + Sync {
 	/// The language of the file. It can be a super language.
 	/// For example, a ".js" file can have [Language::Ts]
 	fn language(&self) -> Language;
@@ -61,4 +71,12 @@ pub trait ExtensionHandler {
 	fn is_asset(&self) -> bool {
 		false
 	}
+
+	/// The syntax used to parse the file at `path` with the JavaScript parser.
+	///
+	/// Returns `None` for the languages that aren't parsed as JavaScript. The handler may look at
+	/// the content of the file, and at the files around it through `fs`, to know if the file is a module.
+	fn syntax(&self, _fs: &dyn FileSystem, _path: &Path, _source: &str) -> Option<Syntax> {
+		None
+	}
 }
@@ -0,0 +1,93 @@
+use super::javascript::{sniff_module_kind, ModuleKind, PackageManifests};
+use super::{ExtensionHandler, Mime};
+use crate::fs::FileSystem;
+use rslint_parser::Syntax;
+use std::path::Path;
+
+/// Handler of the TypeScript files.
+///
+/// Like for the JavaScript files, the extensions `.mts` and `.cts` decide the kind of module of a
+/// file, and the kind of the other files is read from the nearest `package.json` or deduced from
+/// their content, see [TsFileHandler::module_kind].
+#[derive(Debug, Default)]
+pub struct TsFileHandler {
+	module_kind: Option<ModuleKind>,
+	manifests: PackageManifests,
+}
+
+impl TsFileHandler {
+	/// A handler for the files that are always loaded as `module_kind`, like `.mts` files
+	pub fn with_module_kind(module_kind: ModuleKind) -> Self {
+		Self {
+			module_kind: Some(module_kind),
+			manifests: PackageManifests::default(),
+		}
+	}
+
+	/// Returns the kind of module of the file at `path`, the same way as
+	/// [JsFileHandler::module_kind](super::javascript::JsFileHandler::module_kind)
+	pub fn module_kind(&self, fs: &dyn FileSystem, path: &Path, source: &str) -> ModuleKind {
+		self.module_kind
+			.or_else(|| self.manifests.module_kind(fs, path))
+			.unwrap_or_else(|| sniff_module_kind(source))
+	}
+}
+
+impl ExtensionHandler for TsFileHandler {
+	fn capabilities(&self) -> super::Capabilities {
+		super::Capabilities {
+			format: true,
+			lint: true,
+		}
+	}
+
+	fn language(&self) -> super::Language {
+		super::Language::Ts
+	}
+
+	fn mime(&self) -> super::Mime {
+		Mime::Typescript
+	}
+
+	fn may_use_tabs(&self) -> bool {
+		true
+	}
+
+	fn syntax(&self, fs: &dyn FileSystem, path: &Path, source: &str) -> Option<Syntax> {
+		Some(self.module_kind(fs, path, source).typescript_syntax())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::TsFileHandler;
+	use crate::file_handlers::javascript::ModuleKind;
+	use crate::file_handlers::ExtensionHandler;
+	use crate::fs::MemoryFileSystem;
+	use std::path::Path;
+
+	#[test]
+	fn only_lets_the_modules_await_at_the_top_level() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("module/package.json", r#"{ "type": "module" }"#);
+		fs.insert("commonjs/package.json", r#"{ "type": "commonjs" }"#);
+
+		let source = "await a;";
+		let ts = TsFileHandler::default();
+		let mts = TsFileHandler::with_module_kind(ModuleKind::Module);
+		let cts = TsFileHandler::with_module_kind(ModuleKind::CommonJs);
+
+		let syntax = |handler: &TsFileHandler, path: &str| {
+			handler
+				.syntax(&fs, Path::new(path), source)
+				.unwrap()
+				.top_level_await
+		};
+
+		assert!(syntax(&mts, "commonjs/index.mts"));
+		assert!(!syntax(&cts, "module/index.cts"));
+		assert!(syntax(&ts, "module/index.ts"));
+		assert!(!syntax(&ts, "commonjs/index.ts"));
+		assert!(!syntax(&ts, "index.ts"));
+	}
+}
@@ -0,0 +1,362 @@
+//! Access to the files read and written by Rome.
+//!
+//! The [App] owns a [FileSystem], and everything that reads or writes files goes through it:
+//! the discovery of the files, the ignore files, the `package.json` lookups, and the formatting
+//! of the files. [OsFileSystem] accesses the disk, and [MemoryFileSystem] keeps the files in
+//! memory, so that the commands and the editor integrations can run against virtual files.
+//!
+//! [App]: crate::App
+use crate::normalize;
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+/// The kind of an entry of the file system
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FileKind {
+	File,
+	Directory,
+}
+
+/// What's known about a file or a directory
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Metadata {
+	pub kind: FileKind,
+	/// The size of the file in bytes
+	pub len: u64,
+	/// The time of the last modification, if the file system records it
+	pub modified: Option<SystemTime>,
+}
+
+/// An entry of a directory, see [FileSystem::read_dir]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DirEntry {
+	/// The name of the entry, without the path of the directory
+	pub name: OsString,
+	pub kind: FileKind,
+}
+
+/// The operations that Rome needs to access the files.
+///
+/// Relative paths are resolved from the working directory. The failures are returned as
+/// [io::Error], so that the callers decide how to report them.
+pub trait FileSystem: Send + Sync {
+	/// Reads the whole content of a file
+	fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+	/// Replaces the content of a file, creating the file if it doesn't exist
+	fn write(&self, path: &Path, content: &str) -> io::Result<()>;
+
+	/// Deletes a file
+	fn remove_file(&self, path: &Path) -> io::Result<()>;
+
+	/// Creates a directory and all its missing parents
+	fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
+	/// Returns the metadata of a file or a directory
+	fn metadata(&self, path: &Path) -> io::Result<Metadata>;
+
+	/// Returns the entries of a directory, in no particular order
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>>;
+
+	/// Returns `true` if `path` is an existing file
+	fn is_file(&self, path: &Path) -> bool {
+		self.metadata(path)
+			.map_or(false, |metadata| metadata.kind == FileKind::File)
+	}
+
+	/// Returns `true` if `path` is an existing directory
+	fn is_dir(&self, path: &Path) -> bool {
+		self.metadata(path)
+			.map_or(false, |metadata| metadata.kind == FileKind::Directory)
+	}
+}
+
+/// The file system of the operating system
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+	fn read_to_string(&self, path: &Path) -> io::Result<String> {
+		fs::read_to_string(path)
+	}
+
+	fn write(&self, path: &Path, content: &str) -> io::Result<()> {
+		fs::write(path, content)
+	}
+
+	fn remove_file(&self, path: &Path) -> io::Result<()> {
+		fs::remove_file(path)
+	}
+
+	fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+		fs::create_dir_all(path)
+	}
+
+	fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+		let metadata = fs::metadata(path)?;
+		Ok(Metadata {
+			kind: if metadata.is_dir() {
+				FileKind::Directory
+			} else {
+				FileKind::File
+			},
+			len: metadata.len(),
+			modified: metadata.modified().ok(),
+		})
+	}
+
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+		let mut entries = vec![];
+
+		// Like the other operations, the symbolic links are followed
+		for entry in fs::read_dir(path)?.flatten() {
+			let kind = match fs::metadata(entry.path()) {
+				Ok(metadata) if metadata.is_dir() => FileKind::Directory,
+				Ok(_) => FileKind::File,
+				Err(_) => continue,
+			};
+			entries.push(DirEntry {
+				name: entry.file_name(),
+				kind,
+			});
+		}
+
+		Ok(entries)
+	}
+}
+
+/// A file system that keeps the files in memory.
+///
+/// The directories aren't stored, a directory exists as long as it contains a file.
+///
+/// ```
+/// use rome_core::fs::{FileSystem, MemoryFileSystem};
+/// use std::path::Path;
+///
+/// let fs = MemoryFileSystem::default();
+/// fs.insert("src/index.js", "let a = 1;");
+///
+/// assert!(fs.is_dir(Path::new("src")));
+/// assert_eq!(fs.read_to_string(Path::new("./src/index.js")).unwrap(), "let a = 1;");
+/// ```
+#[derive(Debug)]
+pub struct MemoryFileSystem {
+	/// Used to resolve the relative paths, like the file system of the operating system does
+	current_dir: PathBuf,
+	/// The files by absolute path
+	files: RwLock<BTreeMap<PathBuf, MemoryFile>>,
+}
+
+#[derive(Debug, Clone)]
+struct MemoryFile {
+	content: String,
+	modified: SystemTime,
+}
+
+impl Default for MemoryFileSystem {
+	fn default() -> Self {
+		Self {
+			current_dir: env::current_dir().unwrap_or_default(),
+			files: RwLock::default(),
+		}
+	}
+}
+
+impl MemoryFileSystem {
+	/// Creates or replaces a file
+	pub fn insert(&self, path: impl AsRef<Path>, content: impl Into<String>) {
+		let path = self.resolve(path.as_ref());
+		self.files.write().unwrap().insert(
+			path,
+			MemoryFile {
+				content: content.into(),
+				modified: SystemTime::now(),
+			},
+		);
+	}
+
+	/// Deletes a file, and returns its content if it existed
+	pub fn remove(&self, path: impl AsRef<Path>) -> Option<String> {
+		let path = self.resolve(path.as_ref());
+		self.files
+			.write()
+			.unwrap()
+			.remove(&path)
+			.map(|file| file.content)
+	}
+
+	fn resolve(&self, path: &Path) -> PathBuf {
+		normalize(&self.current_dir.join(path))
+	}
+}
+
+impl FileSystem for MemoryFileSystem {
+	fn read_to_string(&self, path: &Path) -> io::Result<String> {
+		let path = self.resolve(path);
+		self.files
+			.read()
+			.unwrap()
+			.get(&path)
+			.map(|file| file.content.clone())
+			.ok_or_else(|| not_found(&path))
+	}
+
+	fn write(&self, path: &Path, content: &str) -> io::Result<()> {
+		if self.is_dir(path) {
+			return Err(io::Error::new(
+				io::ErrorKind::Other,
+				format!("{} is a directory", path.display()),
+			));
+		}
+
+		self.insert(path, content);
+		Ok(())
+	}
+
+	fn remove_file(&self, path: &Path) -> io::Result<()> {
+		self.remove(path).map(|_| ()).ok_or_else(|| not_found(path))
+	}
+
+	fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+		// The directories are implied by the files they contain
+		if self.is_file(path) {
+			Err(io::Error::new(
+				io::ErrorKind::AlreadyExists,
+				format!("{} is a file", path.display()),
+			))
+		} else {
+			Ok(())
+		}
+	}
+
+	fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+		let path = self.resolve(path);
+		let files = self.files.read().unwrap();
+
+		if let Some(file) = files.get(&path) {
+			return Ok(Metadata {
+				kind: FileKind::File,
+				len: file.content.len() as u64,
+				modified: Some(file.modified),
+			});
+		}
+
+		let is_dir = files
+			.range(path.clone()..)
+			.next()
+			.map_or(false, |(file, _)| file.starts_with(&path));
+		if is_dir {
+			Ok(Metadata {
+				kind: FileKind::Directory,
+				len: 0,
+				modified: None,
+			})
+		} else {
+			Err(not_found(&path))
+		}
+	}
+
+	fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+		let path = self.resolve(path);
+		let files = self.files.read().unwrap();
+		let mut entries: Vec<DirEntry> = vec![];
+
+		for file in files.range(path.clone()..).map(|(file, _)| file) {
+			let relative = match file.strip_prefix(&path) {
+				Ok(relative) => relative,
+				Err(_) => break,
+			};
+			let mut components = relative.components();
+			let name = match components.next() {
+				Some(name) => name.as_os_str().to_os_string(),
+				None => continue,
+			};
+			let kind = if components.next().is_some() {
+				FileKind::Directory
+			} else {
+				FileKind::File
+			};
+
+			// The files are sorted, so the files of a directory are next to each other
+			if entries.last().map_or(true, |last| last.name != name) {
+				entries.push(DirEntry { name, kind });
+			}
+		}
+
+		if entries.is_empty() {
+			Err(not_found(&path))
+		} else {
+			Ok(entries)
+		}
+	}
+}
+
+fn not_found(path: &Path) -> io::Error {
+	io::Error::new(
+		io::ErrorKind::NotFound,
+		format!("{} doesn't exist", path.display()),
+	)
+}
+
+#[cfg(test)]
+mod test {
+	use super::{DirEntry, FileKind, FileSystem, MemoryFileSystem};
+	use std::io;
+	use std::path::Path;
+
+	#[test]
+	fn memory_file_system_derives_the_directories() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("src/index.js", "");
+		fs.insert("src/lib/a.js", "");
+		fs.insert("src/lib/b.js", "");
+		fs.insert("src-other.js", "");
+
+		let mut entries = fs.read_dir(Path::new("src")).unwrap();
+		entries.sort_by(|a, b| a.name.cmp(&b.name));
+		assert_eq!(
+			entries,
+			vec![
+				DirEntry {
+					name: "index.js".into(),
+					kind: FileKind::File,
+				},
+				DirEntry {
+					name: "lib".into(),
+					kind: FileKind::Directory,
+				},
+			]
+		);
+
+		assert!(fs.is_dir(Path::new("src/lib")));
+		assert!(fs.is_file(Path::new("src/../src-other.js")));
+		assert!(!fs.is_dir(Path::new("src/index.js")));
+		assert!(!fs.is_file(Path::new("missing.js")));
+	}
+
+	#[test]
+	fn memory_file_system_reports_errors() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("src/index.js", "let a = 1;");
+
+		let error = fs.read_to_string(Path::new("src/missing.js")).unwrap_err();
+		assert_eq!(error.kind(), io::ErrorKind::NotFound);
+		assert!(fs.write(Path::new("src"), "").is_err());
+
+		fs.write(Path::new("src/index.js"), "let a = 2;").unwrap();
+		assert_eq!(fs.remove("src/index.js"), Some(String::from("let a = 2;")));
+		assert!(fs.read_dir(Path::new("src")).is_err());
+
+		fs.insert("src/index.js", "let a = 1;");
+		assert!(fs.create_dir_all(Path::new("src/index.js")).is_err());
+		fs.remove_file(Path::new("src/index.js")).unwrap();
+		let error = fs.remove_file(Path::new("src/index.js")).unwrap_err();
+		assert_eq!(error.kind(), io::ErrorKind::NotFound);
+	}
+}
@@ -1,8 +1,21 @@
-use crate::file_handlers::{javascript::JsFileHandler, unknown::UnknownFileHandler};
+use crate::file_handlers::{
+	javascript::{JsFileHandler, ModuleKind},
+	typescript::TsFileHandler,
+	unknown::UnknownFileHandler,
+};
+use configuration::{Configuration, FileSettings};
 use file_handlers::{json::JsonFileHandler, ExtensionHandler};
+use fs::{FileSystem, OsFileSystem};
 use std::collections::HashMap;
+use std::env;
+use std::path::{Component, Path, PathBuf};
 
+pub mod configuration;
 pub mod file_handlers;
+pub mod fs;
+pub mod traversal;
+pub mod watch;
+pub mod workspace;
 
 // these strings will live for the whole App, so it makes sense to have them as static
 pub type Handlers = HashMap<&'static str, Box<dyn ExtensionHandler>>;
@@ -10,17 +23,44 @@ pub type Handlers = HashMap<&'static str, Box<dyn ExtensionHandler>>;
 pub struct App {
 	handlers: Handlers,
 	unknown_handler: Box<dyn ExtensionHandler>,
+	configuration: Configuration,
+	/// The path of the file the configuration was loaded from
+	configuration_path: Option<PathBuf>,
+	/// Where the files are read from and written to
+	fs: Box<dyn FileSystem>,
 }
 
 impl Default for App {
 	fn default() -> Self {
 		let mut map: Handlers = HashMap::new();
-		map.insert("js", Box::new(JsFileHandler {}));
-		map.insert("ts", Box::new(JsFileHandler {}));
+		// TODO: register `jsx` and `tsx` once the parser supports JSX. Until then, their files are
+		// skipped because JSX isn't supported yet, rather than reported with syntax errors.
+		map.insert("js", Box::new(JsFileHandler::default()));
+		map.insert(
+			"mjs",
+			Box::new(JsFileHandler::with_module_kind(ModuleKind::Module)),
+		);
+		map.insert(
+			"cjs",
+			Box::new(JsFileHandler::with_module_kind(ModuleKind::CommonJs)),
+		);
+		map.insert("ts", Box::new(TsFileHandler::default()));
+		map.insert(
+			"mts",
+			Box::new(TsFileHandler::with_module_kind(ModuleKind::Module)),
+		);
+		map.insert(
+			"cts",
+			Box::new(TsFileHandler::with_module_kind(ModuleKind::CommonJs)),
+		);
 		map.insert("json", Box::new(JsonFileHandler {}));
+		map.insert("jsonc", Box::new(JsonFileHandler {}));
 		Self {
 			handlers: map,
 			unknown_handler: Box::new(UnknownFileHandler {}),
+			configuration: Configuration::default(),
+			configuration_path: None,
+			fs: Box::new(OsFileSystem),
 		}
 	}
 }
@@ -30,6 +70,63 @@ impl App {
 		Default::default()
 	}
 
+	/// Uses the configuration loaded from the file at `path` instead of the default one.
+	///
+	/// The glob patterns of the configuration are relative to the directory of `path`.
+	pub fn with_configuration(mut self, configuration: Configuration, path: PathBuf) -> Self {
+		self.configuration = configuration;
+		self.configuration_path = Some(path);
+		self
+	}
+
+	/// Reads and writes the files with `fs` instead of the file system of the operating system
+	pub fn with_file_system(mut self, fs: impl FileSystem + 'static) -> Self {
+		self.fs = Box::new(fs);
+		self
+	}
+
+	/// The file system used to read and write the files
+	pub fn fs(&self) -> &dyn FileSystem {
+		self.fs.as_ref()
+	}
+
+	/// The configuration of the project
+	pub fn configuration(&self) -> &Configuration {
+		&self.configuration
+	}
+
+	/// The path of the configuration file, if the configuration was loaded from one
+	pub fn configuration_path(&self) -> Option<&Path> {
+		self.configuration_path.as_deref()
+	}
+
+	/// Returns the pattern of the `ignore` setting of the configuration that matches `path`, if any
+	pub fn configuration_ignore_pattern(&self, path: &Path) -> Option<&str> {
+		let root = self.configuration_path()?.parent()?;
+		self.configuration.ignore_pattern(&relative_to(path, root)?)
+	}
+
+	/// Resolves the settings of the file at `path`, from its handler and the configuration
+	pub fn file_settings(&self, path: &Path) -> FileSettings {
+		let handler = path
+			.extension()
+			.and_then(|extension| extension.to_str())
+			.and_then(|extension| self.get_handler(extension))
+			.unwrap_or_else(|| self.unknown_handler.as_ref());
+
+		let relative_path = self
+			.configuration_path
+			.as_deref()
+			.and_then(|configuration_path| configuration_path.parent())
+			.and_then(|root| relative_to(path, root));
+
+		self.configuration.settings_for(
+			relative_path.as_deref(),
+			handler.language(),
+			handler.capabilities(),
+		)
+	}
+
 	pub fn get_handler<'a>(&self, file_extension: &'a str) -> Option<&dyn ExtensionHandler> {
 		let handler = if self.handlers.contains_key(file_extension) {
 			self.handlers.get(file_extension)
@@ -38,8 +135,50 @@ impl App {
 		};
 		handler.map(|handler| handler.as_ref())
 	}
+
+	/// Returns `true` if a handler was registered for the given extension
+	pub fn has_handler(&self, file_extension: &str) -> bool {
+		self.handlers.contains_key(file_extension)
+	}
 }
 
 pub fn create_app() -> App {
 	App::new()
 }
+
+/// Returns `path` relative to `root`, or `None` if `path` is outside of `root`.
+///
+/// Relative paths are resolved from the working directory. The paths are compared without
+/// accessing the file system, so the file doesn't need to exist.
+fn relative_to(path: &Path, root: &Path) -> Option<PathBuf> {
+	let current_dir = env::current_dir().ok()?;
+	let path = normalize(&current_dir.join(path));
+	let root = normalize(&current_dir.join(root));
+
+	path.strip_prefix(root).ok().map(Path::to_path_buf)
+}
+
+/// Removes the `.` and `..` components of a path, without accessing the file system.
+///
+/// The `..` components that lead out of the start of a relative path are kept, so that
+/// `../src/a.js` and `src/a.js` stay different paths.
+pub fn normalize(path: &Path) -> PathBuf {
+	let mut normalized = PathBuf::new();
+
+	for component in path.components() {
+		match component {
+			Component::CurDir => {}
+			Component::ParentDir => match normalized.components().next_back() {
+				Some(Component::Normal(_)) => {
+					normalized.pop();
+				}
+				// The parent of the root is the root itself
+				Some(Component::RootDir | Component::Prefix(_)) => {}
+				_ => normalized.push(component),
+			},
+			component => normalized.push(component),
+		}
+	}
+
+	normalized
+}
@@ -0,0 +1,700 @@
+//! Discovery of the files that a command should process.
+//!
+//! The inputs of a command can be:
+//! - paths to files, that are always processed;
+//! - paths to directories, that are walked recursively;
+//! - glob patterns like `packages/**/*.js`, that are matched against the files inside the
+//!   longest directory prefix of the pattern that doesn't contain any glob character.
+//!
+//! When walking a directory or matching a glob pattern, only the files with an extension
+//! registered inside the [App] are kept.
+//!
+//! The files and directories are skipped when:
+//! - they match a pattern of a `.romeignore` or `.gitignore` file, which use the syntax and
+//!   semantics of `.gitignore` files. The ignore files are read from the directories of the
+//!   project, from the directory of the path up to the root of the project. The patterns of the
+//!   closest directory take precedence, and `.romeignore` takes precedence over `.gitignore`
+//!   inside the same directory;
+//! - they match a pattern of the `ignore` setting of the configuration;
+//! - a block of `overrides` of the configuration ignores them;
+//! - they're a `.git` directory.
+//!
+//! The files are read through the [FileSystem] of the [App].
+//!
+//! The root of the project is the directory of the configuration file or, if there's none, the
+//! working directory. Paths given explicitly are skipped too if they, or one of their parent
+//! directories, are ignored.
+use crate::fs::{FileKind, FileSystem};
+use crate::{normalize, App};
+use globset::{GlobBuilder, GlobMatcher};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// The names of the ignore files, in order of precedence
+pub(crate) const IGNORE_FILE_NAMES: [&str; 2] = [".romeignore", ".gitignore"];
+
+/// Errors that can happen while resolving the inputs of a command
+#[derive(Debug)]
+pub enum TraversalError {
+	/// The input is neither an existing file, an existing directory, nor a glob pattern
+	NotFound(String),
+
+	/// The input is a glob pattern that couldn't be parsed
+	InvalidGlob(String, globset::Error),
+
+	/// The input is a glob pattern that doesn't match any file
+	NoMatches(String),
+}
+
+impl Display for TraversalError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TraversalError::NotFound(input) => {
+				write!(f, "The file or directory \"{}\" doesn't exist.", input)
+			}
+			TraversalError::InvalidGlob(input, error) => {
+				write!(
+					f,
+					"The pattern \"{}\" is not a valid glob: {}",
+					input, error
+				)
+			}
+			TraversalError::NoMatches(input) => {
+				write!(f, "The pattern \"{}\" doesn't match any file.", input)
+			}
+		}
+	}
+}
+
+impl std::error::Error for TraversalError {}
+
+/// The files found by [collect_files]
+#[derive(Debug, Default)]
+pub struct CollectedFiles {
+	/// The files to process, sorted and without duplicates
+	pub files: Vec<PathBuf>,
+
+	/// The files and directories that were skipped, sorted by path
+	pub skipped: Vec<SkippedPath>,
+}
+
+/// A file or a directory that was skipped while collecting the files
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SkippedPath {
+	pub path: PathBuf,
+	pub reason: SkipReason,
+}
+
+/// Why a file or a directory was skipped
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SkipReason {
+	/// The file doesn't have an extension registered inside the [App]
+	Unsupported,
+
+	/// The file is written in a syntax that isn't supported yet, like JSX
+	UnsupportedSyntax(&'static str),
+
+	/// The directory belongs to the version control system
+	VersionControl,
+
+	/// The path matches a pattern of an ignore file
+	IgnoreFile { file: PathBuf, pattern: String },
+
+	/// The path matches a pattern of the `ignore` setting of the configuration
+	Configuration { pattern: String },
+
+	/// The file is ignored by the block of `overrides` of the configuration at the given index
+	Override(usize),
+}
+
+impl Display for SkipReason {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SkipReason::Unsupported => write!(f, "the extension of the file isn't supported"),
+			SkipReason::UnsupportedSyntax(syntax) => write!(f, "{} isn't supported yet", syntax),
+			SkipReason::VersionControl => write!(f, "it's a version control directory"),
+			SkipReason::IgnoreFile { file, pattern } => {
+				write!(f, "matches \"{}\" in {}", pattern, file.display())
+			}
+			SkipReason::Configuration { pattern } => {
+				write!(
+					f,
+					"matches \"{}\" in the ignore setting of the configuration",
+					pattern
+				)
+			}
+			SkipReason::Override(index) => {
+				write!(f, "ignored by overrides[{}] of the configuration", index)
+			}
+		}
+	}
+}
+
+/// Resolves the inputs of a command into a list of files.
+///
+/// The returned list is sorted and doesn't contain duplicates, so that the files are always
+/// reported in the same order.
+pub fn collect_files<I, S>(app: &App, inputs: I) -> Result<CollectedFiles, TraversalError>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	collect_files_and_directories(app, inputs).map(|(collected, _)| collected)
+}
+
+/// Resolves the inputs of a command like [collect_files], and also returns the directories that
+/// were walked, without the ignored ones
+pub(crate) fn collect_files_and_directories<I, S>(
+	app: &App,
+	inputs: I,
+) -> Result<(CollectedFiles, Vec<PathBuf>), TraversalError>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	let mut traversal = Traversal::new(app);
+	let fs = app.fs();
+
+	for input in inputs {
+		let input = input.as_ref();
+		let path = Path::new(input);
+
+		if fs.is_file(path) {
+			match traversal.explicit_ignore_reason(path, false) {
+				Some(reason) => traversal.skip(path.to_path_buf(), reason),
+				None => traversal.collected.files.push(path.to_path_buf()),
+			}
+		} else if fs.is_dir(path) {
+			match traversal.explicit_ignore_reason(path, true) {
+				Some(reason) => traversal.skip(path.to_path_buf(), reason),
+				None => traversal.walk_directory(path, None),
+			}
+		} else if is_glob(input) {
+			let matcher = GlobBuilder::new(input)
+				.literal_separator(true)
+				.build()
+				.map_err(|error| TraversalError::InvalidGlob(input.to_string(), error))?
+				.compile_matcher();
+			let base = glob_base(input);
+			let previous_len = traversal.collected.files.len() + traversal.collected.skipped.len();
+
+			match traversal.explicit_ignore_reason(&base, true) {
+				Some(reason) => traversal.skip(base, reason),
+				None => traversal.walk_directory(&base, Some(&matcher)),
+			}
+
+			if traversal.collected.files.len() + traversal.collected.skipped.len() == previous_len {
+				return Err(TraversalError::NoMatches(input.to_string()));
+			}
+		} else {
+			return Err(TraversalError::NotFound(input.to_string()));
+		}
+	}
+
+	let mut collected = traversal.collected;
+	collected.files.sort();
+	collected.files.dedup();
+	collected.skipped.sort_by(|a, b| a.path.cmp(&b.path));
+	collected.skipped.dedup_by(|a, b| a.path == b.path);
+
+	Ok((collected, traversal.directories))
+}
+
+/// Returns why [collect_files] skips `path`, whether it's given as input or found while walking
+/// a directory, or `None` if the path isn't ignored.
+///
+/// Unlike the settings of the file, this also checks the `ignore` setting of the configuration,
+/// the ignore files of the project and the version control directories.
+pub fn ignore_reason(app: &App, path: &Path) -> Option<SkipReason> {
+	let is_dir = app.fs().is_dir(path);
+	Traversal::new(app).explicit_ignore_reason(path, is_dir)
+}
+
+/// Collects the files that [collect_files] would return for the inputs, but only among the
+/// `files` and inside the `directories`, which are walked recursively.
+///
+/// The paths that don't exist are accepted, and don't match any file. The inputs that stopped
+/// matching anything are ignored rather than reported as errors. The returned files are sorted
+/// and don't contain duplicates, and are followed by the directories that were walked.
+pub(crate) fn collect_files_within<S: AsRef<str>>(
+	app: &App,
+	inputs: &[S],
+	directories: &[PathBuf],
+	files: &[PathBuf],
+) -> (Vec<PathBuf>, Vec<PathBuf>) {
+	let mut traversal = Traversal::new(app);
+	let fs = app.fs();
+
+	for input in inputs {
+		let input = input.as_ref();
+		let path = Path::new(input);
+
+		let (base, matcher) = if fs.is_file(path) {
+			let is_changed = files.iter().any(|file| traversal.is_same(file, path))
+				|| directories
+					.iter()
+					.any(|directory| traversal.contains(directory, path));
+			if is_changed && traversal.explicit_ignore_reason(path, false).is_none() {
+				traversal.collected.files.push(path.to_path_buf());
+			}
+			continue;
+		} else if fs.is_dir(path) {
+			(path.to_path_buf(), None)
+		} else if is_glob(input) {
+			match GlobBuilder::new(input).literal_separator(true).build() {
+				Ok(glob) => (glob_base(input), Some(glob.compile_matcher())),
+				Err(_) => continue,
+			}
+		} else {
+			continue;
+		};
+
+		for directory in directories {
+			// The directory that changed is either inside the input, or contains all of it
+			let directory = if traversal.contains(&base, directory) {
+				directory
+			} else if traversal.contains(directory, &base) {
+				&base
+			} else {
+				continue;
+			};
+
+			if fs.is_dir(directory) && traversal.explicit_ignore_reason(directory, true).is_none() {
+				traversal.walk_directory(directory, matcher.as_ref());
+			}
+		}
+
+		for file in files {
+			let is_input_file = traversal.contains(&base, file)
+				&& matcher
+					.as_ref()
+					.map_or(true, |matcher| matcher.is_match(file))
+				&& is_handled(app, file)
+				&& fs.is_file(file);
+			if is_input_file && traversal.explicit_ignore_reason(file, false).is_none() {
+				traversal.collected.files.push(file.clone());
+			}
+		}
+	}
+
+	let mut files = traversal.collected.files;
+	files.sort();
+	files.dedup();
+	(files, traversal.directories)
+}
+
+/// Returns `true` if the input contains any character with a special meaning inside a glob pattern
+fn is_glob(input: &str) -> bool {
+	input.contains(&['*', '?', '[', '{'][..])
+}
+
+/// Returns the longest leading directory of a glob pattern that doesn't contain any glob character.
+///
+/// For example, the base of `packages/**/*.js` is `packages`, and the base of `*.js` is `.`.
+fn glob_base(pattern: &str) -> PathBuf {
+	let mut base = PathBuf::new();
+
+	for component in Path::new(pattern).components() {
+		let component = component.as_os_str();
+		if component.to_str().map_or(true, is_glob) {
+			break;
+		}
+		base.push(component);
+	}
+
+	if base.as_os_str().is_empty() {
+		PathBuf::from(".")
+	} else {
+		base
+	}
+}
+
+struct Traversal<'app> {
+	app: &'app App,
+	/// Used to resolve the relative paths, since the ignore files match absolute paths
+	current_dir: PathBuf,
+	/// The ignore files above this directory aren't read
+	root: PathBuf,
+	/// The ignore files of the directories visited so far, by absolute path
+	ignore_files: HashMap<PathBuf, Vec<Gitignore>>,
+	collected: CollectedFiles,
+	/// The directories walked so far
+	directories: Vec<PathBuf>,
+}
+
+impl<'app> Traversal<'app> {
+	fn new(app: &'app App) -> Self {
+		let current_dir = env::current_dir().unwrap_or_default();
+		let root = app
+			.configuration_path()
+			.and_then(|path| path.parent())
+			.map_or_else(
+				|| current_dir.clone(),
+				|root| normalize(&current_dir.join(root)),
+			);
+
+		Self {
+			app,
+			current_dir,
+			root,
+			ignore_files: HashMap::new(),
+			collected: CollectedFiles::default(),
+			directories: Vec::new(),
+		}
+	}
+
+	fn absolute(&self, path: &Path) -> PathBuf {
+		normalize(&self.current_dir.join(path))
+	}
+
+	/// Returns `true` if both paths point to the same file, like `a.js` and `./a.js`
+	fn is_same(&self, first: &Path, second: &Path) -> bool {
+		self.absolute(first) == self.absolute(second)
+	}
+
+	/// Returns `true` if `path` is `directory` or one of its descendants
+	fn contains(&self, directory: &Path, path: &Path) -> bool {
+		self.absolute(path).starts_with(self.absolute(directory))
+	}
+
+	fn skip(&mut self, path: PathBuf, reason: SkipReason) {
+		self.collected.skipped.push(SkippedPath { path, reason });
+	}
+
+	/// Recursively collects the files of `directory` that Rome knows how to handle.
+	///
+	/// Entries that can't be read are skipped.
+	fn walk_directory(&mut self, directory: &Path, matcher: Option<&GlobMatcher>) {
+		let entries = match self.app.fs().read_dir(directory) {
+			Ok(entries) => entries,
+			Err(_) => return,
+		};
+		self.directories.push(directory.to_path_buf());
+
+		for entry in entries {
+			// Joining the path to the directory keeps the paths relative to the input, which is what
+			// glob patterns are matched against
+			let path = if directory == Path::new(".") {
+				PathBuf::from(entry.name)
+			} else {
+				directory.join(entry.name)
+			};
+
+			if entry.kind == FileKind::Directory {
+				match self.ignore_reason(&path, true) {
+					Some(reason) => self.skip(path, reason),
+					None => self.walk_directory(&path, matcher),
+				}
+			} else if matcher.map_or(true, |matcher| matcher.is_match(&path)) {
+				match self.ignore_reason(&path, false) {
+					Some(reason) => self.skip(path, reason),
+					None if !is_handled(self.app, &path) => {
+						let reason = unsupported_reason(&path);
+						self.skip(path, reason)
+					}
+					None => self.collected.files.push(path),
+				}
+			}
+		}
+	}
+
+	/// Returns why a path given explicitly is ignored, checking its parent directories first
+	fn explicit_ignore_reason(&mut self, path: &Path, is_dir: bool) -> Option<SkipReason> {
+		let absolute = self.absolute(path);
+		let mut parents: Vec<PathBuf> = absolute
+			.ancestors()
+			.skip(1)
+			.take_while(|parent| parent.starts_with(&self.root) && *parent != self.root)
+			.map(Path::to_path_buf)
+			.collect();
+		parents.reverse();
+
+		parents
+			.iter()
+			.find_map(|parent| self.ignore_reason(parent, true))
+			.or_else(|| self.ignore_reason(path, is_dir))
+	}
+
+	/// Returns why a path is ignored, without checking its parent directories
+	fn ignore_reason(&mut self, path: &Path, is_dir: bool) -> Option<SkipReason> {
+		if is_dir && path.file_name().map_or(false, |name| name == ".git") {
+			return Some(SkipReason::VersionControl);
+		}
+
+		if let Some(pattern) = self.app.configuration_ignore_pattern(path) {
+			return Some(SkipReason::Configuration {
+				pattern: pattern.to_string(),
+			});
+		}
+
+		if !is_dir {
+			let settings = self.app.file_settings(path);
+			if settings.ignored {
+				let overrides = &self.app.configuration().overrides;
+				let index = settings
+					.overrides
+					.iter()
+					.rev()
+					.find(|index| overrides[**index].ignore)?;
+				return Some(SkipReason::Override(*index));
+			}
+		}
+
+		let absolute = self.absolute(path);
+		let directories: Vec<PathBuf> = absolute
+			.ancestors()
+			.skip(1)
+			.take_while(|directory| directory.starts_with(&self.root))
+			.map(Path::to_path_buf)
+			.collect();
+
+		for directory in directories {
+			for ignore_file in ignore_files(self.app.fs(), &mut self.ignore_files, directory) {
+				match ignore_file.matched(&absolute, is_dir) {
+					Match::Ignore(glob) => {
+						let file = glob.from().unwrap_or_else(|| ignore_file.path());
+						return Some(SkipReason::IgnoreFile {
+							file: file
+								.strip_prefix(&self.current_dir)
+								.unwrap_or(file)
+								.to_path_buf(),
+							pattern: glob.original().to_string(),
+						});
+					}
+					Match::Whitelist(_) => return None,
+					Match::None => {}
+				}
+			}
+		}
+
+		None
+	}
+}
+
+/// Returns the ignore files of a directory, in order of precedence.
+///
+/// The ignore files are read only once per directory.
+fn ignore_files<'cache>(
+	fs: &dyn FileSystem,
+	cache: &'cache mut HashMap<PathBuf, Vec<Gitignore>>,
+	directory: PathBuf,
+) -> &'cache [Gitignore] {
+	cache.entry(directory).or_insert_with_key(|directory| {
+		IGNORE_FILE_NAMES
+			.iter()
+			.filter_map(|name| read_ignore_file(fs, directory, &directory.join(name)))
+			.filter(|ignore_file| !ignore_file.is_empty())
+			.collect()
+	})
+}
+
+/// Parses the ignore file at `path`, whose patterns are relative to `directory`.
+///
+/// Returns `None` if the file can't be read. The lines that can't be parsed are skipped.
+fn read_ignore_file(fs: &dyn FileSystem, directory: &Path, path: &Path) -> Option<Gitignore> {
+	let content = fs.read_to_string(path).ok()?;
+	let mut builder = GitignoreBuilder::new(directory);
+
+	for (index, line) in content.lines().enumerate() {
+		// Like git, ignore the byte order mark at the start of the file
+		let line = if index == 0 {
+			line.trim_start_matches('\u{feff}')
+		} else {
+			line
+		};
+		// The errors are ignored, like the ones of the lines that can't be parsed
+		let _ = builder.add_line(Some(path.to_path_buf()), line);
+	}
+
+	builder.build().ok()
+}
+
+fn is_handled(app: &App, path: &Path) -> bool {
+	path.extension()
+		.and_then(|extension| extension.to_str())
+		.map_or(false, |extension| app.has_handler(extension))
+}
+
+/// The extensions without a handler whose files are written in a syntax that isn't supported yet,
+/// with the name of the syntax
+// TODO: remove `jsx` and `tsx` once the parser supports JSX and their handlers are registered
+const UNSUPPORTED_SYNTAXES: &[(&str, &str)] = &[("jsx", "JSX"), ("tsx", "JSX")];
+
+/// Returns why a file that doesn't have a handler is skipped
+fn unsupported_reason(path: &Path) -> SkipReason {
+	let extension = path.extension().and_then(|extension| extension.to_str());
+
+	UNSUPPORTED_SYNTAXES
+		.iter()
+		.find(|(unsupported, _)| Some(*unsupported) == extension)
+		.map_or(SkipReason::Unsupported, |(_, syntax)| {
+			SkipReason::UnsupportedSyntax(syntax)
+		})
+}
+
+#[cfg(test)]
+mod test {
+	use super::{
+		collect_files, collect_files_within, glob_base, ignore_reason, SkipReason, SkippedPath,
+	};
+	use crate::configuration::Configuration;
+	use crate::fs::MemoryFileSystem;
+	use crate::App;
+	use std::path::{Path, PathBuf};
+
+	#[test]
+	fn glob_base_stops_at_the_first_glob_component() {
+		assert_eq!(glob_base("packages/**/*.js"), PathBuf::from("packages"));
+		assert_eq!(glob_base("src/lib/*.ts"), PathBuf::from("src/lib"));
+		assert_eq!(glob_base("src/{a,b}/index.js"), PathBuf::from("src"));
+		assert_eq!(glob_base("*.js"), PathBuf::from("."));
+	}
+
+	#[test]
+	fn collects_virtual_files() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("virtual/.gitignore", "dist\n");
+		fs.insert("virtual/src/index.js", "");
+		fs.insert("virtual/src/types.ts", "");
+		fs.insert("virtual/src/notes.txt", "");
+		fs.insert("virtual/src/app.jsx", "");
+		fs.insert("virtual/dist/index.js", "");
+		let app = App::new().with_file_system(fs);
+
+		let collected = collect_files(&app, ["virtual"]).unwrap();
+
+		assert_eq!(
+			collected.files,
+			vec![
+				PathBuf::from("virtual/src/index.js"),
+				PathBuf::from("virtual/src/types.ts")
+			]
+		);
+		assert_eq!(
+			collected.skipped,
+			vec![
+				SkippedPath {
+					path: PathBuf::from("virtual/.gitignore"),
+					reason: SkipReason::Unsupported,
+				},
+				SkippedPath {
+					path: PathBuf::from("virtual/dist"),
+					reason: SkipReason::IgnoreFile {
+						file: PathBuf::from("virtual/.gitignore"),
+						pattern: String::from("dist"),
+					},
+				},
+				SkippedPath {
+					path: PathBuf::from("virtual/src/app.jsx"),
+					reason: SkipReason::UnsupportedSyntax("JSX"),
+				},
+				SkippedPath {
+					path: PathBuf::from("virtual/src/notes.txt"),
+					reason: SkipReason::Unsupported,
+				},
+			]
+		);
+		assert!(collect_files(&app, ["virtual/missing.js"]).is_err());
+	}
+
+	#[test]
+	fn explains_why_a_path_is_ignored() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("explained/.gitignore", "dist\n");
+		fs.insert("explained/lib/.romeignore", "*.min.js\n");
+		let configuration = Configuration::from_json(
+			r#"{
+				"ignore": ["generated/**"],
+				"overrides": [{ "include": ["legacy/**"], "ignore": true }]
+			}"#,
+			0,
+		)
+		.unwrap();
+		let app = App::new()
+			.with_file_system(fs)
+			.with_configuration(configuration, PathBuf::from("explained/rome.json"));
+
+		let reason = |path: &str| ignore_reason(&app, Path::new(path));
+		assert_eq!(reason("explained/src/index.js"), None);
+		assert_eq!(
+			reason("explained/generated/data.js"),
+			Some(SkipReason::Configuration {
+				pattern: String::from("generated/**")
+			})
+		);
+		assert_eq!(
+			reason("explained/dist/index.js"),
+			Some(SkipReason::IgnoreFile {
+				file: PathBuf::from("explained/.gitignore"),
+				pattern: String::from("dist"),
+			})
+		);
+		assert_eq!(
+			reason("explained/lib/index.min.js"),
+			Some(SkipReason::IgnoreFile {
+				file: PathBuf::from("explained/lib/.romeignore"),
+				pattern: String::from("*.min.js"),
+			})
+		);
+		assert_eq!(
+			reason("explained/legacy/index.js"),
+			Some(SkipReason::Override(0))
+		);
+		assert_eq!(
+			reason("explained/.git/index.js"),
+			Some(SkipReason::VersionControl)
+		);
+	}
+
+	#[test]
+	fn collects_files_within_the_changed_paths() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("changed/.gitignore", "dist\n");
+		fs.insert("changed/src/index.js", "");
+		fs.insert("changed/src/new/a.js", "");
+		fs.insert("changed/src/new/notes.txt", "");
+		fs.insert("changed/dist/index.js", "");
+		fs.insert("changed/lib/b.js", "");
+		let app = App::new().with_file_system(fs);
+
+		let directories = [
+			PathBuf::from("changed/src/new"),
+			PathBuf::from("changed/dist"),
+			PathBuf::from("changed/deleted"),
+		];
+		let files = [
+			PathBuf::from("changed/lib/b.js"),
+			PathBuf::from("changed/lib/deleted.js"),
+		];
+		assert_eq!(
+			collect_files_within(&app, &["changed"], &directories, &files),
+			(
+				vec![
+					PathBuf::from("changed/lib/b.js"),
+					PathBuf::from("changed/src/new/a.js")
+				],
+				vec![PathBuf::from("changed/src/new")]
+			)
+		);
+
+		let directories = [PathBuf::from("changed/src")];
+		assert_eq!(
+			collect_files_within(&app, &["./changed/lib/b.js"], &directories, &files),
+			(vec![PathBuf::from("./changed/lib/b.js")], vec![])
+		);
+		assert_eq!(
+			collect_files_within(&app, &["changed/src/*.ts"], &directories, &[]),
+			(
+				vec![],
+				vec![
+					PathBuf::from("changed/src"),
+					PathBuf::from("changed/src/new")
+				]
+			)
+		);
+	}
+}
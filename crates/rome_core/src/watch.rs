@@ -0,0 +1,509 @@
+//! Watching the files of a command for changes.
+//!
+//! The [Watcher] subscribes to the notifications of the file system for the directories that
+//! the traversal walks, so the ignored directories, like `node_modules`, aren't watched. When some
+//! paths change, only the files that changed are checked again, along with the directories that
+//! were created, deleted or whose ignore files changed, so that a batch of changes doesn't cost a
+//! walk of the whole project. Changes that happen in a burst, like when an editor writes several
+//! files or a branch is checked out, are grouped together.
+use crate::fs::FileSystem;
+use crate::traversal::{
+	collect_files_and_directories, collect_files_within, ignore_reason, CollectedFiles,
+	TraversalError, IGNORE_FILE_NAMES,
+};
+use crate::{normalize, App};
+use notify::{Op, RawEvent, RecommendedWatcher, RecursiveMode, Watcher as _};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long the files must stay unchanged before a batch of changes is reported by default
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// How long a batch of changes can be delayed by files that keep changing, by default
+const DEFAULT_MAX_WAIT: Duration = Duration::from_secs(2);
+
+/// Errors that can happen while starting to watch the inputs of a command
+#[derive(Debug)]
+pub enum WatchError {
+	/// The inputs couldn't be resolved
+	Traversal(TraversalError),
+
+	/// The notifications of the file system couldn't be set up
+	Notify(notify::Error),
+}
+
+impl Display for WatchError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			WatchError::Traversal(error) => error.fmt(f),
+			WatchError::Notify(error) => {
+				write!(f, "Failed to watch the files for changes: {}", error)
+			}
+		}
+	}
+}
+
+impl std::error::Error for WatchError {}
+
+impl From<TraversalError> for WatchError {
+	fn from(error: TraversalError) -> Self {
+		WatchError::Traversal(error)
+	}
+}
+
+/// The files that changed since the last batch of changes, sorted by path
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Changes {
+	/// The files that were created or modified
+	pub changed: Vec<PathBuf>,
+	/// The files that were deleted, or that aren't part of the inputs anymore
+	pub removed: Vec<PathBuf>,
+}
+
+impl Changes {
+	pub fn is_empty(&self) -> bool {
+		self.changed.is_empty() && self.removed.is_empty()
+	}
+}
+
+/// What's compared to find out whether a file changed
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Stamp {
+	modified: Option<SystemTime>,
+	len: u64,
+}
+
+impl Stamp {
+	fn read(fs: &dyn FileSystem, path: &Path) -> Option<Self> {
+		let metadata = fs.metadata(path).ok()?;
+		Some(Self {
+			modified: metadata.modified,
+			len: metadata.len,
+		})
+	}
+}
+
+/// The paths notified during a burst of changes
+#[derive(Debug, Default)]
+struct Batch {
+	paths: Vec<PathBuf>,
+	/// Some notifications were lost, so all the inputs must be checked again
+	rescan: bool,
+}
+
+/// Watches the files matched by the inputs of a command
+pub struct Watcher<'app> {
+	app: &'app App,
+	inputs: Vec<String>,
+	files: HashMap<PathBuf, Stamp>,
+	/// Used to turn the paths of the inputs into the absolute paths of the notifications
+	current_dir: PathBuf,
+	/// The watched directories by normalized absolute path, which the notifications use, with
+	/// their path as the inputs spell it
+	directories: HashMap<PathBuf, PathBuf>,
+	/// The directories of the files given as inputs, by absolute path. They stay watched even if
+	/// they're ignored, like the files given as inputs.
+	input_directories: HashSet<PathBuf>,
+	events: Receiver<RawEvent>,
+	watcher: RecommendedWatcher,
+	debounce: Duration,
+	max_wait: Duration,
+}
+
+impl<'app> Watcher<'app> {
+	/// Starts watching the inputs, and returns the files they currently match
+	pub fn new<I, S>(app: &'app App, inputs: I) -> Result<(Self, CollectedFiles), WatchError>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		let inputs: Vec<String> = inputs
+			.into_iter()
+			.map(|input| input.as_ref().to_string())
+			.collect();
+		let (collected, mut directories) = collect_files_and_directories(app, &inputs)?;
+
+		let current_dir = env::current_dir().unwrap_or_default();
+		let input_directories = input_directories(app.fs(), &inputs);
+		directories.extend(input_directories.iter().cloned());
+
+		let (sender, events) = channel();
+		let mut watcher = Self {
+			app,
+			files: stamps(app.fs(), &collected.files),
+			input_directories: input_directories
+				.iter()
+				.map(|directory| normalize(&current_dir.join(directory)))
+				.collect(),
+			inputs,
+			current_dir,
+			directories: HashMap::new(),
+			events,
+			watcher: notify::raw_watcher(sender).map_err(WatchError::Notify)?,
+			debounce: DEFAULT_DEBOUNCE,
+			max_wait: DEFAULT_MAX_WAIT,
+		};
+		watcher
+			.watch_directories(&directories, None)
+			.map_err(WatchError::Notify)?;
+
+		Ok((watcher, collected))
+	}
+
+	pub fn with_debounce(mut self, debounce: Duration) -> Self {
+		self.debounce = debounce;
+		self
+	}
+
+	pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+		self.max_wait = max_wait;
+		self
+	}
+
+	/// Blocks until some files change, then until no file changes for the debounce duration, or
+	/// for at most the maximum wait, and returns all the changes
+	pub fn wait(&mut self) -> Changes {
+		loop {
+			// The notifications of the command's own writes don't produce any change
+			let batch = self.next_batch();
+			let changes = if batch.rescan {
+				self.rescan()
+			} else {
+				self.update(batch.paths)
+			};
+			if !changes.is_empty() {
+				return changes;
+			}
+		}
+	}
+
+	/// Returns the paths of the next burst of notifications.
+	///
+	/// The notifications that can't change the files of the inputs are dropped, so that they
+	/// neither start a batch nor delay it.
+	fn next_batch(&self) -> Batch {
+		let mut batch = Batch::default();
+		while batch.paths.is_empty() && !batch.rescan {
+			let event = self
+				.events
+				.recv()
+				.expect("the notifications only stop when the watcher is dropped");
+			self.add_event(&mut batch, event);
+		}
+
+		let deadline = Instant::now() + self.max_wait;
+		let mut quiet_until = Instant::now() + self.debounce;
+		loop {
+			let until = quiet_until.min(deadline);
+			let now = Instant::now();
+			if now >= until {
+				break;
+			}
+
+			match self.events.recv_timeout(until - now) {
+				Ok(event) => {
+					if self.add_event(&mut batch, event) {
+						quiet_until = Instant::now() + self.debounce;
+					}
+				}
+				Err(_) => break,
+			}
+		}
+
+		batch
+	}
+
+	/// Adds the path of a notification to the batch, and returns `true` if it may change the
+	/// files of the inputs
+	fn add_event(&self, batch: &mut Batch, event: RawEvent) -> bool {
+		match (event.path, event.op) {
+			(Some(path), Ok(op)) if !op.contains(Op::RESCAN) => {
+				match relative_path(&self.directories, &path) {
+					Some(path) if self.is_relevant(&path) => {
+						batch.paths.push(path);
+						true
+					}
+					_ => false,
+				}
+			}
+			_ => {
+				batch.rescan = true;
+				true
+			}
+		}
+	}
+
+	/// Returns `true` if a change of `path` may change the files of the inputs
+	fn is_relevant(&self, path: &Path) -> bool {
+		let fs = self.app.fs();
+
+		if is_ignore_file(path) || self.files.contains_key(path) {
+			true
+		} else if fs.is_dir(path) {
+			ignore_reason(self.app, path).is_none()
+		} else if fs.is_file(path) {
+			let (files, _) =
+				collect_files_within(self.app, &self.inputs, &[], &[path.to_path_buf()]);
+			!files.is_empty()
+		} else {
+			// A deleted directory may have contained some files of the inputs
+			self.directories
+				.contains_key(&normalize(&self.current_dir.join(path)))
+		}
+	}
+
+	/// Checks the paths that were notified, and returns the changes of the files
+	fn update(&mut self, paths: Vec<PathBuf>) -> Changes {
+		let fs = self.app.fs();
+		let mut directories = Vec::new();
+		let mut files = Vec::new();
+
+		for path in paths {
+			if is_ignore_file(&path) {
+				// The patterns of an ignore file apply to its whole directory
+				directories.push(match path.parent() {
+					Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+					_ => PathBuf::from("."),
+				});
+			} else if fs.is_file(&path) {
+				files.push(path);
+			} else {
+				// Either a directory that was created or moved here, whose files weren't known
+				// yet, or a path that was deleted and may have been a directory with files
+				directories.push(path);
+			}
+		}
+
+		directories.sort();
+		directories.dedup();
+		files.sort();
+		files.dedup();
+
+		let (found, walked) = collect_files_within(self.app, &self.inputs, &directories, &files);
+		// The directories can't be watched anymore if they were deleted in the meantime, their
+		// files are reported as removed by the next batch then
+		let _ = self.watch_directories(&walked, Some(&directories));
+
+		let is_checked = |path: &Path| {
+			files.iter().any(|file| file == path)
+				|| directories
+					.iter()
+					.any(|directory| normalize(path).starts_with(normalize(directory)))
+		};
+
+		let mut changes = Changes::default();
+		for path in self.files.keys() {
+			if is_checked(path) && found.binary_search(path).is_err() {
+				changes.removed.push(path.clone());
+			}
+		}
+		for path in &changes.removed {
+			self.files.remove(path);
+		}
+		for path in found {
+			if let Some(stamp) = Stamp::read(fs, &path) {
+				if self.files.insert(path.clone(), stamp) != Some(stamp) {
+					changes.changed.push(path);
+				}
+			}
+		}
+
+		changes.changed.sort();
+		changes.removed.sort();
+		changes
+	}
+
+	/// Checks all the files of the inputs again, and returns the ones that changed since the last
+	/// check
+	fn rescan(&mut self) -> Changes {
+		// The inputs can stop matching any file while the command is running, for example when
+		// the file given as input is deleted. All the files are reported as removed then.
+		let files = match collect_files_and_directories(self.app, &self.inputs) {
+			Ok((collected, directories)) => {
+				let _ = self.watch_directories(&directories, None);
+				stamps(self.app.fs(), &collected.files)
+			}
+			Err(_) => HashMap::new(),
+		};
+
+		let mut changes = Changes::default();
+		for (path, stamp) in &files {
+			if self.files.get(path) != Some(stamp) {
+				changes.changed.push(path.clone());
+			}
+		}
+		for path in self.files.keys() {
+			if !files.contains_key(path) {
+				changes.removed.push(path.clone());
+			}
+		}
+
+		changes.changed.sort();
+		changes.removed.sort();
+		self.files = files;
+		changes
+	}
+
+	/// Watches the `walked` directories, and stops watching the directories inside `scopes`, or
+	/// all of them if `scopes` is `None`, that weren't walked, because they were deleted or are
+	/// ignored now.
+	///
+	/// Tries to watch all the directories, and returns the first error.
+	fn watch_directories(
+		&mut self,
+		walked: &[PathBuf],
+		scopes: Option<&[PathBuf]>,
+	) -> Result<(), notify::Error> {
+		let walked: HashMap<PathBuf, &PathBuf> = walked
+			.iter()
+			.map(|directory| (normalize(&self.current_dir.join(directory)), directory))
+			.collect();
+
+		let stale: Vec<PathBuf> = self
+			.directories
+			.iter()
+			.filter(|(absolute, path)| {
+				!walked.contains_key(*absolute)
+					&& !self.input_directories.contains(*absolute)
+					&& scopes.map_or(true, |scopes| {
+						scopes
+							.iter()
+							.any(|scope| normalize(path).starts_with(normalize(scope)))
+					})
+			})
+			.map(|(absolute, _)| absolute.clone())
+			.collect();
+		for absolute in stale {
+			// The notifications of a deleted directory already stopped
+			let _ = self.watcher.unwatch(&absolute);
+			self.directories.remove(&absolute);
+		}
+
+		let mut result = Ok(());
+		for (absolute, path) in walked {
+			if self.directories.contains_key(&absolute) {
+				continue;
+			}
+			match self.watcher.watch(&absolute, RecursiveMode::NonRecursive) {
+				Ok(()) => {
+					self.directories.insert(absolute, path.clone());
+				}
+				Err(error) if result.is_ok() => result = Err(error),
+				Err(_) => {}
+			}
+		}
+
+		result
+	}
+
+	/// Marks the current state of the files as seen, so that writing the files while processing
+	/// a batch of changes doesn't trigger another batch
+	pub fn refresh<P: AsRef<Path>>(&mut self, paths: &[P]) {
+		for path in paths {
+			let path = path.as_ref();
+			if let Some(stamp) = Stamp::read(self.app.fs(), path) {
+				if let Some(previous) = self.files.get_mut(path) {
+					*previous = stamp;
+				}
+			}
+		}
+	}
+}
+
+/// Returns the directories of the files given as inputs, which are watched because editors often
+/// replace a file when saving it, which would stop the notifications of the file itself
+fn input_directories(fs: &dyn FileSystem, inputs: &[String]) -> Vec<PathBuf> {
+	let mut directories: Vec<PathBuf> = inputs
+		.iter()
+		.map(Path::new)
+		.filter(|path| fs.is_file(path))
+		.map(|path| match path.parent() {
+			Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+			_ => PathBuf::from("."),
+		})
+		.collect();
+	directories.sort();
+	directories.dedup();
+	directories
+}
+
+fn is_ignore_file(path: &Path) -> bool {
+	path.file_name().map_or(false, |name| {
+		IGNORE_FILE_NAMES.iter().any(|ignore| name == *ignore)
+	})
+}
+
+/// Turns the absolute path of a notification into a path inside a watched directory, spelled
+/// like the inputs so that it matches the paths of the collected files
+fn relative_path(directories: &HashMap<PathBuf, PathBuf>, absolute: &Path) -> Option<PathBuf> {
+	let (root, directory) = directories
+		.iter()
+		.filter(|(root, _)| absolute.starts_with(root))
+		.max_by_key(|(root, _)| root.components().count())?;
+	let relative = absolute.strip_prefix(root).ok()?;
+
+	// Like when walking a directory, the paths inside the working directory don't start with `./`
+	if relative.as_os_str().is_empty() {
+		Some(directory.clone())
+	} else if directory == Path::new(".") {
+		Some(relative.to_path_buf())
+	} else {
+		Some(directory.join(relative))
+	}
+}
+
+fn stamps(fs: &dyn FileSystem, files: &[PathBuf]) -> HashMap<PathBuf, Stamp> {
+	files
+		.iter()
+		.filter_map(|path| Some((path.clone(), Stamp::read(fs, path)?)))
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::{input_directories, relative_path};
+	use crate::fs::MemoryFileSystem;
+	use std::collections::HashMap;
+	use std::path::{Path, PathBuf};
+
+	#[test]
+	fn watches_the_directories_of_the_input_files() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("index.js", "");
+		fs.insert("src/lib/a.js", "");
+		fs.insert("src/lib/b.js", "");
+		let inputs = ["index.js", "src/lib/a.js", "src/lib/b.js", "src"].map(String::from);
+
+		assert_eq!(
+			input_directories(&fs, &inputs),
+			vec![PathBuf::from("."), PathBuf::from("src/lib")]
+		);
+	}
+
+	#[test]
+	fn spells_the_notified_paths_like_the_inputs() {
+		let directories = HashMap::from([
+			(PathBuf::from("/project"), PathBuf::from(".")),
+			(
+				PathBuf::from("/project/packages/a"),
+				PathBuf::from("./packages/a"),
+			),
+		]);
+
+		let relative = |path: &str| relative_path(&directories, Path::new(path));
+		assert_eq!(
+			relative("/project/index.js"),
+			Some(PathBuf::from("index.js"))
+		);
+		assert_eq!(relative("/project"), Some(PathBuf::from(".")));
+		assert_eq!(
+			relative("/project/packages/a/src/index.js"),
+			Some(PathBuf::from("./packages/a/src/index.js"))
+		);
+		assert_eq!(relative("/other/index.js"), None);
+	}
+}
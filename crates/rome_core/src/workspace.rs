@@ -0,0 +1,275 @@
+//! The files that Rome works on, shared between the parser, the diagnostics and the tools.
+//!
+//! The [Workspace] gives each file a [FileId] that stays the same for as long as the workspace
+//! lives, even when the content of the file changes. The ids are the ones passed to the parser,
+//! so the diagnostics of any file can be printed with the workspace, which implements [Files].
+//!
+//! The workspace caches what's computed from the content of a file, like its line index and its
+//! syntax tree, and throws the caches away when the content changes or when the file is removed.
+use crate::fs::FileSystem;
+use crate::normalize;
+use rslint_errors::file::{line_starts, FileId, Files};
+use rslint_parser::ast::JsAnyRoot;
+use rslint_parser::{parse, Parse, Syntax};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// The files of a workspace, by [FileId]
+#[derive(Debug, Default)]
+pub struct Workspace {
+	/// The removed files leave an empty slot, so that their id isn't given to another file
+	files: Vec<Option<WorkspaceFile>>,
+	ids: HashMap<PathBuf, FileId>,
+}
+
+#[derive(Debug)]
+struct WorkspaceFile {
+	path: PathBuf,
+	/// The path as printed in the diagnostics
+	name: String,
+	source: String,
+	line_starts: Vec<usize>,
+	/// The last syntax tree, with the syntax it was parsed with
+	parse: Option<(Syntax, Parse<JsAnyRoot>)>,
+}
+
+impl Workspace {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the id of the file at `path`, and adds the file if the workspace doesn't contain
+	/// it yet. The content of a new file is empty until it's set with [Workspace::set_source].
+	pub fn add(&mut self, path: impl AsRef<Path>) -> FileId {
+		let path = normalize(path.as_ref());
+		if let Some(id) = self.ids.get(&path) {
+			return *id;
+		}
+
+		let id = self.files.len();
+		self.files.push(Some(WorkspaceFile {
+			name: path.display().to_string(),
+			path: path.clone(),
+			source: String::new(),
+			line_starts: vec![0],
+			parse: None,
+		}));
+		self.ids.insert(path, id);
+		id
+	}
+
+	/// Adds the file at `path` with its content, or replaces the content if the workspace already
+	/// contains the file, and returns its id
+	pub fn insert(&mut self, path: impl AsRef<Path>, source: impl Into<String>) -> FileId {
+		let id = self.add(path);
+		self.set_source(id, source);
+		id
+	}
+
+	/// Reads the file at `path` through `fs`, and adds it like [Workspace::insert]
+	pub fn read(&mut self, fs: &dyn FileSystem, path: impl AsRef<Path>) -> io::Result<FileId> {
+		let path = path.as_ref();
+		let source = fs.read_to_string(path)?;
+		Ok(self.insert(path, source))
+	}
+
+	/// Replaces the content of a file, and returns `true` if it changed.
+	///
+	/// The caches of the file are only thrown away if the content changed.
+	///
+	/// # Panics
+	/// Panics if the workspace doesn't contain a file with this id.
+	pub fn set_source(&mut self, id: FileId, source: impl Into<String>) -> bool {
+		let file = self
+			.file_mut(id)
+			.expect("the workspace doesn't contain the file");
+		let source = source.into();
+		if file.source == source {
+			return false;
+		}
+
+		file.line_starts = line_starts(&source).collect();
+		file.parse = None;
+		file.source = source;
+		true
+	}
+
+	/// Removes a file along with its caches, and returns `true` if the workspace contained it.
+	///
+	/// The file gets a new id if it's added again.
+	pub fn remove(&mut self, id: FileId) -> bool {
+		match self.files.get_mut(id).and_then(Option::take) {
+			Some(file) => {
+				self.ids.remove(&file.path);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Returns the id of the file at `path`, if the workspace contains it
+	pub fn file_id(&self, path: impl AsRef<Path>) -> Option<FileId> {
+		self.ids.get(&normalize(path.as_ref())).copied()
+	}
+
+	/// Returns the path of a file
+	pub fn path(&self, id: FileId) -> Option<&Path> {
+		self.file(id).map(|file| file.path.as_path())
+	}
+
+	/// Parses a file with `syntax`, using the file id of the workspace.
+	///
+	/// The syntax tree is cached until the content of the file changes, or until the file is
+	/// parsed with another syntax.
+	pub fn parse(&mut self, id: FileId, syntax: Syntax) -> Option<Parse<JsAnyRoot>> {
+		let file = self.file_mut(id)?;
+
+		match &file.parse {
+			Some((cached_syntax, parse)) if *cached_syntax == syntax => Some(parse.clone()),
+			_ => {
+				let parse = parse(&file.source, id, syntax);
+				file.parse = Some((syntax, parse.clone()));
+				Some(parse)
+			}
+		}
+	}
+
+	fn file(&self, id: FileId) -> Option<&WorkspaceFile> {
+		self.files.get(id)?.as_ref()
+	}
+
+	fn file_mut(&mut self, id: FileId) -> Option<&mut WorkspaceFile> {
+		self.files.get_mut(id)?.as_mut()
+	}
+
+	fn line_start(&self, id: FileId, line_index: usize) -> Option<usize> {
+		let file = self.file(id)?;
+
+		match line_index.cmp(&file.line_starts.len()) {
+			Ordering::Less => file.line_starts.get(line_index).copied(),
+			Ordering::Equal => Some(file.source.len()),
+			Ordering::Greater => None,
+		}
+	}
+}
+
+impl Files for Workspace {
+	fn name(&self, id: FileId) -> Option<&str> {
+		self.file(id).map(|file| file.name.as_str())
+	}
+
+	fn source(&self, id: FileId) -> Option<&str> {
+		self.file(id).map(|file| file.source.as_str())
+	}
+
+	fn line_index(&self, id: FileId, byte_index: usize) -> Option<usize> {
+		let file = self.file(id)?;
+		Some(
+			file.line_starts
+				.binary_search(&byte_index)
+				.unwrap_or_else(|next_line| next_line - 1),
+		)
+	}
+
+	fn line_range(&self, id: FileId, line_index: usize) -> Option<Range<usize>> {
+		let line_start = self.line_start(id, line_index)?;
+		let next_line_start = self.line_start(id, line_index + 1)?;
+
+		Some(line_start..next_line_start)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::Workspace;
+	use crate::fs::MemoryFileSystem;
+	use rslint_errors::file::Files;
+	use rslint_parser::Syntax;
+	use std::path::Path;
+
+	#[test]
+	fn keeps_the_ids_of_the_files() {
+		let mut workspace = Workspace::new();
+		let a = workspace.insert("src/a.js", "let a = 1;\n");
+		let b = workspace.insert("src/b.js", "let b = 2;\nlet c = 3;\n");
+
+		assert_ne!(a, b);
+		assert_eq!(workspace.insert("./src/a.js", "let a = 2;\n"), a);
+		assert_eq!(workspace.file_id("src/b.js"), Some(b));
+		assert_eq!(workspace.file_id("src/c.js"), None);
+		assert_eq!(workspace.path(b), Some(Path::new("src/b.js")));
+
+		let parent = workspace.insert("../src/a.js", "let parent = 1;\n");
+		assert_ne!(parent, a);
+		assert_eq!(workspace.file_id("lib/../../src/a.js"), Some(parent));
+		assert_eq!(workspace.path(parent), Some(Path::new("../src/a.js")));
+
+		assert_eq!(workspace.name(a), Some("src/a.js"));
+		assert_eq!(workspace.source(a), Some("let a = 2;\n"));
+		assert_eq!(workspace.line_index(b, 13), Some(1));
+		assert_eq!(workspace.line_range(b, 1), Some(11..22));
+		assert_eq!(workspace.line_range(b, 3), None);
+	}
+
+	#[test]
+	fn parses_with_the_id_of_the_file() {
+		let mut workspace = Workspace::new();
+		workspace.insert("src/a.js", "let a = 1;\n");
+		let id = workspace.insert("src/b.js", "let b = ;\n");
+
+		let parse = workspace.parse(id, Syntax::default()).unwrap();
+		assert_eq!(parse.errors()[0].file_id, id);
+	}
+
+	#[test]
+	fn removes_the_files() {
+		let mut workspace = Workspace::new();
+		let a = workspace.insert("src/a.js", "let a = 1;\n");
+		let b = workspace.insert("src/b.js", "let b = 2;\n");
+		workspace.parse(a, Syntax::default()).unwrap();
+
+		assert!(workspace.remove(a));
+		assert!(!workspace.remove(a));
+		assert_eq!(workspace.file_id("src/a.js"), None);
+		assert_eq!(workspace.source(a), None);
+		assert!(workspace.parse(a, Syntax::default()).is_none());
+		assert_eq!(workspace.source(b), Some("let b = 2;\n"));
+
+		// The id of a removed file isn't reused
+		let added = workspace.insert("src/a.js", "let a = 1;\n");
+		assert_ne!(added, a);
+		assert_ne!(added, b);
+	}
+
+	#[test]
+	fn invalidates_the_caches_when_the_content_changes() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("src/a.js", "let a = 1;\n");
+		let mut workspace = Workspace::new();
+		let id = workspace.read(&fs, "src/a.js").unwrap();
+
+		let first = workspace.parse(id, Syntax::default()).unwrap();
+		let cached = workspace.parse(id, Syntax::default()).unwrap();
+		assert_eq!(first.syntax(), cached.syntax());
+
+		// Setting the same content keeps the caches
+		assert!(!workspace.set_source(id, "let a = 1;\n"));
+		assert_eq!(
+			workspace.parse(id, Syntax::default()).unwrap().syntax(),
+			first.syntax()
+		);
+
+		assert!(workspace.set_source(id, "let a = 1;\nlet b = ;\n"));
+		let changed = workspace.parse(id, Syntax::default()).unwrap();
+		assert_ne!(changed.syntax(), first.syntax());
+		assert_eq!(changed.errors().len(), 1);
+		assert_eq!(workspace.line_range(id, 1), Some(11..21));
+
+		// Another syntax parses the file again
+		let module = workspace.parse(id, Syntax::default().module()).unwrap();
+		assert_ne!(module.syntax(), changed.syntax());
+	}
+}
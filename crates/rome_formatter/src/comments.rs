@@ -0,0 +1,154 @@
+//! Formatting of the comments, which the parser stores inside the trivia of the tokens.
+//!
+//! The comments after a token, up to the end of its line, are in the trailing trivia of the
+//! token. The other comments are in the leading trivia of the next token. So, each comment is
+//! printed exactly once, as long as each token is printed once with [Formatter::format_token],
+//! [Formatter::format_replaced] or [Formatter::format_raw].
+//!
+//! The comments keep their position relative to the line breaks of the source:
+//! - a comment on its own line stays on its own line, which keeps the documentation comments
+//!   above the declarations;
+//! - a line comment is always followed by a line break. When it ends the line of a token, it's
+//!   printed as a [line_suffix], so that it stays at the end of the line;
+//! - the comments before a closing bracket, like the comments of an empty block, are indented
+//!   like the content of the brackets.
+//!
+//! [Formatter::format_token]: crate::Formatter::format_token
+//! [Formatter::format_replaced]: crate::Formatter::format_replaced
+//! [Formatter::format_raw]: crate::Formatter::format_raw
+use crate::{
+	concat_elements, format_elements, hard_line_break, indent, line_suffix, space_token, token,
+	FormatElement,
+};
+use rslint_parser::{SyntaxToken, T};
+
+/// Returns `true` if there are comments in the leading trivia of the token
+pub(crate) fn has_leading_comments(token: &SyntaxToken) -> bool {
+	token
+		.leading_trivia()
+		.pieces()
+		.any(|piece| piece.as_comments().is_some())
+}
+
+/// Returns `true` if there are comments in the trailing trivia of the token
+pub(crate) fn has_trailing_comments(token: &SyntaxToken) -> bool {
+	token
+		.trailing_trivia()
+		.pieces()
+		.any(|piece| piece.as_comments().is_some())
+}
+
+/// Formats the comments of the leading trivia of a token, which are printed before the token
+pub(crate) fn format_leading_comments(token: &SyntaxToken) -> FormatElement {
+	let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+	let mut elements = vec![];
+	let mut is_after_line_break = false;
+	let mut is_first_on_own_line = false;
+
+	for (index, piece) in pieces.iter().enumerate() {
+		let comment = match piece.as_comments() {
+			Some(comment) => comment,
+			None => {
+				is_after_line_break |= has_line_break(piece.text());
+				continue;
+			}
+		};
+
+		if elements.is_empty() {
+			is_first_on_own_line = is_after_line_break;
+		}
+		if is_after_line_break {
+			elements.push(hard_line_break());
+		}
+		elements.push(format_comment(comment.text()));
+
+		let next_whitespace = pieces
+			.get(index + 1)
+			.and_then(|next| next.as_whitespace())
+			.map(|whitespace| whitespace.text().to_string());
+		if is_line_comment(comment.text())
+			|| next_whitespace.as_deref().map_or(false, has_line_break)
+		{
+			elements.push(hard_line_break());
+		} else if next_whitespace.is_some() {
+			elements.push(space_token());
+		}
+
+		is_after_line_break = false;
+	}
+
+	let comments = concat_elements(elements);
+	if is_first_on_own_line && matches!(token.kind(), T!['}'] | T![']'] | T![')']) {
+		format_elements![indent(comments), hard_line_break()]
+	} else {
+		comments
+	}
+}
+
+/// Formats the comments of the trailing trivia of a token, which are printed after the token
+pub(crate) fn format_trailing_comments(token: &SyntaxToken) -> FormatElement {
+	let pieces: Vec<_> = token.trailing_trivia().pieces().collect();
+	let mut elements = vec![];
+
+	for (index, piece) in pieces.iter().enumerate() {
+		let comment = match piece.as_comments() {
+			Some(comment) => comment,
+			None => continue,
+		};
+
+		if is_line_comment(comment.text()) {
+			elements.push(line_suffix(format_elements![
+				space_token(),
+				format_comment(comment.text())
+			]));
+			continue;
+		}
+
+		let is_after_whitespace = index > 0 && pieces[index - 1].as_whitespace().is_some();
+		let is_before_whitespace = pieces
+			.get(index + 1)
+			.map_or(false, |next| next.as_whitespace().is_some());
+
+		if is_after_whitespace {
+			elements.push(space_token());
+		}
+		elements.push(format_comment(comment.text()));
+		if is_before_whitespace {
+			elements.push(space_token());
+		}
+	}
+
+	concat_elements(elements)
+}
+
+/// Formats the text of a comment.
+///
+/// The lines of a block comment whose lines start with `*`, like the documentation comments, are
+/// indented at the level of the comment. The other comments are printed as is.
+fn format_comment(text: &str) -> FormatElement {
+	let text = text.replace("\r\n", "\n").replace('\r', "\n");
+	let mut lines = text.split('\n');
+	let first_line = lines.next().unwrap_or_default();
+	let other_lines: Vec<&str> = lines.map(str::trim_start).collect();
+
+	if other_lines.is_empty() || !other_lines.iter().all(|line| line.starts_with('*')) {
+		return token(text);
+	}
+
+	let mut elements = vec![token(first_line)];
+	for line in other_lines {
+		elements.push(hard_line_break());
+		elements.push(token(format!(" {}", line)));
+	}
+
+	concat_elements(elements)
+}
+
+fn is_line_comment(text: &str) -> bool {
+	!text.starts_with("/*")
+}
+
+fn has_line_break(text: &str) -> bool {
+	text.chars()
+		.any(|char| matches!(char, '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+}
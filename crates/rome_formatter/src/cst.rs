@@ -1,16 +1,18 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::{
 	CallExpr, ForStmt, ForStmtTest, ForStmtUpdate, JsArrayBindingPattern, JsArrayExpression,
-	JsArrowFunctionExpression, JsBlockStatement, JsBooleanLiteralExpression, JsCallArguments,
-	JsCaseClause, JsCatchClause, JsClassDeclaration, JsConstructorParameters, JsContinueStatement,
+	JsArrowFunctionExpression, JsAssignmentExpression, JsAwaitExpression, JsBinaryExpression,
+	JsBlockStatement, JsBooleanLiteralExpression, JsCallArguments, JsCaseClause, JsCatchClause,
+	JsClassDeclaration, JsConditionalExpression, JsConstructorParameters, JsContinueStatement,
 	JsDebuggerStatement, JsDefaultClause, JsDoWhileStatement, JsEmptyStatement,
 	JsExpressionStatement, JsFinallyClause, JsForInStatement, JsFunctionDeclaration,
 	JsGetterClassMember, JsIdentifierBinding, JsIdentifierExpression, JsIfStatement,
-	JsLabeledStatement, JsNullLiteralExpression, JsNumberLiteralExpression, JsObjectExpression,
-	JsParameters, JsPropertyClassMember, JsPropertyObjectMember, JsReturnStatement, JsScript,
-	JsSequenceExpression, JsSetterClassMember, JsShorthandPropertyObjectMember, JsSpread,
-	JsStringLiteralExpression, JsSwitchStatement, JsTryStatement, JsVariableDeclaration,
-	JsVariableStatement, JsWhileStatement, JsWithStatement,
+	JsLabeledStatement, JsLogicalExpression, JsModule, JsNullLiteralExpression,
+	JsNumberLiteralExpression, JsObjectExpression, JsParameters, JsPropertyClassMember,
+	JsPropertyObjectMember, JsReturnStatement, JsScript, JsSequenceExpression, JsSetterClassMember,
+	JsShorthandPropertyObjectMember, JsSpread, JsStringLiteralExpression, JsSwitchStatement,
+	JsTryStatement, JsUnaryExpression, JsVariableDeclaration, JsVariableStatement,
+	JsWhileStatement, JsWithStatement, JsYieldExpression,
 };
 use rslint_parser::{AstNode, SyntaxKind, SyntaxNode};
 
@@ -55,6 +57,9 @@ impl ToFormatElement for SyntaxNode {
 			SyntaxKind::JS_SCRIPT => JsScript::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
+			SyntaxKind::JS_MODULE => JsModule::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
 			SyntaxKind::JS_SPREAD => JsSpread::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
@@ -168,6 +173,27 @@ impl ToFormatElement for SyntaxNode {
 			SyntaxKind::JS_PROPERTY_CLASS_MEMBER => JsPropertyClassMember::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
+			SyntaxKind::JS_BINARY_EXPRESSION => JsBinaryExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JS_LOGICAL_EXPRESSION => JsLogicalExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JS_CONDITIONAL_EXPRESSION => JsConditionalExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JS_ASSIGNMENT_EXPRESSION => JsAssignmentExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JS_UNARY_EXPRESSION => JsUnaryExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JS_AWAIT_EXPRESSION => JsAwaitExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
+			SyntaxKind::JS_YIELD_EXPRESSION => JsYieldExpression::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
 
 			_ => todo!(
 				"Implement formatting for the {:?} syntax kind.",
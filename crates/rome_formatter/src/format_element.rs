@@ -1,5 +1,6 @@
 use crate::format_elements;
 use crate::intersperse::Intersperse;
+use rslint_parser::TextSize;
 use std::ops::Deref;
 
 type Content = Box<FormatElement>;
@@ -576,6 +577,58 @@ where
 	}
 }
 
+/// Content that gets printed at the end of the line, right before the next line break, like the
+/// comments that end a line. A group containing a line suffix never fits on a single line, since
+/// anything printed after the suffix on the same line would end up inside of it.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, token, space_token, hard_line_break, line_suffix, FormatOptions};
+///
+/// let elements = format_elements![
+///   token("a"),
+///   line_suffix(format_elements![space_token(), token("// comment")]),
+///   token(";"),
+///   hard_line_break(),
+///   token("b;"),
+/// ];
+///
+/// assert_eq!("a; // comment\nb;", format_element(&elements, FormatOptions::default()).code());
+/// ```
+#[inline]
+pub fn line_suffix<T: Into<FormatElement>>(content: T) -> FormatElement {
+	let content = content.into();
+
+	if content.is_empty() {
+		content
+	} else {
+		FormatElement::from(LineSuffix::new(content))
+	}
+}
+
+/// Marks the position in the source of the token that follows, so that the printer can map the
+/// position of the token in the formatted code back to the source, see [crate::SourceMap].
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, source_position, token, FormatOptions};
+/// use rslint_parser::TextSize;
+///
+/// let elements = format_elements![
+///   source_position(TextSize::from(4)),
+///   token("a"),
+/// ];
+///
+/// let formatted = format_element(&elements, FormatOptions::default());
+/// assert_eq!(TextSize::from(4), formatted.source_markers()[0].source);
+/// ```
+#[inline]
+pub fn source_position(position: TextSize) -> FormatElement {
+	FormatElement::SourcePosition(position)
+}
+
 /// Language agnostic IR for formatting source code.
 ///
 /// Use the helper functions like [space], [soft_line_break] etc. defined in this file to create elements.
@@ -608,6 +661,13 @@ pub enum FormatElement {
 
 	/// A token that should be printed as is, see [token] for documentation and examples.
 	Token(Token),
+
+	/// Content printed at the end of the line, see [line_suffix] for documentation and examples.
+	LineSuffix(LineSuffix),
+
+	/// The position in the source of the next token, see [source_position] for documentation
+	/// and examples.
+	SourcePosition(TextSize),
 }
 
 /// Inserts a new line
@@ -708,6 +768,20 @@ impl ConditionalGroupContent {
 	}
 }
 
+/// See [line_suffix] for documentation
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LineSuffix {
+	pub(crate) content: Content,
+}
+
+impl LineSuffix {
+	pub fn new(content: FormatElement) -> Self {
+		Self {
+			content: Box::new(content),
+		}
+	}
+}
+
 /// See [token] for documentation
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Token(String);
@@ -745,27 +819,36 @@ impl FormatElement {
 			FormatElement::Group(g) => g.content.trim_start(),
 			FormatElement::ConditionalGroupContent(g) => g.content.trim_start(),
 			FormatElement::List(list) => {
-				let mut content: Vec<_> = list
-					.iter()
-					.skip_while(|e| match e {
-						FormatElement::Empty => true,
-						FormatElement::Space => true,
-						FormatElement::Line(_) => true,
-						FormatElement::Indent(_) => true,
+				let mut content = Vec::with_capacity(list.len());
+				let mut elements = list.iter();
+
+				// The source positions are kept, the printer maps the last one to the next token
+				for element in &mut elements {
+					match element {
+						FormatElement::Empty
+						| FormatElement::Space
+						| FormatElement::Line(_)
+						| FormatElement::Indent(_) => {}
+						FormatElement::SourcePosition(_) => content.push(element.clone()),
 						FormatElement::Token(t) => {
 							let s = t.trim_start();
-							s.is_empty()
+							if !s.is_empty() {
+								content.push(token(s));
+								break;
+							}
+						}
+						_ => {
+							content.push(element.clone());
+							break;
 						}
-						_ => false,
-					})
-					.map(Clone::clone)
-					.collect();
-				if let Some(FormatElement::Token(s)) = content.get_mut(0) {
-					s.0 = s.trim_start().to_string()
+					}
 				}
+
+				content.extend(elements.cloned());
 				FormatElement::List(List::new(content))
 			}
 			FormatElement::Token(s) => token(s.trim_start()),
+			FormatElement::LineSuffix(_) | FormatElement::SourcePosition(_) => self.clone(),
 		}
 	}
 
@@ -786,6 +869,8 @@ impl FormatElement {
 					FormatElement::Space => false,
 					FormatElement::Line(_) => false,
 					FormatElement::Indent(_) => false,
+					// There's no token after the trailing source positions
+					FormatElement::SourcePosition(_) => false,
 					FormatElement::Token(t) => {
 						let s = t.trim_end();
 						!s.is_empty()
@@ -810,6 +895,7 @@ impl FormatElement {
 				}
 			}
 			FormatElement::Token(s) => token(s.trim_end()),
+			FormatElement::LineSuffix(_) | FormatElement::SourcePosition(_) => self.clone(),
 		}
 	}
 }
@@ -838,6 +924,12 @@ impl From<Line> for FormatElement {
 	}
 }
 
+impl From<LineSuffix> for FormatElement {
+	fn from(suffix: LineSuffix) -> Self {
+		FormatElement::LineSuffix(suffix)
+	}
+}
+
 impl From<Indent> for FormatElement {
 	fn from(token: Indent) -> Self {
 		FormatElement::Indent(token)
@@ -1,17 +1,21 @@
 use crate::format_element::{join_elements, soft_line_break_or_space};
+use crate::printer::Printer;
+use crate::range::{indent_level, line_prefix, replace_range};
 use crate::{
-	format_element::FormatElement, format_elements, group_elements, hard_line_break, soft_indent,
-	space_token, token,
+	format_element, format_element::FormatElement, format_elements, group_elements,
+	hard_line_break, indent, soft_indent, space_token, token, FormatOptions,
 };
 use rslint_parser::ast::{
-	JsAnyObjectMember, JsArrayExpression, JsBooleanLiteralExpression, JsLiteralMemberName,
-	JsNullLiteralExpression, JsNumberLiteralExpression, JsObjectExpression,
+	JsAnyExpression, JsAnyObjectMember, JsArrayExpression, JsBooleanLiteralExpression,
+	JsLiteralMemberName, JsNullLiteralExpression, JsNumberLiteralExpression, JsObjectExpression,
 	JsParenthesizedExpression, JsPropertyObjectMember, JsStringLiteralExpression,
 	JsUnaryExpression,
 };
 use rslint_parser::{
 	parse_text, AstNode, AstSeparatedList, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken,
+	TextRange, TextSize,
 };
+use rslint_text_edit::TextEdit;
 
 fn tokenize_token(syntax_token: SyntaxToken) -> FormatElement {
 	match syntax_token.kind() {
@@ -116,10 +120,83 @@ fn tokenize_node(node: SyntaxNode) -> FormatElement {
 }
 
 pub fn tokenize_json(content: &str) -> FormatElement {
+	let tokenized_content = tokenize_node(parse_json(content).syntax().clone());
+	format_elements![tokenized_content, hard_line_break()]
+}
+
+/// Formats the smallest value or object member of the JSON `content` that contains `range`, at the
+/// indentation of its line, and returns the edit that replaces it with the formatted code. The rest
+/// of the content is left as is. The whole content is formatted if only the root value contains
+/// the range.
+pub(crate) fn format_json_range(
+	content: &str,
+	range: TextRange,
+	options: FormatOptions,
+) -> TextEdit {
+	let json_content = parse_json(content);
+	let root = json_content.syntax();
+
+	// The content is parsed inside of parentheses, which shift its offsets by one
+	let offset = TextSize::from(1);
+	let root_range = root.text_trimmed_range();
+	let range = (range + offset)
+		.intersect(root_range)
+		.unwrap_or_else(|| TextRange::empty(root_range.end()));
+
+	let node = root
+		.descendants()
+		.skip(1)
+		.filter(|node| {
+			is_json_value(node.kind()) && node.text_trimmed_range().contains_range(range)
+		})
+		.last();
+
+	let node = match node {
+		Some(node) => node,
+		None => {
+			let formatted = format_element(&tokenize_json(content), options);
+			return replace_range(
+				content,
+				TextRange::up_to(TextSize::of(content)),
+				formatted.code(),
+			);
+		}
+	};
+
+	let replaced = node.text_trimmed_range() - offset;
+	let prefix = line_prefix(content, replaced.start());
+
+	let mut element = tokenize_node(node);
+	for _ in 0..indent_level(prefix, &options) {
+		element = indent(element);
+	}
+
+	// Prints the start of the line, so that the printer knows the width of the first line
+	let formatted = Printer::new(options).print(&format_elements![token(prefix), element]);
+	replace_range(content, replaced, &formatted.code()[prefix.len()..])
+}
+
+/// Returns `true` for the nodes that [format_json_range] can format on their own
+fn is_json_value(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::JS_OBJECT_EXPRESSION
+			| SyntaxKind::JS_ARRAY_EXPRESSION
+			| SyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+			| SyntaxKind::JS_STRING_LITERAL_EXPRESSION
+			| SyntaxKind::JS_NUMBER_LITERAL_EXPRESSION
+			| SyntaxKind::JS_BOOLEAN_LITERAL_EXPRESSION
+			| SyntaxKind::JS_NULL_LITERAL_EXPRESSION
+			| SyntaxKind::JS_UNARY_EXPRESSION
+	)
+}
+
+/// Parses the JSON content as a JavaScript expression
+fn parse_json(content: &str) -> JsAnyExpression {
 	let script = parse_text(format!("({})", content).as_str(), 0);
 
 	// Unwrap the grouping to get to the JSON content. The grouping is only used as a trick to parse JSON
-	let json_content = JsParenthesizedExpression::cast(
+	JsParenthesizedExpression::cast(
 		script
 			.syntax()
 			.descendants()
@@ -128,10 +205,7 @@ pub fn tokenize_json(content: &str) -> FormatElement {
 	)
 	// TODO: #1725 this should be reviewed for error handling
 	.and_then(|grouping| grouping.expression().ok())
-	.unwrap();
-
-	let tokenized_content = tokenize_node(json_content.syntax().clone());
-	format_elements![tokenized_content, hard_line_break()]
+	.unwrap()
 }
 
 #[cfg(test)]
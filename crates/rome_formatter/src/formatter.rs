@@ -1,10 +1,16 @@
+use crate::comments::{format_leading_comments, format_trailing_comments, has_leading_comments};
 use crate::printer::Printer;
+use crate::range::{indent_level, line_prefix, nodes_in_range, replace_range, replaced_range};
 use crate::{
-	concat_elements, format_elements, if_group_breaks, token, FormatElement, FormatOptions,
-	FormatResult, Formatted, ToFormatElement,
+	concat_elements, empty_element, format_elements, hard_line_break, if_group_breaks, indent,
+	join_elements, source_position, token, FormatElement, FormatError, FormatOptions, FormatResult,
+	Formatted, ToFormatElement,
 };
-use rome_rowan::SyntaxElement;
-use rslint_parser::{AstNode, AstSeparatedList, SyntaxNode, SyntaxToken};
+use rslint_parser::ast::{JsAnyClassMember, JsAnyModuleItem, JsAnyObjectMember, JsAnyStatement};
+use rslint_parser::{AstNode, AstSeparatedList, SyntaxNode, SyntaxToken, TextRange};
+use rslint_text_edit::TextEdit;
+use std::cell::RefCell;
+use std::collections::HashSet;
 
 /// Handles the formatting of a CST and stores the options how the CST should be formatted (user preferences).
 /// The formatter is passed to the [ToFormatElement] implementation of every node in the CST so that they
@@ -12,12 +18,43 @@ use rslint_parser::{AstNode, AstSeparatedList, SyntaxNode, SyntaxToken};
 #[derive(Debug, Default)]
 pub struct Formatter {
 	options: FormatOptions,
+	/// The tokens whose leading comments were printed before the node they start,
+	/// see [Formatter::format_node_start]
+	printed_comments: RefCell<PrintedComments>,
+	/// The ranges of the nodes printed as they are in the source, see [Formatter::format_verbatim]
+	verbatim_ranges: RefCell<Vec<TextRange>>,
+}
+
+/// Returns `true` if `node` is printed as it is in the source when it, or one of its
+/// descendants, can't be formatted. These nodes are printed on their own line, or separated by
+/// their tokens in the source, so they can be printed as they are without changing the code
+/// around them.
+fn is_verbatim_boundary(node: &SyntaxNode) -> bool {
+	let kind = node.kind();
+
+	node.parent().is_none()
+		|| JsAnyModuleItem::can_cast(kind)
+		|| JsAnyStatement::can_cast(kind)
+		|| JsAnyClassMember::can_cast(kind)
+		|| JsAnyObjectMember::can_cast(kind)
+}
+
+/// The tokens whose leading comments were already printed, in the order they were printed,
+/// so that they can be forgotten when the formatting of a node fails
+#[derive(Debug, Default)]
+struct PrintedComments {
+	tokens: HashSet<SyntaxToken>,
+	order: Vec<SyntaxToken>,
 }
 
 impl Formatter {
 	/// Creates a new context that uses the given formatter options
 	pub fn new(options: FormatOptions) -> Self {
-		Self { options }
+		Self {
+			options,
+			printed_comments: RefCell::default(),
+			verbatim_ranges: RefCell::default(),
+		}
 	}
 
 	/// Returns the [FormatOptions] specifying how to format the current CST
@@ -31,7 +68,63 @@ impl Formatter {
 		let element = self.format_syntax_node(root)?;
 
 		let printer = Printer::new(self.options);
-		Ok(printer.print(&element))
+		Ok(printer
+			.print(&element)
+			.with_verbatim_ranges(self.verbatim_ranges.into_inner()))
+	}
+
+	/// Formats the part of a CST that contains `range`, and returns the edit that replaces it
+	/// with the formatted code. The rest of the source is left as is.
+	///
+	/// The smallest statements, module items or class members that contain the range are
+	/// formatted, at the indentation of their line in the source. The whole CST is formatted if
+	/// no such node contains the range.
+	pub fn format_range(self, root: &SyntaxNode, range: TextRange) -> FormatResult<TextEdit> {
+		let source = root.text().to_string();
+		let range = range
+			.intersect(root.text_range())
+			.unwrap_or_else(|| TextRange::empty(root.text_range().end()));
+		let nodes = nodes_in_range(root, range);
+
+		let (first, last) = match (nodes.first(), nodes.last()) {
+			(Some(first), Some(last)) => (first, last),
+			_ => {
+				let formatted = self.format_root(root)?;
+				return Ok(replace_range(&source, root.text_range(), formatted.code()));
+			}
+		};
+
+		let replaced = replaced_range(first, last);
+		let prefix = line_prefix(&source, replaced.start());
+
+		let mut content = join_elements(
+			hard_line_break(),
+			nodes.iter().map(|node| self.format_range_node(node)),
+		);
+		for _ in 0..indent_level(prefix, &self.options) {
+			content = indent(content);
+		}
+
+		// Prints the start of the line, so that the printer knows the width of the first line
+		let printer = Printer::new(self.options);
+		let formatted = printer.print(&format_elements![token(prefix), content]);
+		// The leading comments start with a line break if they're on their own line
+		let code = formatted.code()[prefix.len()..].trim_start();
+
+		Ok(replace_range(&source, replaced, code))
+	}
+
+	/// Formats a node returned by [nodes_in_range], or prints it as is if it can't be formatted
+	fn format_range_node(&self, node: &SyntaxNode) -> FormatElement {
+		let formatted = if let Some(item) = JsAnyModuleItem::cast(node.clone()) {
+			self.format_node(item)
+		} else if let Some(member) = JsAnyClassMember::cast(node.clone()) {
+			self.format_node(member)
+		} else {
+			Err(FormatError::MissingRequiredChild)
+		};
+
+		formatted.unwrap_or_else(|_| self.format_verbatim(node))
 	}
 
 	fn format_syntax_node(&self, node: &SyntaxNode) -> FormatResult<FormatElement> {
@@ -46,30 +139,78 @@ impl Formatter {
 
 	/// Recursively formats the ast node and all its children
 	///
-	/// Returns `None` if the node couldn't be formatted because of syntax errors in its sub tree.
-	/// The parent may use `format_raw` to insert the node content as is.
+	/// If the node can't be formatted because of syntax errors in its sub tree, like a missing
+	/// child or an unknown node, the error is returned to the closest ancestor that is the root,
+	/// a statement or a class or object member, see [is_verbatim_boundary]. That node is printed
+	/// as it is in the source with [Formatter::format_verbatim], so that its parent never prints
+	/// its own tokens around code printed as it is.
 	pub fn format_node<T: AstNode + ToFormatElement>(
 		&self,
 		node: T,
 	) -> FormatResult<FormatElement> {
-		Ok(concat_elements(vec![
-			self.format_node_start(node.syntax()),
-			node.to_format_element(self)?,
-			self.format_node_end(node.syntax()),
-		]))
+		// The comments printed while formatting the node are printed again by `format_raw`
+		let printed_comments = self.printed_comments.borrow().order.len();
+		let verbatim_ranges = self.verbatim_ranges.borrow().len();
+		let start = self.format_node_start(node.syntax());
+
+		match node.to_format_element(self) {
+			Ok(content) => Ok(concat_elements(vec![
+				start,
+				content,
+				self.format_node_end(node.syntax()),
+			])),
+			Err(error) if !is_verbatim_boundary(node.syntax()) => Err(error),
+			Err(_) => {
+				{
+					let mut printed = self.printed_comments.borrow_mut();
+					let forgotten: Vec<_> = printed.order.drain(printed_comments..).collect();
+					for token in forgotten {
+						printed.tokens.remove(&token);
+					}
+				}
+				// The range of the node contains the ranges of its children printed as is
+				self.verbatim_ranges.borrow_mut().truncate(verbatim_ranges);
+
+				Ok(self.format_verbatim(node.syntax()))
+			}
+		}
 	}
 
 	/// Helper function that returns what should be printed before the node that work on
 	/// the non-generic [SyntaxNode] to avoid unrolling the logic for every [AstNode] type.
-	fn format_node_start(&self, _node: &SyntaxNode) -> FormatElement {
-		// TODO: Set the marker for the start source map location, add leading comments, ...
-		concat_elements(vec![])
+	///
+	/// The leading comments of the first token are printed before the outermost node that starts
+	/// with it, rather than with the token, so that a comment on its own line doesn't break the
+	/// groups that the node creates.
+	fn format_node_start(&self, node: &SyntaxNode) -> FormatElement {
+		match node.first_token() {
+			Some(first_token) if has_leading_comments(&first_token) => {
+				let mut printed = self.printed_comments.borrow_mut();
+				if printed.tokens.insert(first_token.clone()) {
+					printed.order.push(first_token.clone());
+					format_leading_comments(&first_token)
+				} else {
+					empty_element()
+				}
+			}
+			_ => empty_element(),
+		}
+	}
+
+	/// Returns the leading comments of a token, unless they were already printed before a node
+	fn format_token_leading_comments(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		if self.printed_comments.borrow().tokens.contains(syntax_token) {
+			empty_element()
+		} else {
+			format_leading_comments(syntax_token)
+		}
 	}
 
 	/// Helper function that returns what should be printed after the node that work on
 	/// the non-generic [SyntaxNode] to avoid unrolling the logic for every [AstNode] type.
+	///
+	/// The source map markers are set by the tokens, see [Formatter::format_replaced].
 	fn format_node_end(&self, _node: &SyntaxNode) -> FormatElement {
-		// TODO: Sets the marker for the end source map location, add trailing comments, ...
 		concat_elements(vec![])
 	}
 
@@ -82,8 +223,8 @@ impl Formatter {
 	///
 	/// ```
 	///
-	/// use rome_formatter::{Formatter, token};
-	/// use rslint_parser::{SyntaxNode, T, SyntaxToken, JsLanguage, SyntaxKind};
+	/// use rome_formatter::{format_elements, source_position, Formatter, token};
+	/// use rslint_parser::{SyntaxNode, T, SyntaxToken, JsLanguage, SyntaxKind, TextSize};
 	/// use rome_rowan::{NodeOrToken, TreeBuilder};
 	///
 	/// let mut builder = TreeBuilder::<'_, JsLanguage>::new();
@@ -97,10 +238,51 @@ impl Formatter {
 	/// let formatter = Formatter::default();
 	/// let result = formatter.format_token(&syntax_token);
 	///
-	/// assert_eq!(Ok(token("'abc'")), result)
+	/// assert_eq!(
+	///   Ok(format_elements![source_position(TextSize::from(0)), token("'abc'")]),
+	///   result
+	/// )
 	/// ```
 	pub fn format_token(&self, syntax_token: &SyntaxToken) -> FormatResult<FormatElement> {
-		Ok(token(syntax_token.text_trimmed()))
+		Ok(self.format_replaced(syntax_token, token(syntax_token.text_trimmed())))
+	}
+
+	/// Prints `replacement` instead of the text of a token, like a string literal printed with
+	/// other quotes. The comments of the token are printed around the replacement.
+	pub fn format_replaced(
+		&self,
+		syntax_token: &SyntaxToken,
+		replacement: FormatElement,
+	) -> FormatElement {
+		// An empty replacement prints no token, the marker would map the next token instead
+		let replacement = if replacement.is_empty() {
+			replacement
+		} else {
+			format_elements![
+				source_position(syntax_token.text_trimmed_range().start()),
+				replacement
+			]
+		};
+
+		format_elements![
+			self.format_token_leading_comments(syntax_token),
+			replacement,
+			format_trailing_comments(syntax_token)
+		]
+	}
+
+	/// Formats the token as `text` if it's present in the source, or else inserts a token with
+	/// `text`. Used for the tokens that are always printed, even though they're optional in the
+	/// source, like the semicolons.
+	pub fn format_or_create_token(
+		&self,
+		syntax_token: Option<SyntaxToken>,
+		text: &str,
+	) -> FormatElement {
+		match syntax_token {
+			Some(syntax_token) => self.format_replaced(&syntax_token, token(text)),
+			None => token(text),
+		}
 	}
 
 	/// Formats each child and returns the result as a list.
@@ -133,11 +315,16 @@ impl Formatter {
 		for (index, element) in list.elements().enumerate() {
 			let node = self.format_node(element.node()?)?;
 			if let Some(separator) = element.trailing_separator()? {
-				let formatted_separator = self.format_token(&separator)?;
-				if index == list.len() - 1 {
-					result.push(format_elements![node, if_group_breaks(formatted_separator)])
+				// The separator after an empty element, like an array hole, is what makes the
+				// element, so it's printed even if the group fits on a single line
+				if index == list.len() - 1 && !node.is_empty() {
+					let trailing_separator = if_group_breaks(token(separator.text_trimmed()));
+					result.push(format_elements![
+						node,
+						self.format_replaced(&separator, trailing_separator)
+					])
 				} else {
-					result.push(format_elements![node, formatted_separator]);
+					result.push(format_elements![node, self.format_token(&separator)?]);
 				}
 			} else {
 				result.push(node);
@@ -153,15 +340,60 @@ impl Formatter {
 	/// "mess up" the developers, yet incomplete, work or accidentally introduce new syntax errors.
 	///
 	/// You may be inclined to call `node.text` directly. However, using `text` doesn't track the nodes
-	///nor its children source mapping information, resulting in incorrect source maps for this subtree.
+	/// nor its children source mapping information, resulting in incorrect source maps for this subtree.
+	///
+	/// The comments are printed as is, except the leading comments already printed before a node,
+	/// and the trailing comments of the last token, which are formatted so that a line comment
+	/// doesn't comment out what's printed after the node.
 	pub fn format_raw(&self, node: &SyntaxNode) -> FormatElement {
-		concat_elements(node.children_with_tokens().map(|child| match child {
-			SyntaxElement::Node(child_node) => {
-				// TODO: Add source map markers before/after node as well as any additional elements that
-				// need to be tracked for every node.
-				self.format_raw(&child_node)
+		let last_token = node.last_token();
+
+		concat_elements(node.descendants_tokens().map(|syntax_token| {
+			let is_last = Some(&syntax_token) == last_token.as_ref();
+			let leading = if self
+				.printed_comments
+				.borrow()
+				.tokens
+				.contains(&syntax_token)
+			{
+				None
+			} else {
+				Some(syntax_token.leading_trivia())
+			};
+
+			let start = match &leading {
+				Some(leading) => leading.text_range().start(),
+				None => syntax_token.text_trimmed_range().start(),
+			};
+			let text = format!(
+				"{}{}",
+				leading.as_ref().map_or("", |leading| leading.text()),
+				syntax_token.text_trimmed()
+			);
+
+			if is_last {
+				format_elements![
+					source_position(start),
+					token(text),
+					format_trailing_comments(&syntax_token)
+				]
+			} else {
+				format_elements![
+					source_position(start),
+					token(format!("{}{}", text, syntax_token.trailing_trivia().text()))
+				]
 			}
-			SyntaxElement::Token(syntax_token) => token(syntax_token.text()),
 		}))
 	}
+
+	/// Prints a node that can't be formatted, because it contains syntax errors, as it is in the
+	/// source, see [Formatter::format_raw]. The range of the node is returned with the formatted
+	/// code, see [Formatted::verbatim_ranges], so that the users know which code was left as is.
+	pub fn format_verbatim(&self, node: &SyntaxNode) -> FormatElement {
+		self.verbatim_ranges
+			.borrow_mut()
+			.push(node.text_trimmed_range());
+
+		self.format_raw(node).trim_start().trim_end()
+	}
 }
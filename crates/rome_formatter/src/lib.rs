@@ -47,6 +47,7 @@
 //! ```
 //! [IR]: https://en.wikipedia.org/wiki/Intermediate_representation
 
+mod comments;
 mod cst;
 mod format_element;
 mod format_elements;
@@ -54,26 +55,31 @@ mod format_json;
 mod formatter;
 mod intersperse;
 mod printer;
+mod range;
+mod source_map;
 mod ts;
 
-use crate::format_json::tokenize_json;
+use crate::format_json::{format_json_range, tokenize_json};
 
 pub use formatter::Formatter;
 use rslint_parser::SyntaxError;
 
 pub use format_element::{
 	block_indent, concat_elements, empty_element, group_elements, hard_line_break, if_group_breaks,
-	if_group_fits_on_single_line, indent, join_elements, soft_indent, soft_line_break,
-	soft_line_break_or_space, space_token, token, FormatElement,
+	if_group_fits_on_single_line, indent, join_elements, line_suffix, soft_indent, soft_line_break,
+	soft_line_break_or_space, source_position, space_token, token, FormatElement,
 };
 pub use printer::Printer;
 pub use printer::PrinterOptions;
+use rome_core::configuration::{FormatterConfiguration, IndentKind};
 use rome_core::file_handlers::Language;
 use rome_core::App;
 use rome_path::RomePath;
-use rslint_parser::parse_text;
+use rslint_errors::{file::FileId, Diagnostic};
+use rslint_parser::{parse, TextRange};
+use rslint_text_edit::TextEdit;
+pub use source_map::{SourceMap, SourceMarker};
 
-use std::io::Read;
 use std::str::FromStr;
 
 /// This trait should be implemented on each node/value that should have a formatted representation
@@ -95,6 +101,63 @@ pub enum FormatError {
 
 	/// When the ability to format the current file has been turned off on purpose
 	CapabilityDisabled,
+
+	/// The source code contains syntax errors that prevent it from being formatted
+	SyntaxErrors(Vec<Diagnostic>),
+
+	/// The file couldn't be read or written, stores the message of the IO error
+	Io(String),
+
+	/// The parser couldn't make sense of a part of the code and created an unknown node for it
+	UnknownNode,
+}
+
+impl std::fmt::Display for FormatError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			FormatError::MissingRequiredChild => {
+				write!(
+					f,
+					"the file contains a node that is missing a required child"
+				)
+			}
+			FormatError::UnsupportedLanguage => {
+				write!(f, "the formatter doesn't support the language of the file")
+			}
+			FormatError::CapabilityDisabled => {
+				write!(f, "formatting is disabled for the file")
+			}
+			FormatError::SyntaxErrors(errors) => {
+				write!(f, "the file contains {} syntax error(s)", errors.len())
+			}
+			FormatError::Io(message) => write!(f, "{}", message),
+			FormatError::UnknownNode => {
+				write!(f, "the file contains code that couldn't be parsed")
+			}
+		}
+	}
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<std::io::Error> for FormatError {
+	fn from(error: std::io::Error) -> Self {
+		FormatError::Io(error.to_string())
+	}
+}
+
+impl FormatError {
+	/// Converts the error into diagnostics that can be rendered for the file with the given id.
+	///
+	/// The syntax errors are returned as they are, the other errors become a single diagnostic
+	/// that isn't attached to any part of the file.
+	pub fn into_diagnostics(self, file_id: FileId) -> Vec<Diagnostic> {
+		match self {
+			FormatError::SyntaxErrors(errors) => errors,
+			FormatError::Io(message) => vec![Diagnostic::error(file_id, "IO", message)],
+			error => vec![Diagnostic::error(file_id, "Format", error.to_string())],
+		}
+	}
 }
 
 impl From<SyntaxError> for FormatError {
@@ -105,7 +168,7 @@ impl From<SyntaxError> for FormatError {
 	}
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub enum IndentStyle {
 	/// Tab
 	Tab,
@@ -132,7 +195,7 @@ impl FromStr for IndentStyle {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct FormatOptions {
 	/// The indent style
 	pub indent_style: IndentStyle,
@@ -159,48 +222,255 @@ impl Default for FormatOptions {
 	}
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+impl From<&FormatterConfiguration> for FormatOptions {
+	/// Creates the options from the settings of a configuration, the settings that aren't set use
+	/// the default of the formatter
+	fn from(configuration: &FormatterConfiguration) -> Self {
+		let default = Self::default();
+
+		let indent_style = match configuration.indent_style {
+			Some(IndentKind::Tab) => IndentStyle::Tab,
+			Some(IndentKind::Space) => IndentStyle::Space(configuration.indent_size.unwrap_or(2)),
+			None => default.indent_style,
+		};
+
+		Self {
+			indent_style,
+			line_width: configuration.line_width.unwrap_or(default.line_width),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Formatted {
 	code: String,
+	diagnostics: Vec<Diagnostic>,
+	source_markers: Vec<SourceMarker>,
+	verbatim_ranges: Vec<TextRange>,
 }
 
 impl Formatted {
 	pub fn new(code: &str) -> Self {
 		Self {
 			code: String::from(code),
+			diagnostics: vec![],
+			source_markers: vec![],
+			verbatim_ranges: vec![],
 		}
 	}
 
+	/// Attaches the ranges of the source that were printed as they are
+	pub(crate) fn with_verbatim_ranges(mut self, verbatim_ranges: Vec<TextRange>) -> Self {
+		self.verbatim_ranges = verbatim_ranges;
+		self
+	}
+
+	/// Attaches the positions of the printed tokens in the source
+	pub(crate) fn with_source_markers(mut self, source_markers: Vec<SourceMarker>) -> Self {
+		self.source_markers = source_markers;
+		self
+	}
+
+	/// Attaches the diagnostics emitted while formatting the code
+	pub fn with_diagnostics(mut self, diagnostics: Vec<Diagnostic>) -> Self {
+		self.diagnostics = diagnostics;
+		self
+	}
+
 	pub fn code(&self) -> &String {
 		&self.code
 	}
+
+	/// The diagnostics emitted while formatting the code, like the syntax errors of the source
+	/// formatted with [format_source_lenient].
+	pub fn diagnostics(&self) -> &[Diagnostic] {
+		&self.diagnostics
+	}
+
+	/// The ranges of the source that couldn't be formatted because they contain syntax errors,
+	/// and were printed as they are. Each range also has a warning in [Formatted::diagnostics].
+	pub fn verbatim_ranges(&self) -> &[TextRange] {
+		&self.verbatim_ranges
+	}
+
+	/// The positions in the source of the printed tokens, ordered as they appear in the code
+	pub fn source_markers(&self) -> &[SourceMarker] {
+		&self.source_markers
+	}
+
+	/// Creates the source map that maps the code back to `source`, the formatted source whose
+	/// name is `source_name`
+	pub fn source_map(&self, source_name: &str, source: &str) -> SourceMap {
+		SourceMap::new(source_name, source, &self.source_markers)
+	}
 }
 
 // TODO: implement me + handle errors
 /// Main function
 pub fn format(rome_path: &mut RomePath, options: FormatOptions) -> FormatResult<Formatted> {
-	// we assume that file exists
-	let mut file = rome_path.open();
-	let mut buffer = String::new();
-	// we assume we have permissions
-	file.read_to_string(&mut buffer)
-		.expect("cannot read the file to format");
+	let buffer = rome_path.read_to_string()?;
+	format_source_lenient(rome_path, buffer.as_str(), options)
+}
+
+/// Formats source code with the handler associated to `rome_path`.
+///
+/// The path is only used to find the language of the source code, so it doesn't need to exist on disk.
+/// This is useful to format buffers that aren't saved yet, or code passed through the standard input.
+///
+/// Returns [FormatError::SyntaxErrors] if the source code contains syntax errors, because the
+/// formatter can't guarantee that the formatted code is correct. The errors point to the file id
+/// of `rome_path`, see [RomePath::with_file_id].
+///
+/// ```
+/// use rome_core::create_app;
+/// use rome_formatter::{format_source, FormatError, FormatOptions};
+/// use rome_path::RomePath;
+///
+/// let app = create_app();
+/// let rome_path = RomePath::new("virtual/file.js").deduce_handler(&app);
+/// let result = format_source(&rome_path, "let a  =  1", FormatOptions::default()).unwrap();
+///
+/// assert_eq!(result.code(), "let a = 1;\n");
+///
+/// let result = format_source(&rome_path, "let a = ;", FormatOptions::default());
+/// assert!(matches!(result, Err(FormatError::SyntaxErrors(_))));
+/// ```
+pub fn format_source(
+	rome_path: &RomePath,
+	source: &str,
+	options: FormatOptions,
+) -> FormatResult<Formatted> {
+	format_with_handler(rome_path, source, options, false)
+}
+
+/// Formats source code like [format_source], but formats it even if it contains syntax errors.
+///
+/// The syntax errors are returned with the formatted code, see [Formatted::diagnostics].
+/// The statements, and the class and object members, that contain errors are printed as they
+/// are in the source, and each of them has a warning in the diagnostics, see
+/// [Formatted::verbatim_ranges].
+///
+/// ```
+/// use rome_core::create_app;
+/// use rome_formatter::{format_source_lenient, FormatOptions};
+/// use rome_path::RomePath;
+/// use rslint_parser::{TextRange, TextSize};
+///
+/// let app = create_app();
+/// let rome_path = RomePath::new("virtual/file.js").deduce_handler(&app);
+/// let source = "let a  =  1;\nif  a  {  b  }";
+/// let result = format_source_lenient(&rome_path, source, FormatOptions::default()).unwrap();
+///
+/// assert_eq!(result.code(), "let a = 1;\nif  a  {  b  }\n");
+/// let verbatim_range = TextRange::new(TextSize::from(13), TextSize::from(27));
+/// assert_eq!(result.verbatim_ranges(), [verbatim_range]);
+/// ```
+pub fn format_source_lenient(
+	rome_path: &RomePath,
+	source: &str,
+	options: FormatOptions,
+) -> FormatResult<Formatted> {
+	format_with_handler(rome_path, source, options, true)
+}
+
+/// Formats the part of the source code that contains `range`, and returns the edit that replaces
+/// it with the formatted code, see [Formatter::format_range]. The rest of the source is left as is.
+///
+/// Returns [FormatError::SyntaxErrors] if the source code contains syntax errors, like
+/// [format_source]. For the JSON files, the smallest value or object member that contains the range
+/// is formatted.
+///
+/// ```
+/// use rome_core::create_app;
+/// use rome_formatter::{format_range, FormatOptions};
+/// use rome_path::RomePath;
+/// use rslint_parser::{TextRange, TextSize};
+///
+/// let app = create_app();
+/// let rome_path = RomePath::new("virtual/file.js").deduce_handler(&app);
+/// let mut source = String::from("let a  =  1;\nlet b  =  2;\n");
+///
+/// let range = TextRange::new(TextSize::from(13), TextSize::from(16));
+/// let edit = format_range(&rome_path, &source, range, FormatOptions::default()).unwrap();
+/// edit.apply(&mut source);
+///
+/// assert_eq!(source, "let a  =  1;\nlet b = 2;\n");
+/// ```
+pub fn format_range(
+	rome_path: &RomePath,
+	source: &str,
+	range: TextRange,
+	options: FormatOptions,
+) -> FormatResult<TextEdit> {
+	let handler = rome_path
+		.get_handler()
+		.ok_or(FormatError::UnsupportedLanguage)?;
+	if !handler.capabilities().format {
+		return Err(FormatError::CapabilityDisabled);
+	}
 
+	match handler.language() {
+		Language::Js | Language::Ts => {
+			let syntax = handler
+				.syntax(rome_path.fs(), rome_path, source)
+				.unwrap_or_default();
+			let parsed_result = parse(source, rome_path.file_id(), syntax);
+			let errors = parsed_result.errors();
+
+			if !errors.is_empty() {
+				return Err(FormatError::SyntaxErrors(errors.to_vec()));
+			}
+
+			Formatter::new(options).format_range(&parsed_result.syntax(), range)
+		}
+		Language::Json => Ok(format_json_range(source, range, options)),
+		Language::Unknown => Err(FormatError::UnsupportedLanguage),
+	}
+}
+
+fn format_with_handler(
+	rome_path: &RomePath,
+	source: &str,
+	options: FormatOptions,
+	allow_syntax_errors: bool,
+) -> FormatResult<Formatted> {
 	if let Some(handler) = rome_path.get_handler() {
 		if handler.capabilities().format {
-			let result = match handler.language() {
-				Language::Js => {
-					let parsed_result = parse_text(buffer.as_str(), 0);
-					Formatter::new(options).format_root(&parsed_result.syntax())
+			match handler.language() {
+				Language::Js | Language::Ts => {
+					let syntax = handler
+						.syntax(rome_path.fs(), rome_path, source)
+						.unwrap_or_default();
+					let parsed_result = parse(source, rome_path.file_id(), syntax);
+					let errors = parsed_result.errors().to_vec();
+
+					if !errors.is_empty() && !allow_syntax_errors {
+						return Err(FormatError::SyntaxErrors(errors));
+					}
+
+					match Formatter::new(options).format_root(&parsed_result.syntax()) {
+						Ok(formatted) => {
+							let file_id = rome_path.file_id();
+							let mut diagnostics = errors;
+							diagnostics.extend(
+								formatted
+									.verbatim_ranges()
+									.iter()
+									.map(|range| verbatim_diagnostic(file_id, *range)),
+							);
+							Ok(formatted.with_diagnostics(diagnostics))
+						}
+						// The syntax errors are most likely why the tree couldn't be formatted
+						Err(_) if !errors.is_empty() => Err(FormatError::SyntaxErrors(errors)),
+						Err(err) => Err(err),
+					}
 				}
 				Language::Json => {
-					let element = tokenize_json(buffer.as_str());
+					let element = tokenize_json(source);
 					Ok(format_element(&element, options))
 				}
-				Language::Ts | Language::Unknown => Err(FormatError::UnsupportedLanguage),
-			};
-
-			result
+				Language::Unknown => Err(FormatError::UnsupportedLanguage),
+			}
 		} else {
 			Err(FormatError::CapabilityDisabled)
 		}
@@ -209,15 +479,88 @@ pub fn format(rome_path: &mut RomePath, options: FormatOptions) -> FormatResult<
 	}
 }
 
-pub fn format_file_and_save(rome_path: &mut RomePath, options: FormatOptions) {
-	let result = format(rome_path, options);
-	if let Ok(result) = result {
-		rome_path
-			.save(result.code())
-			.expect("Could not write the formatted code on file");
+/// The warning for a range of the source that was printed as is, see [Formatted::verbatim_ranges]
+fn verbatim_diagnostic(file_id: FileId, range: TextRange) -> Diagnostic {
+	Diagnostic::warning(file_id, "Format", "this code couldn't be formatted")
+		.primary(range, "this code contains syntax errors, it was left as is")
+}
+
+/// The result of formatting a file without writing the formatted code back to disk, see [check_file]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatCheck {
+	original: String,
+	formatted: Formatted,
+}
+
+impl FormatCheck {
+	/// The content of the file before formatting
+	pub fn original(&self) -> &str {
+		&self.original
+	}
+
+	/// The formatted code of the file
+	pub fn formatted(&self) -> &Formatted {
+		&self.formatted
+	}
+
+	/// Returns `true` if formatting the file doesn't change its content
+	pub fn is_formatted(&self) -> bool {
+		self.formatted.code() == &self.original
 	}
 }
 
+/// Formats a file without writing the formatted code back to disk.
+pub fn check_file(rome_path: &mut RomePath, options: FormatOptions) -> FormatResult<FormatCheck> {
+	let original = rome_path.read_to_string()?;
+	let formatted = format_source(rome_path, original.as_str(), options)?;
+
+	Ok(FormatCheck {
+		original,
+		formatted,
+	})
+}
+
+/// Formats a file and writes the formatted code back to disk.
+///
+/// The file is only written if the formatted code is different from its original content, and if
+/// the file doesn't contain syntax errors. Returns `true` if the file has been changed.
+///
+/// The file is read and written through the file system of the [App] passed to
+/// [RomePath::deduce_handler], so it can be a virtual file:
+///
+/// ```
+/// use rome_core::create_app;
+/// use rome_core::fs::{FileSystem, MemoryFileSystem};
+/// use rome_formatter::{format_file_and_save, FormatOptions};
+/// use rome_path::RomePath;
+/// use std::path::Path;
+///
+/// let fs = MemoryFileSystem::default();
+/// fs.insert("virtual/file.js", "let a  =  1");
+/// let app = create_app().with_file_system(fs);
+/// let mut rome_path = RomePath::new("virtual/file.js").deduce_handler(&app);
+///
+/// assert!(format_file_and_save(&mut rome_path, FormatOptions::default()).unwrap());
+/// let content = app.fs().read_to_string(Path::new("virtual/file.js")).unwrap();
+/// assert_eq!(content, "let a = 1;\n");
+/// ```
+pub fn format_file_and_save(
+	rome_path: &mut RomePath,
+	options: FormatOptions,
+) -> FormatResult<bool> {
+	let check = check_file(rome_path, options)?;
+
+	if check.is_formatted() {
+		return Ok(false);
+	}
+
+	rome_path.save(check.formatted().code())?;
+	Ok(true)
+}
+
+/// Formats the file at `path_to_file`, read through the file system of `app`.
+///
+/// Panics if the file can't be read or formatted, use [format] to handle the errors.
 pub fn format_file(path_to_file: &str, options: FormatOptions, app: &App) -> Formatted {
 	let mut rome_path = RomePath::new(path_to_file).deduce_handler(app);
 	let element = format(&mut rome_path, options);
@@ -1,5 +1,6 @@
-use crate::format_element::{ConditionalGroupContent, Group, GroupPrintMode, LineMode};
-use crate::{FormatElement, FormatOptions, Formatted, IndentStyle};
+use crate::format_element::{ConditionalGroupContent, Group, GroupPrintMode, LineMode, LineSuffix};
+use crate::{FormatElement, FormatOptions, Formatted, IndentStyle, SourceMarker};
+use rslint_parser::TextSize;
 
 /// Options that affect how the [Printer] prints the format tokens
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -104,7 +105,9 @@ impl Printer {
 			queue.extend(self.print_element(print_element_call.element, print_element_call.args));
 		}
 
-		Formatted::new(self.state.buffer.as_str())
+		self.print_line_suffixes();
+
+		Formatted::new(self.state.buffer.as_str()).with_source_markers(self.state.source_markers)
 	}
 
 	/// Prints a single element and returns the elements to queue (that should be printed next).
@@ -115,7 +118,7 @@ impl Printer {
 	) -> Vec<PrintElementCall<'a>> {
 		match element {
 			FormatElement::Space => {
-				self.state.pending_spaces += 1;
+				self.state.pending_space = true;
 				vec![]
 			}
 			FormatElement::Empty => vec![],
@@ -131,10 +134,18 @@ impl Printer {
 					self.state.pending_indent = 0;
 				}
 
-				// Print pending spaces
-				if self.state.pending_spaces > 0 {
-					self.print_str(" ".repeat(self.state.pending_spaces as usize).as_str());
-					self.state.pending_spaces = 0;
+				// Print pending space
+				if self.state.pending_space {
+					self.print_str(" ");
+					self.state.pending_space = false;
+				}
+
+				if let Some(source) = self.state.pending_source_position.take() {
+					self.state.source_markers.push(SourceMarker {
+						source,
+						dest_line: self.state.generated_line,
+						dest_column: self.state.generated_column,
+					});
 				}
 
 				self.print_str(token);
@@ -178,11 +189,44 @@ impl Printer {
 			}
 
 			FormatElement::Line { .. } => {
-				self.print_str("\n");
-				self.state.pending_spaces = 0;
+				// A line break at the start of a line would print an empty line. Skipping it
+				// allows the elements, like the comments, to require a line break before them
+				// without knowing what was printed before.
+				if self.state.line_width > 0 || !self.state.line_suffixes.is_empty() {
+					self.print_line_suffixes();
+					self.print_str("\n");
+				}
+				self.state.pending_space = false;
 				self.state.pending_indent = args.indent;
 				vec![]
 			}
+
+			FormatElement::LineSuffix(LineSuffix { content }) => {
+				self.state.line_suffixes.push(((**content).clone(), args));
+				vec![]
+			}
+
+			FormatElement::SourcePosition(position) => {
+				self.state.pending_source_position = Some(*position);
+				vec![]
+			}
+		}
+	}
+
+	/// Prints the line suffixes of the current line, in the order they were added
+	fn print_line_suffixes(&mut self) {
+		let suffixes = std::mem::take(&mut self.state.line_suffixes);
+		let mut queue = ElementCallQueue::new();
+
+		queue.extend(
+			suffixes
+				.iter()
+				.map(|(element, args)| PrintElementCall::new(element, args.clone()))
+				.collect(),
+		);
+
+		while let Some(call) = queue.dequeue() {
+			queue.extend(self.print_element(call.element, call.args));
 		}
 	}
 
@@ -239,7 +283,7 @@ impl Printer {
 			FormatElement::Line(line) => {
 				match line.mode {
 					LineMode::SoftOrSpace => {
-						self.state.pending_spaces += 1;
+						self.state.pending_space = true;
 						vec![]
 					}
 					// We want a flat structure, so omit soft line wraps
@@ -252,6 +296,9 @@ impl Printer {
 				vec![PrintElementCall::new(group.content.as_ref(), args)]
 			}
 
+			// The content after the suffix would be printed before it
+			FormatElement::LineSuffix(_) => return Err(LineBreakRequiredError),
+
 			FormatElement::ConditionalGroupContent(ConditionalGroupContent {
 				mode: GroupPrintMode::Flat,
 				content,
@@ -266,7 +313,8 @@ impl Printer {
 			FormatElement::Empty
 			| FormatElement::Space
 			| FormatElement::Indent { .. }
-			| FormatElement::List { .. } => self.print_element(element, args),
+			| FormatElement::List { .. }
+			| FormatElement::SourcePosition(_) => self.print_element(element, args),
 		};
 
 		Ok(next_calls)
@@ -288,7 +336,7 @@ impl Printer {
 			} else {
 				self.state.buffer.push(char);
 				self.state.generated_index += 1;
-				self.state.generated_column += 1;
+				self.state.generated_column += char.len_utf16();
 
 				let char_width = if char == '\t' {
 					self.options.tab_width as usize
@@ -309,53 +357,62 @@ impl Printer {
 struct PrinterState {
 	buffer: String,
 	pending_indent: u16,
-	pending_spaces: u16,
+	pending_space: bool,
 	generated_index: usize,
 	generated_line: usize,
+	/// The column of the current line, in UTF-16 code units like the columns of the source maps
 	generated_column: usize,
 	line_width: usize,
-	// mappings: Mapping[];
-	// We'll need to clone the line suffixes elements into the state.
-	// I guess that's fine. They're only used for comments and should, therefore, be very limited
-	// in size.
-	// lineSuffixes: [FormatElement, PrintElementArgs][];
+	/// The position in the source of the next token, see [FormatElement::SourcePosition]
+	pending_source_position: Option<TextSize>,
+	/// The positions of the printed tokens that have a source position
+	source_markers: Vec<SourceMarker>,
+	/// The line suffixes to print before the next line break. They're cloned into the state,
+	/// which is fine since they're only used for comments and are very limited in size.
+	line_suffixes: Vec<(FormatElement, PrintElementArgs)>,
 }
 
 impl PrinterState {
 	/// Allows creating a snapshot of the state that can be restored using [restore]
 	pub fn snapshot(&self) -> PrinterStateSnapshot {
 		PrinterStateSnapshot {
-			pending_spaces: self.pending_spaces,
+			pending_space: self.pending_space,
 			pending_indents: self.pending_indent,
 			generated_index: self.generated_index,
 			generated_line: self.generated_line,
 			generated_column: self.generated_column,
 			line_width: self.line_width,
 			buffer_position: self.buffer.len(),
+			pending_source_position: self.pending_source_position,
+			source_markers_count: self.source_markers.len(),
 		}
 	}
 
 	/// Restores the printer state to the state stored in the snapshot.
 	pub fn restore(&mut self, snapshot: PrinterStateSnapshot) {
-		self.pending_spaces = snapshot.pending_spaces;
+		self.pending_space = snapshot.pending_space;
 		self.pending_indent = snapshot.pending_indents;
 		self.generated_index = snapshot.generated_index;
 		self.generated_column = snapshot.generated_column;
 		self.generated_line = snapshot.generated_line;
 		self.line_width = snapshot.line_width;
 		self.buffer.truncate(snapshot.buffer_position);
+		self.pending_source_position = snapshot.pending_source_position;
+		self.source_markers.truncate(snapshot.source_markers_count);
 	}
 }
 
 /// Snapshot of a printer state.
 struct PrinterStateSnapshot {
 	pending_indents: u16,
-	pending_spaces: u16,
+	pending_space: bool,
 	generated_index: usize,
 	generated_column: usize,
 	generated_line: usize,
 	line_width: usize,
 	buffer_position: usize,
+	pending_source_position: Option<TextSize>,
+	source_markers_count: usize,
 }
 
 /// Stores arguments passed to `print_element` call, holding the state specific to printing an element.
@@ -440,7 +497,8 @@ mod tests {
 	use crate::printer::{LineEnding, Printer, PrinterOptions};
 	use crate::{
 		block_indent, format_elements, group_elements, hard_line_break, if_group_breaks,
-		soft_indent, soft_line_break, soft_line_break_or_space, token, FormatElement, Formatted,
+		line_suffix, soft_indent, soft_line_break, soft_line_break_or_space, space_token, token,
+		FormatElement, Formatted,
 	};
 
 	/// Prints the given element with the default printer options
@@ -580,6 +638,47 @@ two lines`,
 		assert_eq!("[\n\t'a',\n\t\'b',\n\t\'c',\n\t'd',\n]", result.code());
 	}
 
+	#[test]
+	fn it_prints_the_line_suffixes_before_the_next_line_break() {
+		let result = print_element(format_elements![
+			token("a"),
+			line_suffix(format_elements![space_token(), token("// comment")]),
+			token(";"),
+			hard_line_break(),
+			token("b"),
+			line_suffix(format_elements![space_token(), token("// last")]),
+		]);
+
+		assert_eq!("a; // comment\nb // last", result.code());
+	}
+
+	#[test]
+	fn it_breaks_a_group_that_contains_a_line_suffix() {
+		let result = print_element(create_array_element(vec![
+			format_elements![
+				token("1"),
+				line_suffix(format_elements![space_token(), token("// one")])
+			],
+			token("2"),
+		]));
+
+		assert_eq!("[\n  1, // one\n  2,\n]", result.code());
+	}
+
+	#[test]
+	fn it_doesnt_print_empty_lines_for_consecutive_line_breaks() {
+		let result = print_element(format_elements![
+			token("a"),
+			hard_line_break(),
+			hard_line_break(),
+			block_indent(token("b")),
+			hard_line_break(),
+			token("c"),
+		]);
+
+		assert_eq!("a\n  b\nc", result.code());
+	}
+
 	fn create_array_element(items: Vec<FormatElement>) -> FormatElement {
 		let separator = format_elements![token(","), soft_line_break_or_space(),];
 
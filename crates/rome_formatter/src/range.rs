@@ -0,0 +1,277 @@
+//! Helpers to format a range of a file, see [Formatter::format_range].
+//!
+//! A range is formatted by formatting the smallest statements, module items or class members that
+//! contain it. These nodes always start a line in the formatted code, so they can be formatted on
+//! their own, at the indentation of their line in the source.
+//!
+//! [Formatter::format_range]: crate::Formatter::format_range
+use crate::{FormatOptions, IndentStyle, PrinterOptions};
+use rome_rowan::SyntaxElement;
+use rslint_parser::ast::{JsAnyClassMember, JsAnyModuleItem};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, TextRange, TextSize};
+use rslint_text_edit::TextEdit;
+
+/// Returns the nodes to format to format `range`, in the order of the source.
+///
+/// Returns an empty list if there's no node smaller than `root` that contains the range.
+pub(crate) fn nodes_in_range(root: &SyntaxNode, range: TextRange) -> Vec<SyntaxNode> {
+	let start = match root.covering_element(range) {
+		SyntaxElement::Node(node) => node,
+		SyntaxElement::Token(token) => match token.parent() {
+			Some(parent) => parent,
+			None => return vec![],
+		},
+	};
+
+	for node in start.ancestors() {
+		if node == *root {
+			break;
+		}
+
+		if is_list_of_formattable_nodes(node.kind()) {
+			// The range covers more than one item of the list
+			let children: Vec<_> = node
+				.children()
+				.filter(|child| child.text_trimmed_range().intersect(range).is_some())
+				.collect();
+
+			if !children.is_empty() {
+				return children;
+			}
+		} else if is_formattable(&node) {
+			return vec![node];
+		}
+	}
+
+	vec![]
+}
+
+/// Returns `true` for the lists whose items are formatted one per line
+fn is_list_of_formattable_nodes(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::JS_MODULE_ITEM_LIST
+			| SyntaxKind::JS_STATEMENT_LIST
+			| SyntaxKind::JS_CLASS_MEMBER_LIST
+	)
+}
+
+/// Returns `true` if the node can be formatted on its own, see [nodes_in_range].
+/// The module items include the statements.
+fn is_formattable(node: &SyntaxNode) -> bool {
+	JsAnyModuleItem::can_cast(node.kind()) || JsAnyClassMember::can_cast(node.kind())
+}
+
+/// Returns the range of the source replaced by the formatted nodes: the nodes, with the leading
+/// comments of the first node and the trailing comments of the last node, which are printed with
+/// the nodes.
+pub(crate) fn replaced_range(first: &SyntaxNode, last: &SyntaxNode) -> TextRange {
+	let start = first
+		.first_token()
+		.and_then(|token| {
+			token
+				.leading_trivia()
+				.pieces()
+				.find(|piece| piece.as_comments().is_some())
+				.map(|comment| comment.text_range().start())
+		})
+		.unwrap_or_else(|| first.text_trimmed_range().start());
+
+	let end = last
+		.last_token()
+		.and_then(|token| {
+			token
+				.trailing_trivia()
+				.pieces()
+				.filter(|piece| piece.as_comments().is_some())
+				.last()
+				.map(|comment| comment.text_range().end())
+		})
+		.unwrap_or_else(|| last.text_trimmed_range().end());
+
+	TextRange::new(start, end)
+}
+
+/// Returns the text of the line of `offset` that comes before `offset`
+pub(crate) fn line_prefix(source: &str, offset: TextSize) -> &str {
+	let offset = usize::from(offset);
+	let line_start = source[..offset]
+		.rfind(|char| matches!(char, '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+		.map_or(0, |index| {
+			index + source[index..].chars().next().map_or(1, char::len_utf8)
+		});
+
+	&source[line_start..offset]
+}
+
+/// Returns the number of indentation levels at the start of `line`. A tab is a level, and the
+/// spaces make a level for each indentation width of `options`.
+pub(crate) fn indent_level(line: &str, options: &FormatOptions) -> usize {
+	let spaces_per_level = match options.indent_style {
+		IndentStyle::Tab => PrinterOptions::from(options.clone()).tab_width,
+		IndentStyle::Space(width) => width,
+	}
+	.max(1) as usize;
+
+	let indentation_len = line.len()
+		- line
+			.trim_start_matches(|char| matches!(char, ' ' | '\t'))
+			.len();
+	let indentation = &line[..indentation_len];
+	let tabs = indentation.matches('\t').count();
+	let spaces = indentation.matches(' ').count();
+
+	tabs + spaces / spaces_per_level
+}
+
+/// Returns the edit that replaces `range` of the source with the formatted code, or an empty edit
+/// if the range is already formatted
+pub(crate) fn replace_range(source: &str, range: TextRange, formatted: &str) -> TextEdit {
+	if &source[range] == formatted {
+		TextEdit::default()
+	} else {
+		TextEdit::replace(range, String::from(formatted))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{format_range, FormatOptions, IndentStyle};
+	use rome_core::create_app;
+	use rome_path::RomePath;
+	use rslint_parser::{TextRange, TextSize};
+
+	/// Formats the range of `source` between the first `selection` and its end
+	fn format_selection(source: &str, selection: &str, options: FormatOptions) -> String {
+		format_file_selection("file.js", source, selection, options)
+	}
+
+	/// Formats the range of `source` between the first `selection` and its end, in a file at `path`
+	fn format_file_selection(
+		path: &str,
+		source: &str,
+		selection: &str,
+		options: FormatOptions,
+	) -> String {
+		let app = create_app();
+		let rome_path = RomePath::new(path).deduce_handler(&app);
+		let start = TextSize::from(source.find(selection).unwrap() as u32);
+		let range = TextRange::at(start, TextSize::of(selection));
+
+		let mut formatted = String::from(source);
+		format_range(&rome_path, source, range, options)
+			.unwrap()
+			.apply(&mut formatted);
+		formatted
+	}
+
+	#[test]
+	fn formats_the_statement_at_the_indentation_of_its_line() {
+		let source = "function f() {\n\tif(a){b}\n\tlet  c = 1\n}\n";
+
+		assert_eq!(
+			format_selection(source, "if", FormatOptions::default()),
+			"function f() {\n\tif (a) {\n\t\tb;\n\t}\n\tlet  c = 1\n}\n"
+		);
+	}
+
+	#[test]
+	fn formats_the_statements_that_intersect_the_range() {
+		let source = "let a  =  1; let b  =  2;\nlet c  =  3;\n";
+
+		assert_eq!(
+			format_selection(source, "1; let", FormatOptions::default()),
+			"let a = 1;\nlet b = 2;\nlet c  =  3;\n"
+		);
+	}
+
+	#[test]
+	fn formats_the_comments_of_the_statement() {
+		let source = "let z  =  0;\n\n// a\nlet a  =  1; // b\nlet c  =  3;\n";
+
+		assert_eq!(
+			format_selection(source, "let a", FormatOptions::default()),
+			"let z  =  0;\n\n// a\nlet a = 1; // b\nlet c  =  3;\n"
+		);
+	}
+
+	#[test]
+	fn formats_the_class_member() {
+		let source = "class A {\n  x  =  1\n  y  =  2\n}\n";
+
+		assert_eq!(
+			format_selection(source, "x", FormatOptions::new(IndentStyle::Space(2))),
+			"class A {\n  x = 1;\n  y  =  2\n}\n"
+		);
+	}
+
+	#[test]
+	fn formats_the_json_value() {
+		let source = "{\n\t\"a\":   [1,2],\n\t\"b\":  {\"c\":   true,  \"d\": 1}\n}\n";
+		let format = |selection| {
+			format_file_selection("file.json", source, selection, FormatOptions::default())
+		};
+
+		assert_eq!(
+			format("{\"c\""),
+			"{\n\t\"a\":   [1,2],\n\t\"b\":  {\"c\": true, \"d\": 1}\n}\n"
+		);
+		assert_eq!(
+			format("\"c\""),
+			"{\n\t\"a\":   [1,2],\n\t\"b\":  {\"c\": true,  \"d\": 1}\n}\n"
+		);
+		assert_eq!(
+			format("1,2"),
+			"{\n\t\"a\":   [1, 2],\n\t\"b\":  {\"c\":   true,  \"d\": 1}\n}\n"
+		);
+		assert_eq!(
+			format("\"a\""),
+			"{\n\t\"a\": [1, 2],\n\t\"b\":  {\"c\":   true,  \"d\": 1}\n}\n"
+		);
+		assert_eq!(
+			format(source),
+			"{\"a\": [1, 2], \"b\": {\"c\": true, \"d\": 1}}\n"
+		);
+	}
+
+	#[test]
+	fn formats_the_json_value_at_the_indentation_of_its_line() {
+		let source = "{\n\t\"list\": [\"first\", \"second\"]\n}\n";
+
+		assert_eq!(
+			format_file_selection(
+				"file.json",
+				source,
+				"\"first\"",
+				FormatOptions {
+					line_width: 20,
+					..FormatOptions::default()
+				}
+			),
+			"{\n\t\"list\": [\"first\", \"second\"]\n}\n"
+		);
+		assert_eq!(
+			format_file_selection(
+				"file.json",
+				source,
+				"[",
+				FormatOptions {
+					line_width: 20,
+					..FormatOptions::default()
+				}
+			),
+			"{\n\t\"list\": [\n\t\t\"first\",\n\t\t\"second\"\n\t]\n}\n"
+		);
+	}
+
+	#[test]
+	fn returns_no_edit_when_the_range_is_formatted() {
+		let app = create_app();
+		let rome_path = RomePath::new("file.js").deduce_handler(&app);
+		let source = "let a = 1;\nlet b  =  2;\n";
+		let range = TextRange::new(TextSize::from(0), TextSize::from(3));
+
+		let edit = format_range(&rome_path, source, range, FormatOptions::default()).unwrap();
+		assert!(edit.is_empty());
+	}
+}
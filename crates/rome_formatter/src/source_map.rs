@@ -0,0 +1,270 @@
+//! Mapping of the formatted code back to the source.
+//!
+//! The formatter marks the position in the source of every token it prints with a
+//! [FormatElement::SourcePosition], and the [Printer] records where each marked token ends up in
+//! the formatted code as a [SourceMarker]. The markers are returned with the formatted code, see
+//! [Formatted::source_markers], and can be serialized as a
+//! [source map v3](https://sourcemaps.info/spec.html) with [Formatted::source_map].
+//!
+//! The lines and the columns start at 0. The columns are counted in UTF-16 code units, like the
+//! JavaScript tools that read the source maps do.
+//!
+//! [FormatElement::SourcePosition]: crate::FormatElement::SourcePosition
+//! [Printer]: crate::Printer
+//! [Formatted::source_markers]: crate::Formatted::source_markers
+//! [Formatted::source_map]: crate::Formatted::source_map
+use rslint_parser::TextSize;
+use serde::Serialize;
+
+/// The position of a token in the formatted code, and the position of the token in the source
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourceMarker {
+	/// The offset of the token in the source
+	pub source: TextSize,
+	/// The line of the token in the formatted code
+	pub dest_line: usize,
+	/// The column of the token in the formatted code
+	pub dest_column: usize,
+}
+
+/// A source map v3 that maps the formatted code to a single source file
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMap {
+	version: u8,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	file: Option<String>,
+	sources: Vec<String>,
+	sources_content: Vec<String>,
+	names: Vec<String>,
+	mappings: String,
+}
+
+impl SourceMap {
+	/// Creates the source map of code formatted from `source`, whose markers are `markers`.
+	/// `source_name` is the name, or the URL, of the source file in the source map.
+	pub fn new(source_name: &str, source: &str, markers: &[SourceMarker]) -> Self {
+		Self {
+			version: 3,
+			file: None,
+			sources: vec![String::from(source_name)],
+			sources_content: vec![String::from(source)],
+			names: vec![],
+			mappings: encode_mappings(source, markers),
+		}
+	}
+
+	/// Sets the name of the formatted file
+	pub fn with_file(mut self, file: &str) -> Self {
+		self.file = Some(String::from(file));
+		self
+	}
+
+	/// The mappings of the source map, encoded as Base64 VLQ segments
+	pub fn mappings(&self) -> &str {
+		&self.mappings
+	}
+
+	/// Serializes the source map to JSON
+	pub fn to_json(&self) -> String {
+		serde_json::to_string(self).expect("a source map can always be serialized")
+	}
+}
+
+/// Encodes the markers as the `mappings` field of a source map. Each marker becomes a segment
+/// `[dest column, source index, source line, source column]`, where each field is relative to
+/// the previous segment, except the dest column that restarts at every line.
+fn encode_mappings(source: &str, markers: &[SourceMarker]) -> String {
+	let mut markers = markers.to_vec();
+	markers.sort_by_key(|marker| (marker.dest_line, marker.dest_column));
+	markers.dedup_by_key(|marker| (marker.dest_line, marker.dest_column));
+
+	let line_index = LineIndex::new(source);
+	let mut mappings = String::new();
+	let mut line = 0;
+	let mut is_first_segment_of_line = true;
+	let mut previous_dest_column = 0;
+	let mut previous_source_line = 0;
+	let mut previous_source_column = 0;
+
+	for marker in markers {
+		while line < marker.dest_line {
+			mappings.push(';');
+			line += 1;
+			is_first_segment_of_line = true;
+			previous_dest_column = 0;
+		}
+
+		if !is_first_segment_of_line {
+			mappings.push(',');
+		}
+		is_first_segment_of_line = false;
+
+		let (source_line, source_column) = line_index.line_column(marker.source);
+
+		encode_vlq(
+			&mut mappings,
+			marker.dest_column as i64 - previous_dest_column,
+		);
+		// There's only one source
+		encode_vlq(&mut mappings, 0);
+		encode_vlq(&mut mappings, source_line as i64 - previous_source_line);
+		encode_vlq(&mut mappings, source_column as i64 - previous_source_column);
+
+		previous_dest_column = marker.dest_column as i64;
+		previous_source_line = source_line as i64;
+		previous_source_column = source_column as i64;
+	}
+
+	mappings
+}
+
+const BASE64_DIGITS: &[u8; 64] =
+	b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Appends a number encoded as a Base64 VLQ: the sign is the lowest bit, and the number is
+/// written in groups of 5 bits, starting with the lowest ones, with the 6th bit set on every group
+/// but the last one.
+fn encode_vlq(output: &mut String, value: i64) {
+	let mut value = if value < 0 {
+		((-value) << 1) | 1
+	} else {
+		value << 1
+	};
+
+	loop {
+		let mut digit = value & 0b11111;
+		value >>= 5;
+		if value > 0 {
+			digit |= 0b100000;
+		}
+		output.push(BASE64_DIGITS[digit as usize] as char);
+
+		if value == 0 {
+			break;
+		}
+	}
+}
+
+/// Converts the offsets of a source to lines and columns
+struct LineIndex<'a> {
+	source: &'a str,
+	/// The offset of the start of each line
+	line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+	fn new(source: &'a str) -> Self {
+		let mut line_starts = vec![0];
+		let mut chars = source.char_indices().peekable();
+
+		while let Some((offset, char)) = chars.next() {
+			match char {
+				'\r' if matches!(chars.peek(), Some((_, '\n'))) => {}
+				'\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(offset + char.len_utf8()),
+				_ => {}
+			}
+		}
+
+		Self {
+			source,
+			line_starts,
+		}
+	}
+
+	/// Returns the line and the column of an offset
+	fn line_column(&self, offset: TextSize) -> (usize, usize) {
+		let offset = usize::from(offset).min(self.source.len());
+		let line = match self.line_starts.binary_search(&offset) {
+			Ok(line) => line,
+			Err(next_line) => next_line - 1,
+		};
+
+		let line_start = self.line_starts[line];
+		let column = self.source[line_start..offset]
+			.chars()
+			.map(char::len_utf16)
+			.sum();
+
+		(line, column)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{encode_vlq, SourceMap, SourceMarker};
+	use crate::{format_source, FormatOptions};
+	use rome_core::create_app;
+	use rome_path::RomePath;
+	use rslint_parser::TextSize;
+
+	fn vlq(value: i64) -> String {
+		let mut output = String::new();
+		encode_vlq(&mut output, value);
+		output
+	}
+
+	#[test]
+	fn it_encodes_vlq_numbers() {
+		assert_eq!("A", vlq(0));
+		assert_eq!("C", vlq(1));
+		assert_eq!("D", vlq(-1));
+		assert_eq!("gB", vlq(16));
+		assert_eq!("2H", vlq(123));
+		assert_eq!("x+B", vlq(-1000));
+	}
+
+	#[test]
+	fn it_encodes_the_mappings_relative_to_the_previous_segment() {
+		let marker = |source: u32, dest_line, dest_column| SourceMarker {
+			source: TextSize::from(source),
+			dest_line,
+			dest_column,
+		};
+
+		// `let  a  =  1\n\nb` formatted as `let a = 1;\n\nb;`
+		let source = "let  a  =  1\n\nb";
+		let markers = [
+			marker(0, 0, 0),
+			marker(5, 0, 4),
+			marker(8, 0, 6),
+			marker(11, 0, 8),
+			marker(14, 2, 0),
+		];
+
+		let source_map = SourceMap::new("a.js", source, &markers);
+		assert_eq!("AAAA,IAAK,EAAG,EAAG;;AAEX", source_map.mappings());
+		assert_eq!(
+			r#"{"version":3,"sources":["a.js"],"sourcesContent":["let  a  =  1\n\nb"],"names":[],"mappings":"AAAA,IAAK,EAAG,EAAG;;AAEX"}"#,
+			source_map.to_json()
+		);
+	}
+
+	#[test]
+	fn it_counts_the_columns_in_utf16_code_units() {
+		let source = "\"😀\" + a";
+		let markers = [SourceMarker {
+			source: TextSize::from(9),
+			dest_line: 0,
+			dest_column: 0,
+		}];
+
+		// The emoji is two UTF-16 code units, the `a` is at the column 7
+		assert_eq!("AAAO", SourceMap::new("a.js", source, &markers).mappings());
+	}
+
+	#[test]
+	fn it_maps_the_formatted_tokens_to_the_source() {
+		let source = "let  a  =  1\n\n\nb";
+		let app = create_app();
+		let rome_path = RomePath::new("a.js").deduce_handler(&app);
+		let formatted = format_source(&rome_path, source, FormatOptions::default()).unwrap();
+
+		assert_eq!("let a = 1;\nb;\n", formatted.code());
+		// The semicolons and the end of file aren't in the source
+		assert_eq!(
+			"AAAA,IAAK,EAAG,EAAG;AAGX",
+			formatted.source_map("a.js", source).mappings()
+		);
+	}
+}
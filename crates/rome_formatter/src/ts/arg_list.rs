@@ -1,15 +1,54 @@
+use crate::comments::{has_leading_comments, has_trailing_comments};
 use crate::{
-	concat_elements, format_elements, group_elements, FormatElement, FormatResult, Formatter,
-	ToFormatElement,
+	concat_elements, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
-use rslint_parser::ast::JsCallArguments;
+use rome_rowan::SyntaxElement;
+use rslint_parser::ast::{AstNode, JsCallArguments};
 
 impl ToFormatElement for JsCallArguments {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_paren_token()?)?;
-		let args = concat_elements(formatter.format_separated(self.args())?);
+		let args = formatter.format_separated(self.args())?;
 		let r_bracket = formatter.format_token(&self.r_paren_token()?)?;
 
+		// The comments between the arguments may end with a line break, so the arguments are
+		// printed one per line
+		let args = if has_comments_between_arguments(self) {
+			soft_indent(join_elements(soft_line_break_or_space(), args))
+		} else {
+			concat_elements(args)
+		};
+
 		Ok(group_elements(format_elements![l_bracket, args, r_bracket]))
 	}
 }
+
+/// Returns `true` if there are comments inside the parentheses, around the separators or the
+/// arguments
+fn has_comments_between_arguments(arguments: &JsCallArguments) -> bool {
+	let parentheses = arguments
+		.l_paren_token()
+		.map_or(false, |token| has_trailing_comments(&token))
+		|| arguments
+			.r_paren_token()
+			.map_or(false, |token| has_leading_comments(&token));
+
+	parentheses
+		|| arguments
+			.args()
+			.syntax()
+			.children_with_tokens()
+			.any(|child| match child {
+				SyntaxElement::Token(token) => {
+					has_leading_comments(&token) || has_trailing_comments(&token)
+				}
+				SyntaxElement::Node(node) => {
+					node.first_token()
+						.map_or(false, |token| has_leading_comments(&token))
+						|| node
+							.last_token()
+							.map_or(false, |token| has_trailing_comments(&token))
+				}
+			})
+}
@@ -1,4 +1,4 @@
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::{FormatElement, FormatError, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnyAssignment;
 
 impl ToFormatElement for JsAnyAssignment {
@@ -16,7 +16,7 @@ impl ToFormatElement for JsAnyAssignment {
 			JsAnyAssignment::JsParenthesizedAssignment(parenthesized_assignment) => {
 				parenthesized_assignment.to_format_element(formatter)
 			}
-			JsAnyAssignment::JsUnknownAssignment(_) => todo!(),
+			JsAnyAssignment::JsUnknownAssignment(_) => Err(FormatError::UnknownNode),
 		}
 	}
 }
@@ -1,6 +1,6 @@
 use crate::{
 	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space,
-	FormatElement, FormatResult, Formatter, ToFormatElement,
+	FormatElement, FormatError, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{
 	JsAnyArrayAssignmentPatternElement, JsArrayAssignmentPattern,
@@ -30,9 +30,11 @@ impl ToFormatElement for JsAnyArrayAssignmentPatternElement {
 			JsAnyArrayAssignmentPatternElement::JsArrayAssignmentPatternRestElement(
 				array_assignment_pattern_rest_element,
 			) => array_assignment_pattern_rest_element.to_format_element(formatter),
-			JsAnyArrayAssignmentPatternElement::JsArrayHole(_array_hole) => todo!(),
-			JsAnyArrayAssignmentPatternElement::JsUnknownAssignment(_unknown_assignment) => {
-				todo!()
+			JsAnyArrayAssignmentPatternElement::JsArrayHole(hole) => {
+				hole.to_format_element(formatter)
+			}
+			JsAnyArrayAssignmentPatternElement::JsUnknownAssignment(_) => {
+				Err(FormatError::UnknownNode)
 			}
 		}
 	}
@@ -1,6 +1,7 @@
 use crate::{
 	empty_element, format_elements, group_elements, join_elements, soft_indent,
-	soft_line_break_or_space, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	soft_line_break_or_space, space_token, FormatElement, FormatError, FormatResult, Formatter,
+	ToFormatElement,
 };
 use rslint_parser::ast::{
 	JsAnyObjectAssignmentPatternMember, JsObjectAssignmentPattern,
@@ -33,7 +34,9 @@ impl ToFormatElement for JsAnyObjectAssignmentPatternMember {
 			JsAnyObjectAssignmentPatternMember::JsObjectAssignmentPatternRest(
 				object_assignment_pattern_rest,
 			) => object_assignment_pattern_rest.to_format_element(formatter),
-			JsAnyObjectAssignmentPatternMember::JsUnknownAssignment(_) => todo!(),
+			JsAnyObjectAssignmentPatternMember::JsUnknownAssignment(_) => {
+				Err(FormatError::UnknownNode)
+			}
 		}
 	}
 }
@@ -1,6 +1,6 @@
 use crate::{
 	empty_element, format_elements, group_elements, join_elements, space_token, FormatElement,
-	FormatResult, Formatter, ToFormatElement,
+	FormatError, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{
 	JsAnyObjectBindingPatternMember, JsObjectBindingPattern, JsObjectBindingPatternProperty,
@@ -38,7 +38,7 @@ impl ToFormatElement for JsAnyObjectBindingPatternMember {
 			JsAnyObjectBindingPatternMember::JsIdentifierBinding(identifier_binding) => {
 				identifier_binding.to_format_element(formatter)
 			}
-			JsAnyObjectBindingPatternMember::JsUnknownBinding(_) => todo!(),
+			JsAnyObjectBindingPatternMember::JsUnknownBinding(_) => Err(FormatError::UnknownNode),
 		}
 	}
 }
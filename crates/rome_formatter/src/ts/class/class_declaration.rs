@@ -12,11 +12,25 @@ impl ToFormatElement for JsClassDeclaration {
 			empty_element()
 		};
 
+		let type_parameters = if let Some(type_parameters) = self.type_parameters() {
+			formatter.format_node(type_parameters)?
+		} else {
+			empty_element()
+		};
+
+		let implements = if let Some(implements_clause) = self.implements_clause() {
+			format_elements![space_token(), formatter.format_node(implements_clause)?]
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
 			formatter.format_token(&self.class_token()?)?,
 			space_token(),
 			formatter.format_node(self.id()?)?,
+			type_parameters,
 			extends,
+			implements,
 			space_token(),
 			group_elements(format_elements![
 				formatter.format_token(&self.l_curly_token()?)?,
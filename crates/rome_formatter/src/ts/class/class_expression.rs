@@ -18,9 +18,16 @@ impl ToFormatElement for JsClassExpression {
 			empty_element()
 		};
 
+		let type_parameters = if let Some(type_parameters) = self.type_parameters() {
+			formatter.format_node(type_parameters)?
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
 			formatter.format_token(&self.class_token()?)?,
 			id,
+			type_parameters,
 			extends,
 			space_token(),
 			group_elements(format_elements![
@@ -1,5 +1,6 @@
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::{format_elements, token, FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnyClassMember;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -16,8 +17,13 @@ impl ToFormatElement for JsAnyClassMember {
 			}
 			JsAnyClassMember::JsGetterClassMember(getter) => getter.to_format_element(formatter),
 			JsAnyClassMember::JsSetterClassMember(setter) => setter.to_format_element(formatter),
-			JsAnyClassMember::JsUnknownMember(_) => todo!(),
-			JsAnyClassMember::TsIndexSignature(_) => todo!(),
+			JsAnyClassMember::JsUnknownMember(unknown_member) => {
+				Ok(formatter.format_verbatim(unknown_member.syntax()))
+			}
+			JsAnyClassMember::TsIndexSignature(signature) => Ok(format_elements![
+				signature.to_format_element(formatter)?,
+				token(";")
+			]),
 		}
 	}
 }
@@ -1,14 +1,22 @@
 use crate::{
-	format_elements, group_elements, join_elements, soft_line_break_or_space, space_token,
-	FormatElement, FormatResult, Formatter, ToFormatElement,
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{
 	JsAnyConstructorParameter, JsConstructorClassMember, JsConstructorParameters,
+	TsAnyConstructorParamParameter, TsConstructorParam,
 };
 
 impl ToFormatElement for JsConstructorClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let access_modifier = if let Some(access_modifier) = self.access_modifier() {
+			format_elements![formatter.format_node(access_modifier)?, space_token()]
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
+			access_modifier,
 			formatter.format_node(self.name()?)?,
 			formatter.format_node(self.parameters()?)?,
 			space_token(),
@@ -23,24 +31,67 @@ impl ToFormatElement for JsConstructorParameters {
 		let params = formatter.format_separated(self.parameters())?;
 		let r_bracket = formatter.format_token(&self.r_paren_token()?)?;
 
-		Ok(format_elements![group_elements(format_elements![
+		Ok(group_elements(format_elements![
 			l_bracket,
-			join_elements(soft_line_break_or_space(), params),
+			soft_indent(join_elements(soft_line_break_or_space(), params)),
 			r_bracket
-		])])
+		]))
 	}
 }
 
 impl ToFormatElement for JsAnyConstructorParameter {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		match self {
-			JsAnyConstructorParameter::TsConstructorParam(_) => todo!(),
+			JsAnyConstructorParameter::TsConstructorParam(param) => {
+				param.to_format_element(formatter)
+			}
 			JsAnyConstructorParameter::JsBindingPatternWithDefault(binding) => {
 				binding.to_format_element(formatter)
 			}
 			JsAnyConstructorParameter::JsAnyBindingPattern(binding) => {
 				binding.to_format_element(formatter)
 			}
+			JsAnyConstructorParameter::TsTypedParameter(parameter) => {
+				parameter.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for TsConstructorParam {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let access_modifier = if let Some(access_modifier) = self.access_modifier() {
+			format_elements![formatter.format_node(access_modifier)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let readonly = if let Some(readonly) = self.readonly_token() {
+			format_elements![formatter.format_token(&readonly)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			access_modifier,
+			readonly,
+			formatter.format_node(self.parameter()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsAnyConstructorParamParameter {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsAnyConstructorParamParameter::JsAnyBindingPattern(binding) => {
+				binding.to_format_element(formatter)
+			}
+			TsAnyConstructorParamParameter::JsBindingPatternWithDefault(binding) => {
+				binding.to_format_element(formatter)
+			}
+			TsAnyConstructorParamParameter::TsTypedParameter(parameter) => {
+				parameter.to_format_element(formatter)
+			}
 		}
 	}
 }
@@ -0,0 +1,18 @@
+use crate::{
+	format_elements, join_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::TsImplementsClause;
+
+impl ToFormatElement for TsImplementsClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.implements_token()?)?,
+			space_token(),
+			join_elements(
+				space_token(),
+				formatter.format_separated(self.interfaces())?
+			)
+		])
+	}
+}
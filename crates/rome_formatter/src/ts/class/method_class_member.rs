@@ -6,18 +6,54 @@ use rslint_parser::ast::JsMethodClassMember;
 
 impl ToFormatElement for JsMethodClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let access_modifier = if let Some(access_modifier) = self.access_modifier() {
+			format_elements![formatter.format_node(access_modifier)?, space_token()]
+		} else {
+			empty_element()
+		};
 		let static_token = if let Some(token) = self.static_token() {
 			format_elements![formatter.format_token(&token)?, space_token()]
 		} else {
 			empty_element()
 		};
+		let abstract_token = if let Some(token) = self.abstract_token() {
+			format_elements![formatter.format_token(&token)?, space_token()]
+		} else {
+			empty_element()
+		};
+		let async_token = if let Some(token) = self.async_token() {
+			format_elements![formatter.format_token(&token)?, space_token()]
+		} else {
+			empty_element()
+		};
+		let star_token = if let Some(token) = self.star_token() {
+			formatter.format_token(&token)?
+		} else {
+			empty_element()
+		};
 		let name = formatter.format_node(self.name()?)?;
+		let type_parameters = if let Some(type_parameters) = self.type_parameters() {
+			formatter.format_node(type_parameters)?
+		} else {
+			empty_element()
+		};
 		let params = formatter.format_node(self.parameters()?)?;
+		let return_type = if let Some(return_type) = self.return_type() {
+			formatter.format_node(return_type)?
+		} else {
+			empty_element()
+		};
 		let body = formatter.format_node(self.body()?)?;
 		Ok(format_elements![
+			access_modifier,
 			static_token,
+			abstract_token,
+			async_token,
+			star_token,
 			name,
+			type_parameters,
 			params,
+			return_type,
 			space_token(),
 			body
 		])
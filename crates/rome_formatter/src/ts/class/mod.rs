@@ -6,6 +6,7 @@ mod constructor_class_member;
 mod empty_class_member;
 mod extends_clause;
 mod getter_class_member;
+mod implements_clause;
 mod method_class_member;
 mod private_class_member_name;
 mod property_class_member;
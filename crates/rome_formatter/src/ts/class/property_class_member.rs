@@ -1,28 +1,58 @@
 use crate::{
-	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
-	ToFormatElement,
+	concat_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::JsPropertyClassMember;
 
 impl ToFormatElement for JsPropertyClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let static_token = if let Some(token) = self.static_token() {
-			format_elements![formatter.format_token(&token)?, space_token()]
-		} else {
-			empty_element()
-		};
-
-		let init = if let Some(init) = self.value() {
-			format_elements![space_token(), formatter.format_node(init)?]
-		} else {
-			empty_element()
-		};
-
-		Ok(format_elements![
-			static_token,
-			formatter.format_node(self.name()?)?,
-			init,
-			token(";")
-		])
+		let mut tokens = vec![];
+
+		if let Some(token) = self.declare_token() {
+			tokens.push(formatter.format_token(&token)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(access_modifier) = self.access_modifier() {
+			tokens.push(formatter.format_node(access_modifier)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(token) = self.static_token() {
+			tokens.push(formatter.format_token(&token)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(token) = self.abstract_token() {
+			tokens.push(formatter.format_token(&token)?);
+			tokens.push(space_token());
+		}
+
+		if let Some(token) = self.readonly_token() {
+			tokens.push(formatter.format_token(&token)?);
+			tokens.push(space_token());
+		}
+
+		tokens.push(formatter.format_node(self.name()?)?);
+
+		if let Some(token) = self.question_mark_token() {
+			tokens.push(formatter.format_token(&token)?);
+		}
+
+		if let Some(token) = self.excl_token() {
+			tokens.push(formatter.format_token(&token)?);
+		}
+
+		if let Some(ty) = self.ty() {
+			tokens.push(formatter.format_node(ty)?);
+		}
+
+		if let Some(init) = self.value() {
+			tokens.push(space_token());
+			tokens.push(formatter.format_node(init)?);
+		}
+
+		tokens.push(formatter.format_or_create_token(self.semicolon_token(), ";"));
+
+		Ok(concat_elements(tokens))
 	}
 }
@@ -20,7 +20,13 @@ impl ToFormatElement for JsFunctionDeclaration {
 
 		tokens.push(space_token());
 		tokens.push(formatter.format_node(self.id()?)?);
+		if let Some(type_parameters) = self.type_parameters() {
+			tokens.push(formatter.format_node(type_parameters)?);
+		}
 		tokens.push(formatter.format_node(self.parameters()?)?);
+		if let Some(return_type) = self.return_type() {
+			tokens.push(formatter.format_node(return_type)?);
+		}
 		tokens.push(space_token());
 		tokens.push(formatter.format_node(self.body()?)?);
 
@@ -1,5 +1,5 @@
 use crate::{
-	empty_element, format_elements, join_elements, space_token, token, FormatElement, FormatResult,
+	empty_element, format_elements, join_elements, space_token, FormatElement, FormatResult,
 	Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{JsVariableDeclaration, JsVariableDeclarations, JsVariableStatement};
@@ -8,7 +8,7 @@ impl ToFormatElement for JsVariableStatement {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		Ok(format_elements![
 			formatter.format_node(self.declarations()?)?,
-			token(";"),
+			formatter.format_or_create_token(self.semicolon_token(), ";"),
 		])
 	}
 }
@@ -35,8 +35,22 @@ impl ToFormatElement for JsVariableDeclaration {
 			empty_element()
 		};
 
+		let excl = if let Some(excl) = self.excl_token() {
+			formatter.format_token(&excl)?
+		} else {
+			empty_element()
+		};
+
+		let type_annotation = if let Some(type_annotation) = self.type_annotation() {
+			formatter.format_node(type_annotation)?
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
 			formatter.format_node(self.id()?)?,
+			excl,
+			type_annotation,
 			initializer
 		])
 	}
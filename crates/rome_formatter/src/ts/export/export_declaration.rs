@@ -0,0 +1,125 @@
+use crate::{
+	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{
+	DefaultDecl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportWildcard,
+	JsAnyExportDeclaration, JsAnyExpression,
+};
+use rslint_parser::{AstNode, SyntaxToken};
+
+use crate::ts::expressions::literal_expression::format_string_literal_token;
+
+impl ToFormatElement for ExportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			format_optional_keyword(self.type_token(), formatter)?,
+			formatter.format_node(self.decl()?)?
+		])
+	}
+}
+
+impl ToFormatElement for JsAnyExportDeclaration {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyExportDeclaration::ExportNamed(named) => named.to_format_element(formatter),
+			JsAnyExportDeclaration::JsClassDeclaration(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::JsFunctionDeclaration(decl) => {
+				decl.to_format_element(formatter)
+			}
+			JsAnyExportDeclaration::JsVariableStatement(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::TsEnum(_)
+			| JsAnyExportDeclaration::TsTypeAliasDecl(_)
+			| JsAnyExportDeclaration::TsNamespaceDecl(_)
+			| JsAnyExportDeclaration::TsModuleDecl(_)
+			| JsAnyExportDeclaration::TsInterfaceDecl(_) => {
+				// TODO: format the TypeScript declarations
+				Ok(formatter.format_raw(self.syntax()).trim_start().trim_end())
+			}
+		}
+	}
+}
+
+impl ToFormatElement for ExportDefaultDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			format_optional_keyword(self.default_token(), formatter)?,
+			format_optional_keyword(self.type_token(), formatter)?,
+			formatter.format_node(self.decl()?)?
+		])
+	}
+}
+
+impl ToFormatElement for DefaultDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			DefaultDecl::JsFunctionDeclaration(decl) => decl.to_format_element(formatter),
+			DefaultDecl::JsClassDeclaration(decl) => decl.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for ExportDefaultExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let expr = self.expr()?;
+
+		// `export default function() {}` and `export default class {}` are declarations
+		let semicolon = match expr {
+			JsAnyExpression::JsFunctionExpression(_) | JsAnyExpression::JsClassExpression(_) => {
+				empty_element()
+			}
+			_ => token(";"),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			format_optional_keyword(self.type_token(), formatter)?,
+			format_optional_keyword(self.default_token(), formatter)?,
+			formatter.format_node(expr)?,
+			semicolon
+		])
+	}
+}
+
+impl ToFormatElement for ExportWildcard {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let alias = match (self.as_token(), self.alias()) {
+			(Some(as_token), Some(alias)) => format_elements![
+				space_token(),
+				formatter.format_token(&as_token)?,
+				space_token(),
+				formatter.format_node(alias)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			format_optional_keyword(self.type_token(), formatter)?,
+			formatter.format_token(&self.star_token()?)?,
+			alias,
+			space_token(),
+			formatter.format_token(&self.from_token()?)?,
+			space_token(),
+			format_string_literal_token(&self.source_token()?, formatter),
+			token(";")
+		])
+	}
+}
+
+/// Formats a keyword that may be omitted, like `default` or `type`, followed by a space
+fn format_optional_keyword(
+	keyword: Option<SyntaxToken>,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	Ok(match keyword {
+		Some(keyword) => format_elements![formatter.format_token(&keyword)?, space_token()],
+		None => empty_element(),
+	})
+}
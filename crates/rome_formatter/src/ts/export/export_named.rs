@@ -0,0 +1,76 @@
+use crate::{
+	empty_element, format_elements, group_elements, if_group_breaks, indent, join_elements,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{AstSeparatedList, ExportNamed, Specifier};
+
+use crate::ts::expressions::literal_expression::format_string_literal_token;
+
+impl ToFormatElement for ExportNamed {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let l_curly = formatter.format_token(&self.l_curly_token()?)?;
+		let r_curly = formatter.format_token(&self.r_curly_token()?)?;
+		let specifiers = self.specifiers();
+
+		let specifiers = if specifiers.is_empty() {
+			format_elements![l_curly, r_curly]
+		} else {
+			// The trailing separator of the source is already printed when the group breaks
+			let trailing_comma = if specifiers.trailing_separator().is_some() {
+				empty_element()
+			} else {
+				if_group_breaks(token(","))
+			};
+
+			group_elements(format_elements![
+				l_curly,
+				indent(format_elements![
+					soft_line_break_or_space(),
+					join_elements(
+						soft_line_break_or_space(),
+						formatter.format_separated(specifiers)?
+					),
+					trailing_comma,
+				]),
+				soft_line_break_or_space(),
+				r_curly,
+			])
+		};
+
+		let source = match (self.from_token(), self.js_string_literal_token()) {
+			(Some(from), Some(source)) => format_elements![
+				space_token(),
+				formatter.format_token(&from)?,
+				space_token(),
+				format_string_literal_token(&source, formatter)
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![
+			specifiers,
+			source,
+			formatter.format_or_create_token(self.semicolon_token(), ";")
+		])
+	}
+}
+
+impl ToFormatElement for Specifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let alias = match (self.as_token(), self.alias()) {
+			(Some(as_token), Some(alias)) => format_elements![
+				space_token(),
+				formatter.format_token(&as_token)?,
+				space_token(),
+				formatter.format_node(alias)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.name()?)?,
+			alias
+		])
+	}
+}
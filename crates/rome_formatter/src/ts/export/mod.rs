@@ -0,0 +1,2 @@
+mod export_declaration;
+mod export_named;
@@ -2,16 +2,25 @@ use crate::{
 	empty_element, format_elements, group_elements, if_group_breaks, join_elements, soft_indent,
 	soft_line_break_or_space, token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
-use rslint_parser::ast::{JsArrayExpression, JsArrayHole};
+use rslint_parser::ast::{AstSeparatedList, JsArrayExpression, JsArrayHole};
 
 impl ToFormatElement for JsArrayExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let elements = formatter.format_separated(self.elements())?;
+		let list = self.elements();
+		// The trailing separator of the list is printed by `format_separated`
+		let trailing_comma = if list.is_empty() || list.trailing_separator().is_some() {
+			empty_element()
+		} else {
+			if_group_breaks(token(","))
+		};
+		let elements = formatter.format_separated(list)?;
 
 		Ok(group_elements(format_elements!(
 			formatter.format_token(&self.l_brack_token()?)?,
-			soft_indent(join_elements(soft_line_break_or_space(), elements)),
-			if_group_breaks(token(",")),
+			soft_indent(format_elements![
+				join_elements(soft_line_break_or_space(), elements),
+				trailing_comma,
+			]),
 			formatter.format_token(&self.r_brack_token()?)?,
 		)))
 	}
@@ -2,6 +2,7 @@ use rslint_parser::ast::{
 	JsAnyArrowFunctionBody, JsAnyArrowFunctionParameters, JsArrowFunctionExpression,
 };
 
+use crate::ts::expressions::assignment_expression::format_assignment_right;
 use crate::{
 	concat_elements, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
 	ToFormatElement,
@@ -18,6 +19,10 @@ impl ToFormatElement for JsArrowFunctionExpression {
 			));
 		}
 
+		if let Some(type_parameters) = self.type_parameters() {
+			tokens.push(formatter.format_node(type_parameters)?);
+		}
+
 		if let Some(params) = self.parameters() {
 			match params {
 				JsAnyArrowFunctionParameters::JsAnyBinding(binding) => {
@@ -31,10 +36,24 @@ impl ToFormatElement for JsArrowFunctionExpression {
 			}
 		}
 
+		if let Some(return_type) = self.return_type() {
+			tokens.push(formatter.format_node(return_type)?);
+		}
+
 		tokens.push(space_token());
 		tokens.push(formatter.format_token(&self.fat_arrow_token()?)?);
-		tokens.push(space_token());
-		tokens.push(formatter.format_node(self.body()?)?);
+
+		// The expressions that break by operator move to the next line like the right side of an
+		// assignment, so that their continuation lines are indented under the arrow
+		match self.body()? {
+			JsAnyArrowFunctionBody::JsAnyExpression(expression) => {
+				tokens.push(format_assignment_right(expression, formatter)?);
+			}
+			body => {
+				tokens.push(space_token());
+				tokens.push(formatter.format_node(body)?);
+			}
+		}
 
 		Ok(concat_elements(tokens))
 	}
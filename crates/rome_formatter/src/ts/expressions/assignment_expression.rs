@@ -0,0 +1,53 @@
+use crate::{
+	format_elements, group_elements, indent, soft_line_break_or_space, space_token, FormatElement,
+	FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{JsAnyExpression, JsAssignmentExpression};
+use rslint_parser::SyntaxResult;
+
+impl ToFormatElement for JsAssignmentExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.left()?)?,
+			space_token(),
+			formatter.format_token(&self.operator_token()?)?,
+			format_assignment_right(self.right()?, formatter)?,
+		])
+	}
+}
+
+/// Formats the expression on the right of an assignment operator, or the expression body of an
+/// arrow function, including the space after the operator or the arrow.
+///
+/// A chain of binary or logical expressions, or a conditional expression that tests one, moves
+/// to the next line, indented, if it doesn't fit after the operator. The other expressions stay
+/// after the operator and break on their own.
+pub(crate) fn format_assignment_right(
+	right: JsAnyExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	if breaks_after_operator(&right)? {
+		Ok(group_elements(indent(format_elements![
+			soft_line_break_or_space(),
+			formatter.format_node(right)?
+		])))
+	} else {
+		Ok(format_elements![
+			space_token(),
+			formatter.format_node(right)?
+		])
+	}
+}
+
+fn breaks_after_operator(right: &JsAnyExpression) -> SyntaxResult<bool> {
+	let breaks = match right {
+		JsAnyExpression::JsBinaryExpression(_) | JsAnyExpression::JsLogicalExpression(_) => true,
+		JsAnyExpression::JsConditionalExpression(conditional) => matches!(
+			conditional.test()?,
+			JsAnyExpression::JsBinaryExpression(_) | JsAnyExpression::JsLogicalExpression(_)
+		),
+		_ => false,
+	};
+
+	Ok(breaks)
+}
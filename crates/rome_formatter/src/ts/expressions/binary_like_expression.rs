@@ -0,0 +1,192 @@
+use crate::{
+	concat_elements, format_elements, group_elements, indent, soft_line_break_or_space,
+	space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	JsAnyExpression, JsBinaryExpression, JsConditionalExpression, JsLogicalExpression,
+};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, SyntaxResult, SyntaxToken, T};
+
+impl ToFormatElement for JsBinaryExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		format_binary_like_expression(BinaryLikeExpression::Binary(self.clone()), formatter)
+	}
+}
+
+impl ToFormatElement for JsLogicalExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		format_binary_like_expression(BinaryLikeExpression::Logical(self.clone()), formatter)
+	}
+}
+
+/// The expressions that are formatted as a chain of operands separated by an operator
+#[derive(Debug, Clone)]
+enum BinaryLikeExpression {
+	Binary(JsBinaryExpression),
+	Logical(JsLogicalExpression),
+}
+
+impl BinaryLikeExpression {
+	fn cast(expression: JsAnyExpression) -> Option<Self> {
+		match expression {
+			JsAnyExpression::JsBinaryExpression(binary) => Some(Self::Binary(binary)),
+			JsAnyExpression::JsLogicalExpression(logical) => Some(Self::Logical(logical)),
+			_ => None,
+		}
+	}
+
+	fn left(&self) -> SyntaxResult<JsAnyExpression> {
+		match self {
+			Self::Binary(binary) => binary.left(),
+			Self::Logical(logical) => logical.left(),
+		}
+	}
+
+	fn operator(&self) -> SyntaxResult<SyntaxToken> {
+		match self {
+			Self::Binary(binary) => binary.operator(),
+			Self::Logical(logical) => logical.operator(),
+		}
+	}
+
+	fn right(&self) -> SyntaxResult<JsAnyExpression> {
+		match self {
+			Self::Binary(binary) => binary.right(),
+			Self::Logical(logical) => logical.right(),
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Binary(binary) => binary.syntax(),
+			Self::Logical(logical) => logical.syntax(),
+		}
+	}
+}
+
+/// Formats a chain of binary or logical expressions, like `a + b - c`.
+///
+/// The operands of the operators with the same precedence are printed at the same level:
+/// either all on the same line, or one per line with the operator at the end of the line.
+/// An operand with operators of another precedence, like `b * c` in `a + b * c`, is
+/// grouped on its own, so that it's only broken if it doesn't fit on its line.
+fn format_binary_like_expression(
+	expression: BinaryLikeExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	// The operators and their right operand, starting from the end of the chain
+	let mut tail = Vec::new();
+	let mut current = expression.clone();
+
+	let first = loop {
+		let operator = current.operator()?;
+		let right = current.right()?;
+		let left = current.left()?;
+
+		let flattened = match BinaryLikeExpression::cast(left.clone()) {
+			Some(left) if should_flatten(operator.kind(), left.operator()?.kind()) => Some(left),
+			_ => None,
+		};
+
+		tail.push((operator, right));
+
+		match flattened {
+			Some(left) => current = left,
+			None => break left,
+		}
+	};
+
+	let first = formatter.format_node(first)?;
+	let tail = tail
+		.into_iter()
+		.rev()
+		.map(|(operator, right)| {
+			Ok(format_elements![
+				space_token(),
+				formatter.format_token(&operator)?,
+				soft_line_break_or_space(),
+				formatter.format_node(right)?,
+			])
+		})
+		.collect::<FormatResult<Vec<_>>>()?;
+	let tail = concat_elements(tail);
+
+	if should_indent(expression.syntax()) {
+		Ok(group_elements(format_elements![first, indent(tail)]))
+	} else {
+		Ok(group_elements(format_elements![first, tail]))
+	}
+}
+
+/// Returns `true` if the continuation lines of a chain should be indented. They aren't when the
+/// chain is an operand of another chain, or when its parent already breaks and indents it.
+///
+/// The test of a conditional expression isn't indented either, so that its continuation lines
+/// don't line up with the `?` and `:` branches, which are indented.
+fn should_indent(expression: &SyntaxNode) -> bool {
+	let parent = match expression.parent() {
+		Some(parent) => parent,
+		None => return true,
+	};
+
+	if let Some(conditional) = JsConditionalExpression::cast(parent.clone()) {
+		return conditional
+			.test()
+			.map_or(true, |test| test.syntax() != expression);
+	}
+
+	!matches!(
+		parent.kind(),
+		SyntaxKind::JS_BINARY_EXPRESSION
+			| SyntaxKind::JS_LOGICAL_EXPRESSION
+			| SyntaxKind::JS_ASSIGNMENT_EXPRESSION
+			| SyntaxKind::JS_INITIALIZER_CLAUSE
+			| SyntaxKind::JS_IF_STATEMENT
+			| SyntaxKind::JS_WHILE_STATEMENT
+			| SyntaxKind::JS_DO_WHILE_STATEMENT
+			| SyntaxKind::JS_SWITCH_STATEMENT
+	)
+}
+
+/// Returns `true` if the left operand of `parent_operator`, whose operator is `operator`, is
+/// printed at the same level as the right operand.
+///
+/// Only the operators with the same precedence are flattened, and not the ones whose grouping
+/// isn't obvious to the reader, like `a * b / c` or `a == b == c`.
+fn should_flatten(parent_operator: SyntaxKind, operator: SyntaxKind) -> bool {
+	if precedence(parent_operator) != precedence(operator) {
+		return false;
+	}
+
+	!matches!(
+		(parent_operator, operator),
+		// `a ** b ** c` is `a ** (b ** c)`
+		(T![**], _)
+			| (T![==] | T![!=] | T![===] | T![!==], _)
+			| (T![%], T![*] | T![/])
+			| (T![*] | T![/], T![%])
+			| (T![*], T![/])
+			| (T![/], T![*])
+			| (T![<<] | T![>>] | T![>>>], _)
+	)
+}
+
+/// Returns the precedence of a binary or logical operator, the operators with a higher
+/// precedence bind tighter
+fn precedence(operator: SyntaxKind) -> u8 {
+	match operator {
+		T![??] => 1,
+		T![||] => 2,
+		T![&&] => 3,
+		T![|] => 4,
+		T![^] => 5,
+		T![&] => 6,
+		T![==] | T![!=] | T![===] | T![!==] => 7,
+		T![<] | T![>] | T![<=] | T![>=] | T![in] | T![instanceof] => 8,
+		T![<<] | T![>>] | T![>>>] => 9,
+		T![+] | T![-] => 10,
+		T![*] | T![/] | T![%] => 11,
+		T![**] => 12,
+		_ => 0,
+	}
+}
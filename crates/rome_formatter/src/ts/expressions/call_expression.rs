@@ -1,10 +1,22 @@
-use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::{
+	empty_element, format_elements, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
 use rslint_parser::ast::CallExpr;
 
 impl ToFormatElement for CallExpr {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let name = formatter.format_node(self.callee()?)?;
+		let opt_chain = if let Some(opt_chain) = self.opt_chain_token() {
+			formatter.format_token(&opt_chain)?
+		} else {
+			empty_element()
+		};
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
 		let arguments = formatter.format_node(self.arguments()?)?;
-		Ok(format_elements![name, arguments])
+		Ok(format_elements![name, opt_chain, type_args, arguments])
 	}
 }
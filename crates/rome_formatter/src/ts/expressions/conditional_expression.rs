@@ -0,0 +1,44 @@
+use crate::{
+	format_elements, group_elements, indent, soft_line_break_or_space, space_token, FormatElement,
+	FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{JsAnyExpression, JsConditionalExpression};
+
+impl ToFormatElement for JsConditionalExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(group_elements(format_elements![
+			formatter.format_node(self.test()?)?,
+			indent(format_conditional_branches(self, formatter)?),
+		]))
+	}
+}
+
+/// Formats the consequent and the alternate of a conditional expression, each on its own line if
+/// the expression doesn't fit on a single line.
+///
+/// A conditional expression in the alternate continues the chain at the same indentation, rather
+/// than being indented under the `:`, so that a chain of conditions reads like an `if else` chain.
+fn format_conditional_branches(
+	expression: &JsConditionalExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let consequent = format_elements![
+		soft_line_break_or_space(),
+		formatter.format_token(&expression.question_mark_token()?)?,
+		space_token(),
+		formatter.format_node(expression.consequent()?)?,
+		soft_line_break_or_space(),
+		formatter.format_token(&expression.colon_token()?)?,
+		space_token(),
+	];
+
+	let alternate = match expression.alternate()? {
+		JsAnyExpression::JsConditionalExpression(alternate) => format_elements![
+			formatter.format_node(alternate.test()?)?,
+			format_conditional_branches(&alternate, formatter)?,
+		],
+		alternate => formatter.format_node(alternate)?,
+	};
+
+	Ok(format_elements![consequent, alternate])
+}
@@ -1,14 +1,13 @@
 use crate::{
-	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
-	ToFormatElement,
+	empty_element, format_elements, space_token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{
-	JsAnyExpression, JsAssignmentExpression, JsAwaitExpression, JsBinaryExpression,
-	JsComputedMemberExpression, JsConditionalExpression, JsLogicalExpression,
+	ImportMeta, JsAnyExpression, JsAwaitExpression, JsComputedMemberExpression,
 	JsParenthesizedExpression, JsThisExpression, JsUnaryExpression, JsYieldExpression, NewExpr,
-	NewTarget,
+	NewTarget, TsNonNull,
 };
-use rslint_parser::{token_set, TokenSet, T};
+use rslint_parser::{token_set, AstNode, TokenSet, T};
 
 impl ToFormatElement for JsAnyExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -17,7 +16,7 @@ impl ToFormatElement for JsAnyExpression {
 			JsAnyExpression::JsAnyLiteralExpression(literal) => {
 				literal.to_format_element(formatter)
 			}
-			JsAnyExpression::Template(_) => todo!(),
+			JsAnyExpression::Template(template) => template.to_format_element(formatter),
 			JsAnyExpression::JsIdentifierExpression(identifier_expr) => {
 				identifier_expr.to_format_element(formatter)
 			}
@@ -63,7 +62,7 @@ impl ToFormatElement for JsAnyExpression {
 				class_expression.to_format_element(formatter)
 			}
 			JsAnyExpression::NewTarget(expr) => expr.to_format_element(formatter),
-			JsAnyExpression::ImportMeta(_) => todo!(),
+			JsAnyExpression::ImportMeta(import_meta) => import_meta.to_format_element(formatter),
 			JsAnyExpression::JsImportCallExpression(import_call_expr) => {
 				import_call_expr.to_format_element(formatter)
 			}
@@ -73,16 +72,23 @@ impl ToFormatElement for JsAnyExpression {
 			JsAnyExpression::JsAwaitExpression(await_expression) => {
 				await_expression.to_format_element(formatter)
 			}
-			JsAnyExpression::TsNonNull(_) => todo!(),
-			JsAnyExpression::TsAssertion(_) => todo!(),
-			JsAnyExpression::TsConstAssertion(_) => todo!(),
+			JsAnyExpression::TsNonNull(non_null) => non_null.to_format_element(formatter),
+			// TODO format type assertions, they're printed as they are for now
+			JsAnyExpression::TsAssertion(assertion) => Ok(formatter
+				.format_raw(assertion.syntax())
+				.trim_start()
+				.trim_end()),
+			JsAnyExpression::TsConstAssertion(assertion) => Ok(formatter
+				.format_raw(assertion.syntax())
+				.trim_start()
+				.trim_end()),
 			JsAnyExpression::JsPreUpdateExpression(pre_update_expression) => {
 				pre_update_expression.to_format_element(formatter)
 			}
 			JsAnyExpression::JsPostUpdateExpression(post_update_expression) => {
 				post_update_expression.to_format_element(formatter)
 			}
-			JsAnyExpression::JsUnknownExpression(_) => todo!(),
+			JsAnyExpression::JsUnknownExpression(_) => Err(FormatError::UnknownNode),
 			JsAnyExpression::JsLogicalExpression(logical_expression) => {
 				logical_expression.to_format_element(formatter)
 			}
@@ -127,11 +133,17 @@ impl ToFormatElement for JsComputedMemberExpression {
 
 impl ToFormatElement for NewExpr {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
 			formatter.format_token(&self.new_token()?)?,
-			// TODO handle TsTypeArgs
 			space_token(),
 			formatter.format_node(self.object()?)?,
+			type_args,
 			formatter.format_node(self.arguments()?)?,
 		])
 	}
@@ -140,66 +152,50 @@ impl ToFormatElement for NewExpr {
 impl ToFormatElement for JsUnaryExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let operator = self.operator()?;
-		let space_or_empty =
-			if token_set![T![delete], T![void], T![typeof]].contains(operator.kind()) {
-				space_token()
-			} else {
-				empty_element()
-			};
-		Ok(format_elements![
-			formatter.format_token(&self.operator()?)?,
-			space_or_empty,
-			formatter.format_node(self.argument()?)?,
-		])
-	}
-}
+		let argument = self.argument()?;
 
-impl ToFormatElement for JsBinaryExpression {
-	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		Ok(format_elements![
-			formatter.format_node(self.left()?)?,
-			space_token(),
-			formatter.format_token(&self.operator()?)?,
-			space_token(),
-			formatter.format_node(self.right()?)?
-		])
-	}
-}
+		// `- -a` and `+ ++a` would be read as a `--` and a `++` operator without the space
+		let argument_operator = match &argument {
+			JsAnyExpression::JsUnaryExpression(unary) => Some(unary.operator()?.kind()),
+			JsAnyExpression::JsPreUpdateExpression(update) => Some(update.operator()?.kind()),
+			_ => None,
+		};
+		let is_ambiguous = matches!(
+			(operator.kind(), argument_operator),
+			(T![-], Some(T![-] | T![--])) | (T![+], Some(T![+] | T![++]))
+		);
 
-impl ToFormatElement for JsConditionalExpression {
-	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let space_or_empty = if is_ambiguous
+			|| token_set![T![delete], T![void], T![typeof]].contains(operator.kind())
+		{
+			space_token()
+		} else {
+			empty_element()
+		};
 		Ok(format_elements![
-			formatter.format_node(self.test()?)?,
-			space_token(),
-			formatter.format_token(&self.question_mark_token()?)?,
-			space_token(),
-			formatter.format_node(self.consequent()?)?,
-			space_token(),
-			formatter.format_token(&self.colon_token()?)?,
-			space_token(),
-			formatter.format_node(self.alternate()?)?,
+			formatter.format_token(&operator)?,
+			space_or_empty,
+			formatter.format_node(argument)?,
 		])
 	}
 }
 
-impl ToFormatElement for JsAssignmentExpression {
+impl ToFormatElement for NewTarget {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		Ok(format_elements![
-			formatter.format_node(self.left()?)?,
-			space_token(),
-			formatter.format_token(&self.operator_token()?)?,
-			space_token(),
-			formatter.format_node(self.right()?)?,
+			formatter.format_token(&self.new_token()?)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_token(&self.target_token()?)?,
 		])
 	}
 }
 
-impl ToFormatElement for NewTarget {
+impl ToFormatElement for ImportMeta {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		Ok(format_elements![
-			formatter.format_token(&self.new_token()?)?,
+			formatter.format_token(&self.import_token()?)?,
 			formatter.format_token(&self.dot_token()?)?,
-			formatter.format_token(&self.target_token()?)?,
+			formatter.format_token(&self.meta_token()?)?,
 		])
 	}
 }
@@ -234,14 +230,11 @@ impl ToFormatElement for JsAwaitExpression {
 	}
 }
 
-impl ToFormatElement for JsLogicalExpression {
+impl ToFormatElement for TsNonNull {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		Ok(format_elements![
-			formatter.format_node(self.left()?)?,
-			space_token(),
-			formatter.format_token(&self.operator()?)?,
-			space_token(),
-			formatter.format_node(self.right()?)?,
+			formatter.format_node(self.expr()?)?,
+			formatter.format_token(&self.excl_token()?)?,
 		])
 	}
 }
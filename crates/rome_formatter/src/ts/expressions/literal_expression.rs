@@ -1,25 +1,36 @@
 use crate::{token, FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::{
 	JsAnyLiteralExpression, JsBigIntLiteralExpression, JsBooleanLiteralExpression,
-	JsNullLiteralExpression, JsNumberLiteralExpression, JsStringLiteralExpression,
+	JsNullLiteralExpression, JsNumberLiteralExpression, JsRegexLiteralExpression,
+	JsStringLiteralExpression,
 };
+use rslint_parser::SyntaxToken;
 
 impl ToFormatElement for JsStringLiteralExpression {
-	fn to_format_element(&self, _: &Formatter) -> FormatResult<FormatElement> {
-		let value_token = self.value_token()?;
-		let quoted = value_token.text_trimmed();
-
-		// uses single quotes
-		if quoted.starts_with('\'') {
-			let s = &quoted[1..quoted.len() - 1];
-			let s = format!("\"{}\"", s);
-			Ok(token(s))
-		} else {
-			Ok(token(quoted))
-		}
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_string_literal_token(&self.value_token()?, formatter))
 	}
 }
 
+/// Formats a string literal token, string literals are always printed with double quotes
+pub(crate) fn format_string_literal_token(
+	value_token: &SyntaxToken,
+	formatter: &Formatter,
+) -> FormatElement {
+	let quoted = value_token.text_trimmed();
+
+	// uses single quotes
+	let content = if quoted.starts_with('\'') {
+		let s = &quoted[1..quoted.len() - 1];
+		let s = format!("\"{}\"", s);
+		token(s)
+	} else {
+		token(quoted)
+	};
+
+	formatter.format_replaced(value_token, content)
+}
+
 impl ToFormatElement for JsBooleanLiteralExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		formatter.format_token(&self.value_token()?)
@@ -44,6 +55,12 @@ impl ToFormatElement for JsBigIntLiteralExpression {
 	}
 }
 
+impl ToFormatElement for JsRegexLiteralExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.value_token()?)
+	}
+}
+
 impl ToFormatElement for JsAnyLiteralExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		match self {
@@ -62,7 +79,9 @@ impl ToFormatElement for JsAnyLiteralExpression {
 			JsAnyLiteralExpression::JsNullLiteralExpression(null_literal) => {
 				null_literal.to_format_element(formatter)
 			}
-			JsAnyLiteralExpression::JsRegexLiteralExpression(_) => todo!(),
+			JsAnyLiteralExpression::JsRegexLiteralExpression(regex) => {
+				regex.to_format_element(formatter)
+			}
 		}
 	}
 }
@@ -1,10 +1,13 @@
 mod array_expr;
 mod arrow_expr;
+pub(crate) mod assignment_expression;
+mod binary_like_expression;
 mod call_expression;
+mod conditional_expression;
 mod expression;
 mod function_expression;
 mod identifier_expression;
-mod literal_expression;
+pub(crate) mod literal_expression;
 mod object_expression;
 mod sequence_expression;
 mod static_member_expression;
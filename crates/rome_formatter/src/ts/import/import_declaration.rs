@@ -0,0 +1,103 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{
+	AnyJsImportClause, JsImport, JsImportAssertion, JsImportBareClause, JsImportDefaultClause,
+	JsImportNamedClause, JsImportNamespaceClause,
+};
+
+impl ToFormatElement for JsImport {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.import_token()?)?,
+			space_token(),
+			formatter.format_node(self.import_clause()?)?,
+			formatter.format_or_create_token(self.semicolon_token(), ";")
+		])
+	}
+}
+
+impl ToFormatElement for AnyJsImportClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			AnyJsImportClause::JsImportBareClause(clause) => clause.to_format_element(formatter),
+			AnyJsImportClause::JsImportDefaultClause(clause) => clause.to_format_element(formatter),
+			AnyJsImportClause::JsImportNamedClause(clause) => clause.to_format_element(formatter),
+			AnyJsImportClause::JsImportNamespaceClause(clause) => {
+				clause.to_format_element(formatter)
+			}
+			AnyJsImportClause::JsName(name) => name.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for JsImportBareClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.source()?)?,
+			format_assertion(self.assertion(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for JsImportDefaultClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.local_name()?)?,
+			space_token(),
+			formatter.format_token(&self.from_token()?)?,
+			space_token(),
+			formatter.format_node(self.source()?)?,
+			format_assertion(self.assertion(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for JsImportNamespaceClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.star_token()?)?,
+			space_token(),
+			formatter.format_token(&self.as_token()?)?,
+			space_token(),
+			formatter.format_node(self.local_name()?)?,
+			space_token(),
+			formatter.format_token(&self.from_token()?)?,
+			space_token(),
+			formatter.format_node(self.source()?)?,
+			format_assertion(self.assertion(), formatter)?
+		])
+	}
+}
+
+impl ToFormatElement for JsImportNamedClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let default_specifier = if let Some(default_specifier) = self.default_specifier() {
+			format_elements![formatter.format_node(default_specifier)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			default_specifier,
+			formatter.format_node(self.named_import()?)?,
+			space_token(),
+			formatter.format_token(&self.from_token()?)?,
+			space_token(),
+			formatter.format_node(self.source()?)?,
+			format_assertion(self.assertion(), formatter)?
+		])
+	}
+}
+
+/// Formats the optional assertion that follows the source of an import
+fn format_assertion(
+	assertion: Option<JsImportAssertion>,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	Ok(match assertion {
+		Some(assertion) => format_elements![space_token(), formatter.format_node(assertion)?],
+		None => empty_element(),
+	})
+}
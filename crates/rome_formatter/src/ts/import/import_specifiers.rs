@@ -0,0 +1,126 @@
+use crate::{
+	empty_element, format_elements, group_elements, if_group_breaks, indent, join_elements,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	AstSeparatedList, JsAnyNamedImport, JsAnyNamedImportSpecifier, JsDefaultImportSpecifier,
+	JsLiteralExportName, JsNamedImportSpecifier, JsNamedImportSpecifiers,
+	JsNamespaceImportSpecifier, JsShorthandNamedImportSpecifier,
+};
+use rslint_parser::SyntaxKind;
+
+use crate::ts::expressions::literal_expression::format_string_literal_token;
+
+impl ToFormatElement for JsDefaultImportSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.local_name()?)?,
+			formatter.format_token(&self.trailing_comma_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for JsAnyNamedImport {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyNamedImport::JsNamedImportSpecifiers(specifiers) => {
+				specifiers.to_format_element(formatter)
+			}
+			JsAnyNamedImport::JsNamespaceImportSpecifier(specifier) => {
+				specifier.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for JsNamespaceImportSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.star_token()?)?,
+			space_token(),
+			formatter.format_token(&self.as_token()?)?,
+			space_token(),
+			formatter.format_node(self.local_name()?)?
+		])
+	}
+}
+
+impl ToFormatElement for JsNamedImportSpecifiers {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let l_curly = formatter.format_token(&self.l_curly_token()?)?;
+		let r_curly = formatter.format_token(&self.r_curly_token()?)?;
+		let specifiers = self.specifiers();
+
+		if specifiers.is_empty() {
+			return Ok(format_elements![l_curly, r_curly]);
+		}
+
+		// The trailing separator of the source is already printed when the group breaks
+		let trailing_comma = if specifiers.trailing_separator().is_some() {
+			empty_element()
+		} else {
+			if_group_breaks(token(","))
+		};
+
+		Ok(group_elements(format_elements![
+			l_curly,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				join_elements(
+					soft_line_break_or_space(),
+					formatter.format_separated(specifiers)?
+				),
+				trailing_comma,
+			]),
+			soft_line_break_or_space(),
+			r_curly,
+		]))
+	}
+}
+
+impl ToFormatElement for JsAnyNamedImportSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+				specifier.to_format_element(formatter)
+			}
+			JsAnyNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+				specifier.to_format_element(formatter)
+			}
+			JsAnyNamedImportSpecifier::JsUnknownNamedImportSpecifier(_) => {
+				Err(FormatError::UnknownNode)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for JsNamedImportSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.name()?)?,
+			space_token(),
+			formatter.format_token(&self.as_token()?)?,
+			space_token(),
+			formatter.format_node(self.local_name()?)?
+		])
+	}
+}
+
+impl ToFormatElement for JsShorthandNamedImportSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_node(self.local_name()?)
+	}
+}
+
+impl ToFormatElement for JsLiteralExportName {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let value = self.value()?;
+
+		if value.kind() == SyntaxKind::JS_STRING_LITERAL {
+			Ok(format_string_literal_token(&value, formatter))
+		} else {
+			formatter.format_token(&value)
+		}
+	}
+}
@@ -1,3 +1,7 @@
+mod import_declaration;
+mod import_specifiers;
+mod module_source;
+
 use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsImportCallExpression;
 
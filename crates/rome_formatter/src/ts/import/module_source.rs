@@ -0,0 +1,66 @@
+use crate::{
+	format_elements, group_elements, join_elements, soft_line_break_or_space, space_token,
+	FormatElement, FormatError, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	JsAnyImportAssertionEntry, JsImportAssertion, JsImportAssertionEntry, JsModuleSource,
+};
+use rslint_parser::SyntaxKind;
+
+use crate::ts::expressions::literal_expression::format_string_literal_token;
+
+impl ToFormatElement for JsModuleSource {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_string_literal_token(&self.value_token()?, formatter))
+	}
+}
+
+impl ToFormatElement for JsImportAssertion {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.assert_token()?)?,
+			space_token(),
+			group_elements(format_elements![
+				formatter.format_token(&self.l_curly_token()?)?,
+				space_token(),
+				join_elements(
+					soft_line_break_or_space(),
+					formatter.format_separated(self.assertions())?
+				),
+				space_token(),
+				formatter.format_token(&self.r_curly_token()?)?,
+			])
+		])
+	}
+}
+
+impl ToFormatElement for JsAnyImportAssertionEntry {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyImportAssertionEntry::JsImportAssertionEntry(entry) => {
+				entry.to_format_element(formatter)
+			}
+			JsAnyImportAssertionEntry::JsUnknownImportAssertionEntry(_) => {
+				Err(FormatError::UnknownNode)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for JsImportAssertionEntry {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let key = self.key()?;
+		let key = if key.kind() == SyntaxKind::JS_STRING_LITERAL {
+			format_string_literal_token(&key, formatter)
+		} else {
+			formatter.format_token(&key)?
+		};
+
+		Ok(format_elements![
+			key,
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			format_string_literal_token(&self.value_token()?, formatter)
+		])
+	}
+}
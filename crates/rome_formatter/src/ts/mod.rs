@@ -5,14 +5,17 @@ mod auxiliary;
 mod bindings;
 mod class;
 mod declarators;
+mod export;
 mod expressions;
 mod ident;
 mod import;
+mod module;
 mod object_members;
 mod parameter_list;
 mod script;
 mod statements;
 mod template;
+mod typescript;
 
 #[cfg(test)]
 mod test {
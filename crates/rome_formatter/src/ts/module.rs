@@ -0,0 +1,57 @@
+use crate::{
+	format_elements, hard_line_break, join_elements, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{AstNodeList, JsAnyModuleItem, JsModule, JsModuleItemList};
+use rslint_parser::AstNode;
+
+impl ToFormatElement for JsModule {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let mut elements = vec![];
+
+		if let Some(interpreter) = self.interpreter_token() {
+			elements.push(formatter.format_token(&interpreter)?);
+			elements.push(hard_line_break());
+		}
+
+		elements.push(format_module_items(self.items(), formatter));
+		// The comments at the end of the file are in the leading trivia of the end of file token
+		elements.push(formatter.format_token(&self.eof_token()?)?);
+
+		Ok(format_elements![
+			concat_elements(elements),
+			hard_line_break()
+		])
+	}
+}
+
+impl ToFormatElement for JsAnyModuleItem {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyModuleItem::JsAnyStatement(statement) => statement.to_format_element(formatter),
+			JsAnyModuleItem::JsImport(import) => import.to_format_element(formatter),
+			JsAnyModuleItem::ExportDecl(export) => export.to_format_element(formatter),
+			JsAnyModuleItem::ExportDefaultDecl(export) => export.to_format_element(formatter),
+			JsAnyModuleItem::ExportDefaultExpr(export) => export.to_format_element(formatter),
+			JsAnyModuleItem::ExportWildcard(export) => export.to_format_element(formatter),
+			JsAnyModuleItem::TsImportEqualsDecl(_)
+			| JsAnyModuleItem::TsExportAssignment(_)
+			| JsAnyModuleItem::TsNamespaceExportDecl(_) => {
+				// TODO: format the TypeScript module items
+				Ok(formatter.format_raw(self.syntax()).trim_start().trim_end())
+			}
+		}
+	}
+}
+
+/// Formats the items of a module, one item per line
+fn format_module_items(items: JsModuleItemList, formatter: &Formatter) -> FormatElement {
+	join_elements(
+		hard_line_break(),
+		items.iter().map(|item| {
+			formatter
+				.format_node(item.clone())
+				.unwrap_or_else(|_| formatter.format_verbatim(item.syntax()))
+		}),
+	)
+}
@@ -17,13 +17,23 @@ impl ToFormatElement for JsMethodObjectMember {
 		} else {
 			empty_element()
 		};
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+		let return_type = if let Some(return_type) = self.return_type() {
+			formatter.format_node(return_type)?
+		} else {
+			empty_element()
+		};
 		Ok(format_elements![
 			async_token,
 			star_token,
 			formatter.format_node(self.name()?)?,
-			// TODO self.type_params()
+			type_params,
 			formatter.format_node(self.parameters()?)?,
-			// TODO self.return_type()
+			return_type,
 			space_token(),
 			formatter.format_node(self.body()?)?,
 		])
@@ -1,6 +1,6 @@
 use crate::{
-	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space,
-	FormatElement, FormatResult, Formatter, ToFormatElement,
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{JsAnyParameter, JsParameters, JsRestParameter};
 
@@ -24,15 +24,23 @@ impl ToFormatElement for JsAnyParameter {
 				binding.to_format_element(formatter)
 			}
 			JsAnyParameter::JsRestParameter(binding) => binding.to_format_element(formatter),
+			JsAnyParameter::TsTypedParameter(binding) => binding.to_format_element(formatter),
 		}
 	}
 }
 
 impl ToFormatElement for JsRestParameter {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_annotation = if let Some(type_annotation) = self.type_annotation() {
+			formatter.format_node(type_annotation)?
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
 			formatter.format_token(&self.dotdotdot_token()?)?,
-			formatter.format_node(self.binding()?)?
+			formatter.format_node(self.binding()?)?,
+			type_annotation
 		])
 	}
 }
@@ -14,6 +14,8 @@ impl ToFormatElement for JsScript {
 		}
 
 		elements.push(format_statements(self.statements(), formatter));
+		// The comments at the end of the file are in the leading trivia of the end of file token
+		elements.push(formatter.format_token(&self.eof_token()?)?);
 
 		Ok(format_elements![
 			concat_elements(elements),
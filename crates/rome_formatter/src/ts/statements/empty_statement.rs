@@ -2,7 +2,8 @@ use crate::{empty_element, FormatElement, FormatResult, Formatter, ToFormatEleme
 use rslint_parser::ast::JsEmptyStatement;
 
 impl ToFormatElement for JsEmptyStatement {
-	fn to_format_element(&self, _formatter: &Formatter) -> FormatResult<FormatElement> {
-		Ok(empty_element())
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// The `;` is removed, but not its comments
+		Ok(formatter.format_replaced(&self.semicolon_token()?, empty_element()))
 	}
 }
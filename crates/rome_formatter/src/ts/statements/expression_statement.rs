@@ -1,12 +1,12 @@
 use rslint_parser::ast::JsExpressionStatement;
 
-use crate::{format_elements, token, FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
 
 impl ToFormatElement for JsExpressionStatement {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		Ok(format_elements![
 			formatter.format_node(self.expression()?)?,
-			token(";")
+			formatter.format_or_create_token(self.semicolon_token(), ";")
 		])
 	}
 }
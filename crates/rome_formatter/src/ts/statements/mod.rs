@@ -29,7 +29,7 @@ pub fn format_statements(stmts: JsStatementList, formatter: &Formatter) -> Forma
 		stmts.iter().map(|stmt| {
 			formatter
 				.format_node(stmt.clone())
-				.unwrap_or_else(|_| formatter.format_raw(stmt.syntax()).trim_start().trim_end())
+				.unwrap_or_else(|_| formatter.format_verbatim(stmt.syntax()))
 		}),
 	)
 }
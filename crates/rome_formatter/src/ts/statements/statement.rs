@@ -55,16 +55,21 @@ impl ToFormatElement for JsAnyStatement {
 			JsAnyStatement::JsClassDeclaration(decl) => decl.to_format_element(formatter),
 			JsAnyStatement::JsVariableStatement(decl) => decl.to_format_element(formatter),
 			JsAnyStatement::JsUnknownStatement(unknown_statement) => {
-				Ok(formatter.format_raw(unknown_statement.syntax()))
+				Ok(formatter.format_verbatim(unknown_statement.syntax()))
 			}
 			JsAnyStatement::JsTryFinallyStatement(try_finally) => {
 				try_finally.to_format_element(formatter)
 			}
-			JsAnyStatement::TsEnum(_) => todo!(),
-			JsAnyStatement::TsTypeAliasDecl(_) => todo!(),
-			JsAnyStatement::TsNamespaceDecl(_) => todo!(),
-			JsAnyStatement::TsModuleDecl(_) => todo!(),
-			JsAnyStatement::TsInterfaceDecl(_) => todo!(),
+			JsAnyStatement::TsEnum(decl) => decl.to_format_element(formatter),
+			JsAnyStatement::TsTypeAliasDecl(decl) => decl.to_format_element(formatter),
+			// TODO format namespaces and modules, they're printed as they are for now
+			JsAnyStatement::TsNamespaceDecl(decl) => {
+				Ok(formatter.format_raw(decl.syntax()).trim_start().trim_end())
+			}
+			JsAnyStatement::TsModuleDecl(decl) => {
+				Ok(formatter.format_raw(decl.syntax()).trim_start().trim_end())
+			}
+			JsAnyStatement::TsInterfaceDecl(decl) => decl.to_format_element(formatter),
 		}
 	}
 }
@@ -1,13 +1,55 @@
-use crate::{format_elements, token, FormatElement, FormatResult, Formatter, ToFormatElement};
-use rslint_parser::ast::Template;
+use crate::{
+	concat_elements, empty_element, format_elements, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{AnyTemplateElement, Template, TemplateChunkElement, TemplateElement};
 
 impl ToFormatElement for Template {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		// TODO implement template string, see parser in rslint_parser/src/ast/expr_ext.rs
+		let tag = if let Some(tag) = self.tag() {
+			formatter.format_node(tag)?
+		} else {
+			empty_element()
+		};
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
+		let elements = concat_elements(formatter.format_nodes(self.elements())?);
+
 		Ok(format_elements![
+			tag,
+			type_args,
 			formatter.format_token(&self.l_tick_token()?)?,
-			token("TODO"),
+			elements,
 			formatter.format_token(&self.r_tick_token()?)?
 		])
 	}
 }
+
+impl ToFormatElement for AnyTemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			AnyTemplateElement::TemplateChunkElement(chunk) => chunk.to_format_element(formatter),
+			AnyTemplateElement::TemplateElement(element) => element.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TemplateChunkElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// The content of a template is printed as is
+		formatter.format_token(&self.template_chunk_token()?)
+	}
+}
+
+impl ToFormatElement for TemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.dollar_curly_token()?)?,
+			formatter.format_node(self.expression()?)?,
+			formatter.format_token(&self.r_curly_token()?)?,
+		])
+	}
+}
@@ -0,0 +1,13 @@
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::TsAccessibility;
+
+impl ToFormatElement for TsAccessibility {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let modifier = self
+			.private_token()
+			.or_else(|_| self.protected_token())
+			.or_else(|_| self.public_token())?;
+
+		formatter.format_token(&modifier)
+	}
+}
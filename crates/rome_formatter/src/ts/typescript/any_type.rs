@@ -0,0 +1,41 @@
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::TsType;
+
+impl ToFormatElement for TsType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsType::TsAny(ty) => ty.to_format_element(formatter),
+			TsType::TsArray(ty) => ty.to_format_element(formatter),
+			TsType::TsBigint(ty) => ty.to_format_element(formatter),
+			TsType::TsBoolean(ty) => ty.to_format_element(formatter),
+			TsType::TsConditionalType(ty) => ty.to_format_element(formatter),
+			TsType::TsConstructorType(ty) => ty.to_format_element(formatter),
+			TsType::TsFnType(ty) => ty.to_format_element(formatter),
+			TsType::TsImport(ty) => ty.to_format_element(formatter),
+			TsType::TsIndexedArray(ty) => ty.to_format_element(formatter),
+			TsType::TsInfer(ty) => ty.to_format_element(formatter),
+			TsType::TsIntersection(ty) => ty.to_format_element(formatter),
+			TsType::TsLiteral(ty) => ty.to_format_element(formatter),
+			TsType::TsMappedType(ty) => ty.to_format_element(formatter),
+			TsType::TsNever(ty) => ty.to_format_element(formatter),
+			TsType::TsNull(ty) => ty.to_format_element(formatter),
+			TsType::TsNumber(ty) => ty.to_format_element(formatter),
+			TsType::TsObject(ty) => ty.to_format_element(formatter),
+			TsType::TsObjectType(ty) => ty.to_format_element(formatter),
+			TsType::TsParen(ty) => ty.to_format_element(formatter),
+			TsType::TsPredicate(ty) => ty.to_format_element(formatter),
+			TsType::TsString(ty) => ty.to_format_element(formatter),
+			TsType::TsSymbol(ty) => ty.to_format_element(formatter),
+			TsType::TsTemplate(ty) => ty.to_format_element(formatter),
+			TsType::TsThis(ty) => ty.to_format_element(formatter),
+			TsType::TsTuple(ty) => ty.to_format_element(formatter),
+			TsType::TsTypeOperator(ty) => ty.to_format_element(formatter),
+			TsType::TsTypeQuery(ty) => ty.to_format_element(formatter),
+			TsType::TsTypeRef(ty) => ty.to_format_element(formatter),
+			TsType::TsUndefined(ty) => ty.to_format_element(formatter),
+			TsType::TsUnion(ty) => ty.to_format_element(formatter),
+			TsType::TsUnknown(ty) => ty.to_format_element(formatter),
+			TsType::TsVoid(ty) => ty.to_format_element(formatter),
+		}
+	}
+}
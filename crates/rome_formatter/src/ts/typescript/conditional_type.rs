@@ -0,0 +1,35 @@
+use crate::{
+	format_elements, group_elements, indent, soft_line_break_or_space, space_token, FormatElement,
+	FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsConditionalType, TsExtends};
+
+impl ToFormatElement for TsConditionalType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(group_elements(format_elements![
+			formatter.format_node(self.check_type()?)?,
+			space_token(),
+			formatter.format_node(self.extends()?)?,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				formatter.format_token(&self.question_mark_token()?)?,
+				space_token(),
+				formatter.format_node(self.true_type()?)?,
+				soft_line_break_or_space(),
+				formatter.format_token(&self.colon_token()?)?,
+				space_token(),
+				formatter.format_node(self.false_type()?)?,
+			])
+		]))
+	}
+}
+
+impl ToFormatElement for TsExtends {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.extends_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+		])
+	}
+}
@@ -0,0 +1,75 @@
+use crate::ts::expressions::literal_expression::format_string_literal_token;
+use crate::{
+	block_indent, empty_element, format_elements, group_elements, hard_line_break, join_elements,
+	space_token, token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{AstSeparatedList, TsEnum, TsEnumMember};
+use rslint_parser::SyntaxKind;
+
+impl ToFormatElement for TsEnum {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = if let Some(declare) = self.declare_token() {
+			format_elements![formatter.format_token(&declare)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let const_token = if let Some(const_token) = self.const_token() {
+			format_elements![formatter.format_token(&const_token)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		// Every member goes on its own line, followed by a comma, even the last one
+		let mut members = Vec::with_capacity(self.members().len());
+		for element in self.members().elements() {
+			let separator = if let Some(separator) = element.trailing_separator()? {
+				formatter.format_token(&separator)?
+			} else {
+				token(",")
+			};
+
+			members.push(format_elements![
+				formatter.format_node(element.node()?)?,
+				separator
+			]);
+		}
+
+		Ok(format_elements![
+			declare,
+			const_token,
+			formatter.format_token(&self.enum_token()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+			space_token(),
+			group_elements(format_elements![
+				formatter.format_token(&self.l_curly_token()?)?,
+				block_indent(join_elements(hard_line_break(), members)),
+				formatter.format_token(&self.r_curly_token()?)?
+			])
+		])
+	}
+}
+
+impl ToFormatElement for TsEnumMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let name = self.name()?;
+		let name = if name.kind() == SyntaxKind::JS_STRING_LITERAL {
+			format_string_literal_token(&name, formatter)
+		} else {
+			formatter.format_token(&name)?
+		};
+
+		let value = match (self.eq_token(), self.value()) {
+			(Some(eq), Some(value)) => format_elements![
+				space_token(),
+				formatter.format_token(&eq)?,
+				space_token(),
+				formatter.format_node(value)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![name, value])
+	}
+}
@@ -0,0 +1,82 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{TsConstructorType, TsFnType, TsPredicate, TsThisOrMore};
+
+impl ToFormatElement for TsFnType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			type_params,
+			formatter.format_node(self.params()?)?,
+			space_token(),
+			formatter.format_token(&self.fat_arrow_token()?)?,
+			space_token(),
+			formatter.format_node(self.return_type()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsConstructorType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			type_params,
+			formatter.format_node(self.params()?)?,
+			space_token(),
+			formatter.format_token(&self.fat_arrow_token()?)?,
+			space_token(),
+			formatter.format_node(self.return_type()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsPredicate {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let asserts = if let Some(asserts) = self.asserts_token() {
+			format_elements![formatter.format_token(&asserts)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let parameter_name = if let Some(parameter_name) = self.parameter_name() {
+			formatter.format_node(parameter_name)?
+		} else {
+			empty_element()
+		};
+
+		let ty = match (self.is_token(), self.ty()) {
+			(Some(is), Some(ty)) => format_elements![
+				space_token(),
+				formatter.format_token(&is)?,
+				space_token(),
+				formatter.format_node(ty)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![asserts, parameter_name, ty])
+	}
+}
+
+impl ToFormatElement for TsThisOrMore {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsThisOrMore::TsThis(this) => this.to_format_element(formatter),
+			TsThisOrMore::TsTypeName(name) => name.to_format_element(formatter),
+		}
+	}
+}
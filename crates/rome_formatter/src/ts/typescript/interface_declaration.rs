@@ -0,0 +1,68 @@
+use crate::{
+	block_indent, empty_element, format_elements, group_elements, hard_line_break, join_elements,
+	space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::TsInterfaceDecl;
+
+impl ToFormatElement for TsInterfaceDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = if let Some(declare) = self.declare_token() {
+			format_elements![formatter.format_token(&declare)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		let mut commas = self.extends_comma_tokens();
+		let mut extends = vec![];
+		for interface in self.extends() {
+			let interface = formatter.format_node(interface)?;
+			match commas.next() {
+				Some(comma) => {
+					extends.push(format_elements![interface, formatter.format_token(&comma)?])
+				}
+				None => extends.push(interface),
+			}
+		}
+
+		let extends = if let Some(extends_token) = self.extends_token() {
+			format_elements![
+				space_token(),
+				formatter.format_token(&extends_token)?,
+				space_token(),
+				join_elements(space_token(), extends)
+			]
+		} else {
+			empty_element()
+		};
+
+		let mut members = vec![];
+		for member in self.members() {
+			let separator = member.separator_token();
+			members.push(format_elements![
+				formatter.format_node(member)?,
+				formatter.format_or_create_token(separator, ";")
+			]);
+		}
+
+		Ok(format_elements![
+			declare,
+			formatter.format_token(&self.interface_token()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+			type_params,
+			extends,
+			space_token(),
+			group_elements(format_elements![
+				formatter.format_token(&self.l_curly_token()?)?,
+				block_indent(join_elements(hard_line_break(), members)),
+				formatter.format_token(&self.r_curly_token()?)?
+			])
+		])
+	}
+}
@@ -0,0 +1,83 @@
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::{
+	TsAny, TsBigint, TsBoolean, TsNever, TsNull, TsNumber, TsObject, TsString, TsSymbol, TsThis,
+	TsUndefined, TsUnknown, TsVoid,
+};
+
+impl ToFormatElement for TsAny {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.any_token()?)
+	}
+}
+
+impl ToFormatElement for TsUnknown {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.unknown_token()?)
+	}
+}
+
+impl ToFormatElement for TsNumber {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.number_token()?)
+	}
+}
+
+impl ToFormatElement for TsObject {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.object_token()?)
+	}
+}
+
+impl ToFormatElement for TsBoolean {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.boolean_token()?)
+	}
+}
+
+impl ToFormatElement for TsBigint {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.bigint_token()?)
+	}
+}
+
+impl ToFormatElement for TsString {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.string_token()?)
+	}
+}
+
+impl ToFormatElement for TsSymbol {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.symbol_token()?)
+	}
+}
+
+impl ToFormatElement for TsUndefined {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.undefined_token()?)
+	}
+}
+
+impl ToFormatElement for TsNever {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.never_token()?)
+	}
+}
+
+impl ToFormatElement for TsVoid {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.void_token()?)
+	}
+}
+
+impl ToFormatElement for TsNull {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.null_token()?)
+	}
+}
+
+impl ToFormatElement for TsThis {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.this_token()?)
+	}
+}
@@ -0,0 +1,93 @@
+use crate::{
+	empty_element, format_elements, group_elements, if_group_breaks, indent,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{TsMappedType, TsMappedTypeParam, TsMappedTypeReadonly};
+
+impl ToFormatElement for TsMappedType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let readonly = if let Some(readonly) = self.readonly_modifier() {
+			format_elements![formatter.format_node(readonly)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let optional_modifier = if let Some(modifier) = self.optional_modifier_token() {
+			formatter.format_token(&modifier)?
+		} else {
+			empty_element()
+		};
+
+		let question_mark = if let Some(question_mark) = self.question_mark_token() {
+			formatter.format_token(&question_mark)?
+		} else {
+			empty_element()
+		};
+
+		// The `;` is only printed if the type is broken over multiple lines
+		let semicolon = if_group_breaks(token(";"));
+		let semicolon = match self.semicolon_token() {
+			Some(semicolon_token) => formatter.format_replaced(&semicolon_token, semicolon),
+			None => semicolon,
+		};
+
+		Ok(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				readonly,
+				formatter.format_node(self.param()?)?,
+				optional_modifier,
+				question_mark,
+				formatter.format_token(&self.colon_token()?)?,
+				space_token(),
+				formatter.format_node(self.ty()?)?,
+				semicolon,
+			]),
+			soft_line_break_or_space(),
+			formatter.format_token(&self.r_curly_token()?)?,
+		]))
+	}
+}
+
+impl ToFormatElement for TsMappedTypeReadonly {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let operator = if let Some(operator) = self.operator_token() {
+			formatter.format_token(&operator)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			operator,
+			formatter.format_token(&self.readonly_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsMappedTypeParam {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// `as Alias` remaps the keys of the mapped type
+		let alias = match (self.as_token(), self.alias()) {
+			(Some(as_token), Some(alias)) => format_elements![
+				space_token(),
+				formatter.format_token(&as_token)?,
+				space_token(),
+				formatter.format_node(alias)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_token(&self.name_token()?)?,
+			space_token(),
+			formatter.format_token(&self.in_token()?)?,
+			space_token(),
+			formatter.format_node(self.keys()?)?,
+			alias,
+			formatter.format_token(&self.r_brack_token()?)?,
+		])
+	}
+}
@@ -0,0 +1,17 @@
+mod accessibility;
+mod any_type;
+mod conditional_type;
+mod enum_declaration;
+mod fn_type;
+mod interface_declaration;
+mod keyword_types;
+mod mapped_type;
+mod object_type;
+mod tuple_and_array;
+mod type_alias_declaration;
+mod type_annotation;
+mod type_expressions;
+mod type_params;
+mod type_reference;
+mod typed_parameter;
+mod union_and_intersection;
@@ -0,0 +1,205 @@
+use crate::{
+	concat_elements, empty_element, format_elements, group_elements, if_group_breaks, indent,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{
+	AstNodeList, TsAnyPropertySignatureName, TsCallSignatureDecl, TsConstructSignatureDecl,
+	TsIndexSignature, TsMethodSignature, TsObjectType, TsPropertySignature, TsType, TsTypeElement,
+};
+use rslint_parser::SyntaxToken;
+
+impl ToFormatElement for TsObjectType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let l_curly = formatter.format_token(&self.l_curly_token()?)?;
+		let r_curly = formatter.format_token(&self.r_curly_token()?)?;
+
+		if self.members().len() == 0 {
+			return Ok(format_elements![l_curly, r_curly]);
+		}
+
+		// The members are always separated by `;`, the separator of the last member is only
+		// printed if the type is broken over multiple lines
+		let members_count = self.members().len();
+		let mut members = Vec::with_capacity(members_count);
+		for (index, member) in self.members().iter().enumerate() {
+			let separator = member.separator_token();
+			let member = formatter.format_node(member)?;
+
+			if index == members_count - 1 {
+				let last_separator = if_group_breaks(token(";"));
+				members.push(format_elements![
+					member,
+					match separator {
+						Some(separator) => formatter.format_replaced(&separator, last_separator),
+						None => last_separator,
+					}
+				]);
+			} else {
+				members.push(format_elements![
+					member,
+					formatter.format_or_create_token(separator, ";"),
+					soft_line_break_or_space()
+				]);
+			}
+		}
+
+		let members = concat_elements(members);
+		Ok(group_elements(format_elements![
+			l_curly,
+			indent(format_elements![soft_line_break_or_space(), members]),
+			soft_line_break_or_space(),
+			r_curly,
+		]))
+	}
+}
+
+impl ToFormatElement for TsTypeElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsTypeElement::TsCallSignatureDecl(signature) => signature.to_format_element(formatter),
+			TsTypeElement::TsConstructSignatureDecl(signature) => {
+				signature.to_format_element(formatter)
+			}
+			TsTypeElement::TsIndexSignature(signature) => signature.to_format_element(formatter),
+			TsTypeElement::TsMethodSignature(signature) => signature.to_format_element(formatter),
+			TsTypeElement::TsPropertySignature(signature) => signature.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsPropertySignature {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let readonly = if let Some(readonly) = self.readonly_token() {
+			format_elements![formatter.format_token(&readonly)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let question_mark = if let Some(question_mark) = self.question_mark_token() {
+			formatter.format_token(&question_mark)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			readonly,
+			formatter.format_node(self.name()?)?,
+			question_mark,
+			format_type_annotation(self.colon_token(), self.ty(), formatter)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsMethodSignature {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let question_mark = if let Some(question_mark) = self.question_mark_token() {
+			formatter.format_token(&question_mark)?
+		} else {
+			empty_element()
+		};
+
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.name()?)?,
+			question_mark,
+			type_params,
+			formatter.format_node(self.parameters()?)?,
+			format_type_annotation(self.colon_token(), self.return_type(), formatter)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsCallSignatureDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			type_params,
+			formatter.format_node(self.parameters()?)?,
+			format_type_annotation(self.colon_token(), self.return_type(), formatter)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsConstructSignatureDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			type_params,
+			formatter.format_node(self.parameters()?)?,
+			format_type_annotation(self.colon_token(), self.return_type(), formatter)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsIndexSignature {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let readonly = if let Some(readonly) = self.readonly_token() {
+			format_elements![formatter.format_token(&readonly)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			readonly,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(self.parameter()?)?,
+			formatter.format_node(self.parameter_type()?)?,
+			formatter.format_token(&self.r_brack_token()?)?,
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsAnyPropertySignatureName {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsAnyPropertySignatureName::JsComputedMemberName(name) => {
+				name.to_format_element(formatter)
+			}
+			TsAnyPropertySignatureName::JsName(name) => name.to_format_element(formatter),
+			TsAnyPropertySignatureName::JsNumberLiteralExpression(name) => {
+				name.to_format_element(formatter)
+			}
+			TsAnyPropertySignatureName::JsStringLiteralExpression(name) => {
+				name.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+/// Formats the `: type` of a signature member, the signature members store the colon
+/// and the type separately because both are optional
+fn format_type_annotation(
+	colon: Option<SyntaxToken>,
+	ty: Option<TsType>,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	Ok(match (colon, ty) {
+		(Some(colon), Some(ty)) => format_elements![
+			formatter.format_token(&colon)?,
+			space_token(),
+			formatter.format_node(ty)?
+		],
+		_ => empty_element(),
+	})
+}
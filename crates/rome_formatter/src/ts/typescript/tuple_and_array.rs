@@ -0,0 +1,100 @@
+use crate::{
+	concat_elements, empty_element, format_elements, group_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsArray, TsIndexedArray, TsTuple, TsTupleElement};
+
+impl ToFormatElement for TsArray {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.element_type()?)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_token(&self.r_brack_token()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsIndexedArray {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.object_type()?)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(self.index_type()?)?,
+			formatter.format_token(&self.r_brack_token()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsTuple {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let elements_count = self.elements().count();
+		let mut commas = self.comma_tokens();
+		let mut elements = Vec::with_capacity(elements_count);
+
+		for (index, element) in self.elements().enumerate() {
+			elements.push(formatter.format_node(element)?);
+
+			// The trailing comma is removed
+			let comma = commas.next();
+			if index == elements_count - 1 {
+				if let Some(comma) = comma {
+					elements.push(formatter.format_replaced(&comma, empty_element()));
+				}
+			} else {
+				elements.push(format_elements![
+					formatter.format_or_create_token(comma, ","),
+					soft_line_break_or_space()
+				]);
+			}
+		}
+
+		let elements = concat_elements(elements);
+		Ok(group_elements(format_elements![
+			formatter.format_token(&self.l_brack_token()?)?,
+			soft_indent(elements),
+			formatter.format_token(&self.r_brack_token()?)?,
+		]))
+	}
+}
+
+impl ToFormatElement for TsTupleElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let dotdotdot = if let Some(token) = self.dotdotdot_token() {
+			formatter.format_token(&token)?
+		} else {
+			empty_element()
+		};
+
+		// `name?: type`
+		let name = if let Some(name) = self.name() {
+			let question_mark = if let Some(token) = self.name_question_mark_token() {
+				formatter.format_token(&token)?
+			} else {
+				empty_element()
+			};
+
+			format_elements![
+				formatter.format_node(name)?,
+				question_mark,
+				formatter.format_or_create_token(self.colon_token(), ":"),
+				space_token()
+			]
+		} else {
+			empty_element()
+		};
+
+		// `type?`
+		let optional = if let Some(token) = self.optional_token() {
+			formatter.format_token(&token)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			dotdotdot,
+			name,
+			formatter.format_node(self.ty()?)?,
+			optional
+		])
+	}
+}
@@ -0,0 +1,34 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::TsTypeAliasDecl;
+
+impl ToFormatElement for TsTypeAliasDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = if let Some(declare) = self.declare_token() {
+			format_elements![formatter.format_token(&declare)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let type_params = if let Some(type_params) = self.type_params() {
+			formatter.format_node(type_params)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			declare,
+			formatter.format_token(&self.type_token()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+			type_params,
+			space_token(),
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+			formatter.format_or_create_token(self.semicolon_token(), ";")
+		])
+	}
+}
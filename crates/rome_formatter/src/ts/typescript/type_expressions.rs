@@ -0,0 +1,133 @@
+use crate::ts::expressions::literal_expression::format_string_literal_token;
+use crate::{
+	concat_elements, empty_element, format_elements, space_token, FormatElement, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	TsAnyTemplateElement, TsImport, TsInfer, TsLiteral, TsParen, TsTemplate, TsTemplateElement,
+	TsTypeOperator, TsTypeQuery, TsTypeQueryExpr,
+};
+
+impl ToFormatElement for TsParen {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.r_paren_token()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsLiteral {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let minus = if let Some(minus) = self.minus_token() {
+			formatter.format_token(&minus)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			minus,
+			formatter.format_node(self.literal()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeOperator {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.operator_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeQuery {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.typeof_token()?)?,
+			space_token(),
+			formatter.format_node(self.expr()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeQueryExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsTypeQueryExpr::TsEntityName(name) => name.to_format_element(formatter),
+			TsTypeQueryExpr::TsImport(import) => import.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsImport {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let qualifier = match (self.dot_token(), self.qualifier()) {
+			(Some(dot), Some(qualifier)) => format_elements![
+				formatter.format_token(&dot)?,
+				formatter.format_node(qualifier)?
+			],
+			_ => empty_element(),
+		};
+
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.import_token()?)?,
+			formatter.format_token(&self.l_paren_token()?)?,
+			format_string_literal_token(&self.arg_token()?, formatter),
+			formatter.format_token(&self.r_paren_token()?)?,
+			qualifier,
+			type_args
+		])
+	}
+}
+
+impl ToFormatElement for TsInfer {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.infer_token()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsTemplate {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let elements = concat_elements(formatter.format_nodes(self.elements())?);
+
+		Ok(format_elements![
+			formatter.format_token(&self.l_tick_token()?)?,
+			elements,
+			formatter.format_token(&self.r_tick_token()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsAnyTemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsAnyTemplateElement::TemplateChunkElement(chunk) => chunk.to_format_element(formatter),
+			TsAnyTemplateElement::TsTemplateElement(element) => {
+				element.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for TsTemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.dollar_curly_token()?)?,
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.r_curly_token()?)?,
+		])
+	}
+}
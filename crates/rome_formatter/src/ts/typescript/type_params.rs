@@ -0,0 +1,71 @@
+use crate::{
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsConstraint, TsDefault, TsTypeArgs, TsTypeParam, TsTypeParams};
+
+impl ToFormatElement for TsTypeParams {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let params = formatter.format_separated(self.params())?;
+
+		Ok(group_elements(format_elements![
+			formatter.format_token(&self.l_angle_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), params)),
+			formatter.format_token(&self.r_angle_token()?)?,
+		]))
+	}
+}
+
+impl ToFormatElement for TsTypeArgs {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let args = formatter.format_separated(self.args())?;
+
+		Ok(group_elements(format_elements![
+			formatter.format_token(&self.l_angle_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), args)),
+			formatter.format_token(&self.r_angle_token()?)?,
+		]))
+	}
+}
+
+impl ToFormatElement for TsTypeParam {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let constraint = if let Some(constraint) = self.constraint() {
+			format_elements![space_token(), formatter.format_node(constraint)?]
+		} else {
+			empty_element()
+		};
+
+		let default = if let Some(default) = self.default() {
+			format_elements![space_token(), formatter.format_node(default)?]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.name_token()?)?,
+			constraint,
+			default
+		])
+	}
+}
+
+impl ToFormatElement for TsConstraint {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.extends_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for TsDefault {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+		])
+	}
+}
@@ -0,0 +1,61 @@
+use crate::{
+	empty_element, format_elements, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	TsEntityName, TsExprWithTypeArgs, TsQualifiedPath, TsTypeName, TsTypeRef,
+};
+
+impl ToFormatElement for TsTypeRef {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.name()?)?,
+			type_args
+		])
+	}
+}
+
+impl ToFormatElement for TsExprWithTypeArgs {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = if let Some(type_args) = self.type_args() {
+			formatter.format_node(type_args)?
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.item()?)?,
+			type_args
+		])
+	}
+}
+
+impl ToFormatElement for TsEntityName {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsEntityName::TsQualifiedPath(path) => path.to_format_element(formatter),
+			TsEntityName::TsTypeName(name) => name.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsTypeName {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.name_token()?)
+	}
+}
+
+impl ToFormatElement for TsQualifiedPath {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.lhs()?)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_node(self.rhs()?)?,
+		])
+	}
+}
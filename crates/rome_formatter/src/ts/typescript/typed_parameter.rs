@@ -0,0 +1,34 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::TsTypedParameter;
+
+impl ToFormatElement for TsTypedParameter {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let question_mark = if let Some(question_mark) = self.question_mark_token() {
+			formatter.format_token(&question_mark)?
+		} else {
+			empty_element()
+		};
+
+		let type_annotation = if let Some(type_annotation) = self.type_annotation() {
+			formatter.format_node(type_annotation)?
+		} else {
+			empty_element()
+		};
+
+		let initializer = if let Some(initializer) = self.initializer() {
+			format_elements![space_token(), formatter.format_node(initializer)?]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.binding()?)?,
+			question_mark,
+			type_annotation,
+			initializer
+		])
+	}
+}
@@ -0,0 +1,61 @@
+use crate::{
+	concat_elements, format_elements, group_elements, if_group_breaks, indent, soft_line_break,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{AstSeparatedList, TsIntersection, TsUnion};
+
+impl ToFormatElement for TsUnion {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let mut variants = Vec::with_capacity(self.types().len());
+
+		for element in self.types().elements() {
+			variants.push(formatter.format_node(element.node()?)?);
+
+			if let Some(separator) = element.trailing_separator()? {
+				variants.push(format_elements![
+					soft_line_break_or_space(),
+					formatter.format_token(&separator)?,
+					space_token()
+				]);
+			}
+		}
+
+		// When the union breaks, every variant goes on its own line, including the first one:
+		//
+		// type A =
+		// 	| "a"
+		// 	| "b";
+		let leading_separator = if_group_breaks(format_elements![token("|"), space_token()]);
+		let leading_separator = match self.leading_separator_token() {
+			Some(separator) => formatter.format_replaced(&separator, leading_separator),
+			None => leading_separator,
+		};
+
+		Ok(group_elements(indent(format_elements![
+			soft_line_break(),
+			leading_separator,
+			concat_elements(variants)
+		])))
+	}
+}
+
+impl ToFormatElement for TsIntersection {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let mut elements = Vec::with_capacity(self.types().len());
+
+		for element in self.types().elements() {
+			elements.push(formatter.format_node(element.node()?)?);
+
+			if let Some(separator) = element.trailing_separator()? {
+				elements.push(format_elements![
+					space_token(),
+					formatter.format_token(&separator)?,
+					soft_line_break_or_space()
+				]);
+			}
+		}
+
+		Ok(group_elements(indent(concat_elements(elements))))
+	}
+}
@@ -10,4 +10,14 @@ mod formatter {
 		use crate::spec_test;
 		tests_macros::gen_tests! {"tests/specs/js/**/**.js", spec_test::run}
 	}
+
+	mod module {
+		use crate::spec_test;
+		tests_macros::gen_tests! {"tests/specs/js/module/*.mjs", spec_test::run}
+	}
+
+	mod ts {
+		use crate::spec_test;
+		tests_macros::gen_tests! {"tests/specs/ts/**/*.ts", spec_test::run}
+	}
 }
@@ -0,0 +1,5 @@
+let a = [1,,];
+let b = [,];
+let c = [1, , 2];
+let d = [1, 2,];
+let e = [aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccc, , ];
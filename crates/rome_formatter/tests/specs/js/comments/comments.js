@@ -0,0 +1,38 @@
+// leading comment of the file
+let a = 1; // trailing line comment
+let /* before the name */ b = /* before the value */ 2;
+
+function empty() {
+  // dangling comment of an empty block
+}
+
+call(
+  first, // first argument
+  second,
+);
+
+const list = [
+  1, // one
+  2 /* two */,
+];
+
+if (a) {
+  b();
+}
+// before the else
+else {
+  c();
+}
+
+const emptyObject = {
+  // dangling comment of an empty object
+};
+
+const emptyArray = [
+  // dangling comment of an empty array
+];
+
+/* block comment
+   on several lines */
+foo();
+// comment at the end of the file
@@ -0,0 +1,26 @@
+/**
+ * Adds two numbers.
+ *
+ * @param {number} a
+ * @param {number} b
+ * @returns {number}
+ */
+function add(a, b) {
+  return a + b;
+}
+
+/** The points of the plane */
+class Point {
+    /**
+       * Moves the point.
+       * @param {number} dx
+       */
+    move(dx) {
+        this.x += dx;
+    }
+}
+
+/**
+ * The origin
+ */
+export const origin = new Point();
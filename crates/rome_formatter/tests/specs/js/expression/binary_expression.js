@@ -21,3 +21,13 @@ a  |   b
 a  ^   b
 a  in  b
 a  instanceof b
+const sum = firstOperandOfTheSum + secondOperandOfTheSum + thirdOperandOfTheSum + fourth
+const mixed = firstOperandOfTheSum + secondOperand * thirdOperand - fourthOperandOfTheSum / five
+const product = firstFactorOfTheProduct * secondFactorOfTheProduct / thirdFactorOfTheProduct * 4
+someVeryLongVariableName = firstOperandOfTheSum + secondOperandOfTheSum + thirdOperandOfTheSum
+callFunction(firstOperandOfTheSum + secondOperandOfTheSum + thirdOperandOfTheSum + fourthOperand)
+x = - -a
+x = + +a
+x = - --a
+x = + ++a
+x = -+a
@@ -2,3 +2,8 @@ a  ?  b  :  c
 d
 	? (e + f)
 	: (g + h)
+const animal = isBird ? "the animal is a bird" : isCat ? "the animal is a cat" : "the animal is a dog"
+const message = i % 3 === 0 && i % 5 === 0 ? "fizzbuzz" : i % 3 === 0 ? "fizz" : i % 5 === 0 ? "buzz" : String(i)
+const nested = someCondition ? otherCondition ? "first result value" : "second result value" : "third result value"
+const access = user.isAdministrator || user.permissions.includes("write") || user.owner === document.owner ? "granted" : "denied"
+const check = (user) => user.isAdministrator || user.permissions.includes("write") || user.owner === id ? "yes" : "no"
@@ -1,3 +1,7 @@
 x ?? y
 x || y
 x && y
+const isValid = firstConditionToCheck && secondConditionToCheck && thirdConditionToCheck
+const isAny = firstConditionToCheck && secondConditionToCheck || thirdConditionToCheck && fourth
+if (firstConditionToCheck && secondConditionToCheck && thirdConditionToCheck && fourthCondition) {}
+const value = firstOptionalValue ?? secondOptionalValue ?? thirdOptionalValue ?? fourthOptionalValue
@@ -0,0 +1,9 @@
+let a = `foo ${ bar } baz`;
+let b = tag`a${ b }c`;
+let c = ``;
+let d = `line1
+line2 ${ x  +  y }`;
+let e = String.raw  `\n` // raw
+const url = import . meta.url;
+let r = /ab+c/gi;
+[a, , b] = c;
@@ -0,0 +1,3 @@
+foo(a,, b);
+let b = ;
+let c = 1;
@@ -0,0 +1,6 @@
+class A {
+  x  =  1
+  1 + 2
+  y  =  2
+}
+let   o = { a:   1, b: }
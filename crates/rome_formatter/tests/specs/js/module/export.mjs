@@ -0,0 +1,7 @@
+export const a = 1
+export function f() {}
+export class A {}
+export default function g() {}
+export { a as b, f }
+export * from 'all'
+export * as ns from "ns"
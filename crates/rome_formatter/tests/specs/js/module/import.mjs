@@ -0,0 +1,7 @@
+import   'side-effect'
+import a from 'a'
+import * as ns from "ns"
+import b, { c, d as e } from 'b'
+import { aaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccc, ddddddddd } from 'long'
+import {} from 'empty'
+import json from './data.json' assert { type: 'json' }
@@ -0,0 +1,12 @@
+let a: string = "a"
+let b!: number
+const c: Array<string> = []
+function d<T>(a: T, b?: string, c: number = 1, ...rest: any[]): T { return a }
+const e = <T,>(a: T): T => a
+const f = function (a: string): void {}
+function g(x: unknown): x is string { return true }
+let h: (a: string) => void
+let i: new (a: string) => Foo
+let j = foo<string>(a)
+let k = new Map<string, number>()
+let l = a!
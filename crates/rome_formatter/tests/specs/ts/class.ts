@@ -0,0 +1,19 @@
+class A<T> extends B<T> implements C, D<T> {
+	private a: string;
+	public static readonly b?: number = 1
+	declare c: string
+	d!: number
+	protected e<U>(a: U): T { return this.t }
+	get f(): string { return "" }
+	[key: string]: any
+}
+let g = class<T> {}
+class H {
+	constructor(x: number, y = 1, z?: string, w: string = "w") {}
+}
+class I {
+	constructor(public a: string, private b?: number, protected readonly c = 1, readonly d: boolean, public e) {}
+}
+class J {
+	private constructor(readonly [first, second]: string[] = []) {}
+}
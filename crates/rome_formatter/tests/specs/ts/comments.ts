@@ -0,0 +1,16 @@
+interface Shape {
+  // the name of the shape
+  name: string; // required
+  /** The area, in square units */
+  area?: number;
+}
+
+type Pair = [
+  string, // key
+  number, // value
+];
+
+type Kind =
+  // circle
+  | "circle"
+  | "square"; // square
@@ -0,0 +1,6 @@
+enum A { B, C = 2, "d" = 3 }
+const enum E {
+	F = 1 << 0,
+	G = 1 << 1
+}
+declare enum H {}
@@ -0,0 +1,11 @@
+interface A {}
+interface B<T> extends A, C<T> {
+	readonly a: string,
+	b?: number
+	c(x: number): void;
+	d?<U>(...args: U[]): U
+	[key: string]: any
+	(): void
+	new (x: string): B<T>
+}
+declare interface C { a: string }
@@ -0,0 +1,6 @@
+type A<T> = { [K in keyof T]: T[K] }
+type B<T> = { readonly [K in keyof T]?: T[K] }
+type C<T> = { -readonly [K in keyof T]-?: T[K] }
+type D<T> = { [K in keyof T as `get${K}`]: () => T[K] }
+type E<T> = T extends string ? "string" : "other"
+type F<T> = T extends Array<infer U> ? U : never
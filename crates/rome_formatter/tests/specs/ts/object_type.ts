@@ -0,0 +1,4 @@
+type A = {}
+type B = { a: string, b?: number; readonly c: boolean }
+type C = { aaaaaaaaaaaaaaa: string; bbbbbbbbbbbbbbbbbbbbbbbb: number; cccccccccccccccccc(): void }
+type D<T> = T extends ReadonlyArray<infer Element> ? ElementTypeeeeeeeeeeeeeeeeeee<Element> : never
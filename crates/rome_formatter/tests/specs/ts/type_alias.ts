@@ -0,0 +1,10 @@
+type   A = string
+type B<T> = Array<T>;
+declare type C = typeof a.b
+type D = keyof   T
+type E<T extends object = {}> = T[keyof T]
+type F = [a: string, b?: number, ...rest: boolean[]]
+type G = `prefix-${string}`
+type H = -1 | "a" | true | null | undefined | void
+type I = readonly string[]
+type J = import("./module").Foo<string>
@@ -0,0 +1,5 @@
+type A = string | number
+type B = | "a" | "b"
+type C = { a: string } & { b: number }
+type D = "aaaaaaaaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbbbbbbbb" | "cccccccccccccccccccccccc" | "dddd"
+type E = (string | number)[]
@@ -0,0 +1,2 @@
+function f(this: A,   b) {}
+type T = `get${  Name  }` // c
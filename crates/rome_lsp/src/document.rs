@@ -0,0 +1,80 @@
+use lsp_types::TextDocumentContentChangeEvent;
+use rome_core::workspace::Workspace;
+use rslint_errors::file::{FileId, Files};
+use rslint_errors::lsp::{range_to_byte_span, Error};
+
+/// A document opened by the client. Its text is stored in the [Workspace] of the server.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Document {
+	pub(crate) version: i64,
+	pub(crate) file_id: FileId,
+}
+
+impl Document {
+	pub(crate) fn new(version: i64, file_id: FileId) -> Self {
+		Self { version, file_id }
+	}
+
+	/// The text of the document
+	pub(crate) fn text(self, workspace: &Workspace) -> &str {
+		workspace.source(self.file_id).unwrap_or_default()
+	}
+
+	/// Applies a change sent by the client, which replaces either a range of the text or all of it
+	pub(crate) fn apply_change(
+		self,
+		workspace: &mut Workspace,
+		change: TextDocumentContentChangeEvent,
+	) -> Result<(), Error> {
+		let text = match change.range {
+			Some(range) => {
+				let span = range_to_byte_span(workspace, self.file_id, &range)?;
+				let mut text = self.text(workspace).to_string();
+				text.replace_range(span, &change.text);
+				text
+			}
+			None => change.text,
+		};
+		workspace.set_source(self.file_id, text);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::Document;
+	use lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+	use rome_core::workspace::Workspace;
+
+	#[test]
+	fn applies_incremental_changes() {
+		let mut workspace = Workspace::new();
+		workspace.insert("other.js", "let b = 1;\n");
+		let file_id = workspace.insert("index.js", "let a = 1;\nlet é = 2;\n");
+		let document = Document::new(1, file_id);
+
+		// The positions count UTF-16 code units
+		document
+			.apply_change(
+				&mut workspace,
+				TextDocumentContentChangeEvent {
+					range: Some(Range::new(Position::new(1, 8), Position::new(1, 9))),
+					range_length: None,
+					text: "3".to_string(),
+				},
+			)
+			.unwrap();
+		assert_eq!(document.text(&workspace), "let a = 1;\nlet é = 3;\n");
+
+		assert!(document
+			.apply_change(
+				&mut workspace,
+				TextDocumentContentChangeEvent {
+					range: Some(Range::new(Position::new(5, 0), Position::new(5, 1))),
+					range_length: None,
+					text: "".to_string(),
+				},
+			)
+			.is_err());
+	}
+}
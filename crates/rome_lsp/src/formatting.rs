@@ -0,0 +1,150 @@
+use lsp_types::{FormattingOptions, TextEdit};
+use rome_core::configuration::{FormatterConfiguration, IndentKind};
+use rome_core::workspace::Workspace;
+use rome_core::App;
+use rome_formatter::{format_range, format_source_lenient, FormatOptions};
+use rome_path::RomePath;
+use rslint_errors::file::{FileId, Files};
+use rslint_errors::lsp::{byte_span_to_range, text_edit_to_lsp};
+use rslint_parser::{TextRange, TextSize};
+use similar::TextDiff;
+use std::convert::TryFrom;
+use std::ops::Range;
+
+/// Formats a document of the workspace, and returns the edits that turn its text into the
+/// formatted code.
+///
+/// The parts of the document that contain syntax errors are left as they are, the syntax errors
+/// are already published as diagnostics. Returns `None` when the document can't be formatted, see
+/// [document_format_options].
+pub(crate) fn format_document(
+	app: &App,
+	workspace: &Workspace,
+	file_id: FileId,
+	options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+	let (rome_path, options) = document_format_options(app, workspace, file_id, options)?;
+	let text = workspace.source(file_id)?;
+	let formatted = format_source_lenient(&rome_path, text, options).ok()?;
+
+	let edits = changes(text, formatted.code())
+		.into_iter()
+		.filter_map(|(span, new_text)| {
+			Some(TextEdit {
+				range: byte_span_to_range(workspace, file_id, span).ok()?,
+				new_text,
+			})
+		})
+		.collect();
+
+	Some(edits)
+}
+
+/// Formats the part of a document of the workspace that contains `range`, and returns the edits
+/// that turn it into the formatted code. The rest of the document is left as is.
+///
+/// Returns `None` when the document can't be formatted, see [document_format_options].
+pub(crate) fn format_document_range(
+	app: &App,
+	workspace: &Workspace,
+	file_id: FileId,
+	options: &FormattingOptions,
+	range: Range<usize>,
+) -> Option<Vec<TextEdit>> {
+	let (rome_path, options) = document_format_options(app, workspace, file_id, options)?;
+	let text = workspace.source(file_id)?;
+	let range = TextRange::new(
+		TextSize::try_from(range.start).ok()?,
+		TextSize::try_from(range.end).ok()?,
+	);
+	let edit = format_range(&rome_path, text, range, options).ok()?;
+
+	text_edit_to_lsp(workspace, file_id, &edit).ok()
+}
+
+/// Returns the path of a document of the workspace and the options to format it. The settings
+/// of the configuration file take precedence over the options of the editor.
+///
+/// Returns `None` when the document can't be formatted: the configuration ignores it, or its
+/// language isn't supported. The documents that contain syntax errors can't be formatted either.
+fn document_format_options<'app>(
+	app: &'app App,
+	workspace: &Workspace,
+	file_id: FileId,
+	options: &FormattingOptions,
+) -> Option<(RomePath<'app>, FormatOptions)> {
+	let path = workspace.path(file_id)?;
+	let settings = app.file_settings(path);
+	if settings.ignored
+		|| !settings.capabilities.format
+		|| app.configuration_ignore_pattern(path).is_some()
+	{
+		return None;
+	}
+
+	let editor = FormatterConfiguration {
+		indent_style: Some(if options.insert_spaces {
+			IndentKind::Space
+		} else {
+			IndentKind::Tab
+		}),
+		indent_size: u8::try_from(options.tab_size).ok(),
+		line_width: None,
+	};
+	let options = FormatOptions::from(&editor.merge_with(&settings.formatter));
+
+	let rome_path = RomePath::new(path)
+		.deduce_handler(app)
+		.with_file_id(file_id);
+
+	Some((rome_path, options))
+}
+
+/// The groups of lines that changed between the two texts, with their byte range in `original`
+/// and the text that replaces them
+fn changes(original: &str, formatted: &str) -> Vec<(Range<usize>, String)> {
+	let original_lines = line_offsets(original);
+	let formatted_lines = line_offsets(formatted);
+
+	TextDiff::from_lines(original, formatted)
+		.grouped_ops(0)
+		.into_iter()
+		.filter_map(|group| {
+			let (first, last) = (group.first()?, group.last()?);
+			let span =
+				original_lines[first.old_range().start]..original_lines[last.old_range().end];
+			let new_text = &formatted
+				[formatted_lines[first.new_range().start]..formatted_lines[last.new_range().end]];
+			Some((span, new_text.to_string()))
+		})
+		.collect()
+}
+
+/// Returns the byte offsets of the start of each line, followed by the length of the text
+fn line_offsets(text: &str) -> Vec<usize> {
+	let mut offsets = vec![0];
+	offsets.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+	if offsets.last() != Some(&text.len()) {
+		offsets.push(text.len());
+	}
+	offsets
+}
+
+#[cfg(test)]
+mod test {
+	use super::changes;
+
+	#[test]
+	fn groups_the_changed_lines() {
+		let original = "let a  = 1;\nlet b = 2;\nlet c  = 3;\n";
+		let formatted = "let a = 1;\nlet b = 2;\nlet c = 3;\n";
+
+		assert_eq!(
+			changes(original, formatted),
+			[
+				(0..12, "let a = 1;\n".to_string()),
+				(23..35, "let c = 3;\n".to_string()),
+			]
+		);
+	}
+}
@@ -0,0 +1,17 @@
+//! A language server for Rome, implementing the [Language Server Protocol](https://microsoft.github.io/language-server-protocol/).
+//!
+//! The server keeps the documents opened by the client in memory, and supports:
+//! - publishing the syntax errors of a document each time it's opened or changed;
+//! - formatting a document, or a range of a document;
+//! - quick fixes for the diagnostics that have suggestions;
+//! - semantic tokens, for the whole document or a range of it.
+//!
+//! The messages are read from and written to any [std::io::BufRead] and [std::io::Write], which
+//! are the standard input and output when running `rome lsp`.
+mod document;
+mod formatting;
+mod semantic_tokens;
+mod server;
+mod transport;
+
+pub use server::Server;
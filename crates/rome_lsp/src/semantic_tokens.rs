@@ -0,0 +1,125 @@
+use lsp_types::{
+	SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensLegend,
+};
+use rslint_parser::{ClassifiedToken, TokenModifiers, TokenType};
+use std::ops::Range;
+
+/// The legend of the tokens sent to the client, the index of a type or a modifier in the legend
+/// is the value used to encode it
+pub(crate) fn legend() -> SemanticTokensLegend {
+	SemanticTokensLegend {
+		token_types: TokenType::ALL
+			.iter()
+			.map(|token_type| SemanticTokenType::from(token_type.as_str()))
+			.collect(),
+		token_modifiers: TokenModifiers::NAMES
+			.iter()
+			.map(|&modifier| SemanticTokenModifier::from(modifier))
+			.collect(),
+	}
+}
+
+/// Encodes classified tokens relatively to each other, as expected by the protocol.
+///
+/// The tokens spanning multiple lines, like block comments and template chunks, are split into
+/// one token per line, since not all the clients support multiline tokens.
+pub(crate) fn semantic_tokens(text: &str, tokens: &[ClassifiedToken]) -> SemanticTokens {
+	let line_starts = line_starts(text);
+	let mut data = Vec::with_capacity(tokens.len());
+	let (mut previous_line, mut previous_column) = (0, 0);
+
+	for token in tokens {
+		let Range { mut start, end } = Range::<usize>::from(token.range);
+		let mut line = line_starts.partition_point(|&line_start| line_start <= start) - 1;
+
+		while start < end {
+			let line_end = line_starts.get(line + 1).copied().unwrap_or(text.len());
+			let segment_end = end.min(line_end);
+			let segment = text[start..segment_end].trim_end_matches(&['\n', '\r'][..]);
+
+			if !segment.is_empty() {
+				let column = utf16_len(&text[line_starts[line]..start]);
+				let delta_line = line - previous_line;
+				data.push(SemanticToken {
+					delta_line: delta_line as u32,
+					delta_start: if delta_line == 0 {
+						column - previous_column
+					} else {
+						column
+					},
+					length: utf16_len(segment),
+					token_type: token.token_type as u32,
+					token_modifiers_bitset: token.modifiers.bits(),
+				});
+				previous_line = line;
+				previous_column = column;
+			}
+
+			start = segment_end;
+			line += 1;
+		}
+	}
+
+	SemanticTokens {
+		result_id: None,
+		data,
+	}
+}
+
+/// Returns the byte offsets of the start of each line
+fn line_starts(text: &str) -> Vec<usize> {
+	let mut starts = vec![0];
+	starts.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+	starts
+}
+
+fn utf16_len(text: &str) -> u32 {
+	text.chars().map(|c| c.len_utf16() as u32).sum()
+}
+
+#[cfg(test)]
+mod test {
+	use super::semantic_tokens;
+	use lsp_types::SemanticToken;
+	use rslint_parser::{ClassifiedToken, TextRange, TokenModifiers, TokenType};
+
+	fn token(range: std::ops::Range<u32>, token_type: TokenType) -> ClassifiedToken {
+		ClassifiedToken {
+			range: TextRange::new(range.start.into(), range.end.into()),
+			token_type,
+			modifiers: TokenModifiers::empty(),
+		}
+	}
+
+	#[test]
+	fn encodes_relative_utf16_positions() {
+		// The emoji takes four bytes, and two UTF-16 code units
+		let text = "'😀' + a;\n/* a\nb */ b";
+		let tokens = [
+			token(0..6, TokenType::String),
+			token(7..8, TokenType::Operator),
+			token(9..10, TokenType::Variable),
+			token(12..21, TokenType::Comment),
+			token(22..23, TokenType::Variable),
+		];
+
+		let encoded = |delta_line, delta_start, length, token_type: TokenType| SemanticToken {
+			delta_line,
+			delta_start,
+			length,
+			token_type: token_type as u32,
+			token_modifiers_bitset: 0,
+		};
+		assert_eq!(
+			semantic_tokens(text, &tokens).data,
+			[
+				encoded(0, 0, 4, TokenType::String),
+				encoded(0, 5, 1, TokenType::Operator),
+				encoded(0, 2, 1, TokenType::Variable),
+				encoded(1, 0, 4, TokenType::Comment),
+				encoded(1, 0, 4, TokenType::Comment),
+				encoded(0, 5, 1, TokenType::Variable),
+			]
+		);
+	}
+}
@@ -0,0 +1,461 @@
+use crate::document::Document;
+use crate::formatting::{format_document, format_document_range};
+use crate::semantic_tokens::{legend, semantic_tokens};
+use crate::transport::{read_message, write_message};
+use lsp_types::{
+	CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
+	CodeActionProviderCapability, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+	DidOpenTextDocumentParams, DocumentFormattingParams, DocumentRangeFormattingParams,
+	InitializeResult, PublishDiagnosticsParams, SemanticTokensFullOptions, SemanticTokensOptions,
+	SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensServerCapabilities,
+	ServerCapabilities, ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+	WorkDoneProgressOptions,
+};
+use rome_core::file_handlers::Language;
+use rome_core::workspace::Workspace;
+use rome_core::App;
+use rome_path::RomePath;
+use rslint_errors::lsp::{
+	convert_to_lsp_code_actions, convert_to_lsp_diagnostic, fix_all_code_action, range_to_byte_span,
+};
+use rslint_errors::Diagnostic;
+use rslint_parser::{classify, parse_expr, SyntaxNode, TextRange};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// The error codes defined by JSON-RPC and LSP
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// The kind of the code action that applies all the safe fixes of a document
+const FIX_ALL_KIND: &str = "source.fixAll.rome";
+
+/// An error returned to the client in the response of a request
+#[derive(Debug)]
+struct ResponseError {
+	code: i64,
+	message: String,
+}
+
+impl ResponseError {
+	fn new(code: i64, message: impl Into<String>) -> Self {
+		Self {
+			code,
+			message: message.into(),
+		}
+	}
+}
+
+type RequestResult = Result<Value, ResponseError>;
+
+/// The language server of Rome.
+///
+/// The text of the open documents is stored in a [Workspace], which caches their syntax trees.
+///
+/// ```no_run
+/// use rome_core::create_app;
+/// use rome_lsp::Server;
+/// use std::io;
+///
+/// let app = create_app();
+/// let stdin = io::stdin();
+/// let stdout = io::stdout();
+/// Server::new(&app).run(stdin.lock(), stdout.lock()).unwrap();
+/// ```
+pub struct Server<'app> {
+	app: &'app App,
+	workspace: Workspace,
+	documents: HashMap<Url, Document>,
+	shutdown_requested: bool,
+}
+
+impl<'app> Server<'app> {
+	pub fn new(app: &'app App) -> Self {
+		Self {
+			app,
+			workspace: Workspace::new(),
+			documents: HashMap::new(),
+			shutdown_requested: false,
+		}
+	}
+
+	/// Answers the messages read from `reader` by writing to `writer`, until the client sends the
+	/// `exit` notification or closes `reader`.
+	///
+	/// Returns an error if reading or writing a message fails.
+	pub fn run(&mut self, mut reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+		while let Some(body) = read_message(&mut reader)? {
+			let message: Value = match serde_json::from_str(&body) {
+				Ok(message) => message,
+				Err(err) => {
+					let error = ResponseError::new(PARSE_ERROR, err.to_string());
+					write_message(&mut writer, &response(Value::Null, Err(error)))?;
+					continue;
+				}
+			};
+
+			let method = message["method"].as_str().unwrap_or_default();
+			let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+			match message.get("id") {
+				// The responses of the client to requests of the server don't have a method
+				Some(_) if method.is_empty() => {}
+				Some(id) => {
+					let result = self.handle_request(method, params);
+					write_message(&mut writer, &response(id.clone(), result))?;
+				}
+				None if method == "exit" => return Ok(()),
+				None => {
+					for notification in self.handle_notification(method, params) {
+						write_message(&mut writer, &notification)?;
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Whether the client sent the `shutdown` request before exiting
+	pub fn shutdown_requested(&self) -> bool {
+		self.shutdown_requested
+	}
+
+	fn handle_request(&mut self, method: &str, params: Value) -> RequestResult {
+		if self.shutdown_requested {
+			return Err(ResponseError::new(
+				INVALID_REQUEST,
+				"the server is shutting down",
+			));
+		}
+
+		match method {
+			"initialize" => to_value(self.initialize()),
+			"shutdown" => {
+				self.shutdown_requested = true;
+				Ok(Value::Null)
+			}
+			"textDocument/formatting" => {
+				let params: DocumentFormattingParams = from_value(params)?;
+				let uri = params.text_document.uri;
+				let document = self.document(&uri)?;
+				to_value(format_document(
+					self.app,
+					&self.workspace,
+					document.file_id,
+					&params.options,
+				))
+			}
+			"textDocument/rangeFormatting" => {
+				let params: DocumentRangeFormattingParams = from_value(params)?;
+				let uri = params.text_document.uri;
+				let document = self.document(&uri)?;
+				let range = range_to_byte_span(&self.workspace, document.file_id, &params.range)
+					.map_err(|err| ResponseError::new(INVALID_PARAMS, err.to_string()))?;
+				to_value(format_document_range(
+					self.app,
+					&self.workspace,
+					document.file_id,
+					&params.options,
+					range,
+				))
+			}
+			"textDocument/codeAction" => {
+				let params: CodeActionParams = from_value(params)?;
+				to_value(self.code_actions(params)?)
+			}
+			"textDocument/semanticTokens/full" => {
+				let params: SemanticTokensParams = from_value(params)?;
+				let uri = params.text_document.uri;
+				let document = self.document(&uri)?;
+				let root = self.parse(&uri, document).map(|(root, _)| root);
+				to_value(root.map(|root| {
+					semantic_tokens(document.text(&self.workspace), &classify(&root, None))
+				}))
+			}
+			"textDocument/semanticTokens/range" => {
+				let params: SemanticTokensRangeParams = from_value(params)?;
+				let uri = params.text_document.uri;
+				let document = self.document(&uri)?;
+				let range = range_to_byte_span(&self.workspace, document.file_id, &params.range)
+					.map_err(|err| ResponseError::new(INVALID_PARAMS, err.to_string()))?;
+				let range = TextRange::new((range.start as u32).into(), (range.end as u32).into());
+				let root = self.parse(&uri, document).map(|(root, _)| root);
+				to_value(root.map(|root| {
+					semantic_tokens(
+						document.text(&self.workspace),
+						&classify(&root, Some(range)),
+					)
+				}))
+			}
+			_ => Err(ResponseError::new(
+				METHOD_NOT_FOUND,
+				format!("unsupported request {}", method),
+			)),
+		}
+	}
+
+	/// Handles a notification, and returns the notifications to send back to the client
+	fn handle_notification(&mut self, method: &str, params: Value) -> Vec<Value> {
+		// Notifications don't have responses, so invalid ones are ignored
+		match method {
+			"textDocument/didOpen" => {
+				if let Ok(params) = serde_json::from_value::<DidOpenTextDocumentParams>(params) {
+					let document = params.text_document;
+					let uri = document.uri;
+					let file_id = self.workspace.insert(document_path(&uri), document.text);
+					self.documents
+						.insert(uri.clone(), Document::new(document.version, file_id));
+					return vec![self.publish_diagnostics(&uri)];
+				}
+			}
+			"textDocument/didChange" => {
+				if let Ok(params) = serde_json::from_value::<DidChangeTextDocumentParams>(params) {
+					let uri = params.text_document.uri;
+					if let Some(document) = self.documents.get_mut(&uri) {
+						if let Some(version) = params.text_document.version {
+							document.version = version;
+						}
+						for change in params.content_changes {
+							// The document is out of sync with the client, which can't be
+							// recovered from until the client opens the document again
+							if document.apply_change(&mut self.workspace, change).is_err() {
+								self.close_document(&uri);
+								return vec![];
+							}
+						}
+						return vec![self.publish_diagnostics(&uri)];
+					}
+				}
+			}
+			"textDocument/didClose" => {
+				if let Ok(params) = serde_json::from_value::<DidCloseTextDocumentParams>(params) {
+					let uri = params.text_document.uri;
+					self.close_document(&uri);
+					// Clears the diagnostics of the document
+					return vec![notification(
+						"textDocument/publishDiagnostics",
+						PublishDiagnosticsParams::new(uri, vec![], None),
+					)];
+				}
+			}
+			_ => {}
+		}
+
+		vec![]
+	}
+
+	/// Forgets a document, and removes its file from the workspace unless another open document
+	/// has the same path
+	fn close_document(&mut self, uri: &Url) {
+		if let Some(document) = self.documents.remove(uri) {
+			let is_shared = self
+				.documents
+				.values()
+				.any(|other| other.file_id == document.file_id);
+			if !is_shared {
+				self.workspace.remove(document.file_id);
+			}
+		}
+	}
+
+	fn initialize(&self) -> InitializeResult {
+		InitializeResult {
+			capabilities: ServerCapabilities {
+				text_document_sync: Some(TextDocumentSyncCapability::Kind(
+					TextDocumentSyncKind::Incremental,
+				)),
+				document_formatting_provider: Some(true),
+				document_range_formatting_provider: Some(true),
+				code_action_provider: Some(CodeActionProviderCapability::Options(
+					CodeActionOptions {
+						code_action_kinds: Some(vec![
+							CodeActionKind::QUICKFIX,
+							CodeActionKind::from(FIX_ALL_KIND),
+						]),
+						work_done_progress_options: WorkDoneProgressOptions::default(),
+					},
+				)),
+				semantic_tokens_provider: Some(SemanticTokensServerCapabilities::from(
+					SemanticTokensOptions {
+						legend: legend(),
+						range: Some(true),
+						full: Some(SemanticTokensFullOptions::Bool(true)),
+						..SemanticTokensOptions::default()
+					},
+				)),
+				..ServerCapabilities::default()
+			},
+			server_info: Some(ServerInfo {
+				name: "rome".to_string(),
+				version: Some(env!("CARGO_PKG_VERSION").to_string()),
+			}),
+		}
+	}
+
+	/// Returns the quick fixes of the diagnostics in the range, and the action that applies all
+	/// the safe fixes of the document, filtered by the kinds requested by the client
+	fn code_actions(
+		&mut self,
+		params: CodeActionParams,
+	) -> Result<Vec<CodeActionOrCommand>, ResponseError> {
+		let uri = params.text_document.uri;
+		let document = self.document(&uri)?;
+		let diagnostics = self.diagnostics(&uri, document);
+		let workspace = &self.workspace;
+		let file_id = document.file_id;
+		let range = range_to_byte_span(workspace, file_id, &params.range)
+			.map_err(|err| ResponseError::new(INVALID_PARAMS, err.to_string()))?;
+
+		let only = params.context.only;
+		let is_requested = |kind: &str| match &only {
+			Some(kinds) => kinds.iter().any(|requested| {
+				let requested = requested.as_str();
+				kind == requested || kind.starts_with(&format!("{}.", requested))
+			}),
+			None => true,
+		};
+
+		let mut actions = vec![];
+
+		if is_requested(CodeActionKind::QUICKFIX.as_str()) {
+			for diagnostic in &diagnostics {
+				let span = match &diagnostic.primary {
+					Some(primary) => &primary.span.range,
+					None => continue,
+				};
+				if span.start > range.end || range.start > span.end {
+					continue;
+				}
+
+				let lsp_diagnostic = convert_to_lsp_diagnostic(
+					diagnostic.clone(),
+					workspace,
+					file_id,
+					uri.clone(),
+					Some("rome".to_string()),
+				);
+				actions.extend(
+					convert_to_lsp_code_actions(
+						diagnostic,
+						lsp_diagnostic,
+						workspace,
+						file_id,
+						&uri,
+					)
+					.into_iter()
+					.map(CodeActionOrCommand::CodeAction),
+				);
+			}
+		}
+
+		if is_requested(FIX_ALL_KIND) {
+			let kind = CodeActionKind::from(FIX_ALL_KIND);
+			if let Some(action) = fix_all_code_action(&diagnostics, workspace, file_id, &uri, kind)
+			{
+				actions.push(CodeActionOrCommand::CodeAction(action));
+			}
+		}
+
+		Ok(actions)
+	}
+
+	fn document(&self, uri: &Url) -> Result<Document, ResponseError> {
+		self.documents.get(uri).copied().ok_or_else(|| {
+			ResponseError::new(INVALID_PARAMS, format!("the document {} isn't open", uri))
+		})
+	}
+
+	/// Parses a document, and returns its syntax tree and syntax errors, or `None` if its
+	/// language isn't supported.
+	///
+	/// The syntax trees of the JavaScript and TypeScript documents are cached by the workspace.
+	fn parse(&mut self, uri: &Url, document: Document) -> Option<(SyntaxNode, Vec<Diagnostic>)> {
+		let rome_path = RomePath::new(document_path(uri)).deduce_handler(self.app);
+		let text = document.text(&self.workspace);
+
+		match rome_path.get_handler().map(|handler| handler.language()) {
+			Some(Language::Js | Language::Ts) => {
+				let handler = rome_path.get_handler()?;
+				let syntax = handler
+					.syntax(rome_path.fs(), &rome_path, text)
+					.unwrap_or_default();
+				let parse = self.workspace.parse(document.file_id, syntax)?;
+				Some((parse.syntax(), parse.errors().to_vec()))
+			}
+			Some(Language::Json) => {
+				let parse = parse_expr(text, document.file_id);
+				Some((parse.syntax(), parse.errors().to_vec()))
+			}
+			_ => None,
+		}
+	}
+
+	/// Parses a document, and returns its syntax errors
+	fn diagnostics(&mut self, uri: &Url, document: Document) -> Vec<Diagnostic> {
+		self.parse(uri, document)
+			.map(|(_, diagnostics)| diagnostics)
+			.unwrap_or_default()
+	}
+
+	/// Creates the notification that publishes the syntax errors of a document
+	fn publish_diagnostics(&mut self, uri: &Url) -> Value {
+		let document = self.documents[uri];
+		let diagnostics = self
+			.diagnostics(uri, document)
+			.into_iter()
+			.filter_map(|error| {
+				convert_to_lsp_diagnostic(
+					error,
+					&self.workspace,
+					document.file_id,
+					uri.clone(),
+					Some("rome".to_string()),
+				)
+			})
+			.collect();
+
+		notification(
+			"textDocument/publishDiagnostics",
+			PublishDiagnosticsParams::new(uri.clone(), diagnostics, Some(document.version)),
+		)
+	}
+}
+
+/// The path used to find the language and the settings of a document.
+///
+/// The documents that aren't files, like new documents that aren't saved yet, use the path of
+/// their URI.
+fn document_path(uri: &Url) -> PathBuf {
+	uri.to_file_path()
+		.unwrap_or_else(|_| PathBuf::from(uri.path()))
+}
+
+fn response(id: Value, result: RequestResult) -> Value {
+	match result {
+		Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+		Err(error) => json!({
+			"jsonrpc": "2.0",
+			"id": id,
+			"error": { "code": error.code, "message": error.message },
+		}),
+	}
+}
+
+fn notification(method: &str, params: impl serde::Serialize) -> Value {
+	json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn from_value<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, ResponseError> {
+	serde_json::from_value(params)
+		.map_err(|err| ResponseError::new(INVALID_PARAMS, err.to_string()))
+}
+
+fn to_value(result: impl serde::Serialize) -> RequestResult {
+	// Serializing the types of lsp_types can't fail
+	Ok(serde_json::to_value(result).unwrap())
+}
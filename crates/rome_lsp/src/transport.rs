@@ -0,0 +1,77 @@
+//! The base protocol of LSP: each message is a JSON-RPC message preceded by a `Content-Length`
+//! header.
+use serde_json::Value;
+use std::io::{self, BufRead, Write};
+
+/// Reads the body of the next message.
+///
+/// Returns `None` if the client closed the stream.
+pub(crate) fn read_message(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+	let mut content_length = None;
+
+	loop {
+		let mut header = String::new();
+		if reader.read_line(&mut header)? == 0 {
+			return Ok(None);
+		}
+
+		let header = header.trim_end();
+		if header.is_empty() {
+			break;
+		}
+
+		// The other headers, like `Content-Type`, are ignored
+		if let Some((name, value)) = header.split_once(':') {
+			if name.eq_ignore_ascii_case("Content-Length") {
+				content_length = Some(value.trim().parse::<usize>().map_err(|_| {
+					invalid_data(format!("invalid Content-Length header: {}", header))
+				})?);
+			}
+		}
+	}
+
+	let content_length =
+		content_length.ok_or_else(|| invalid_data("missing Content-Length header".to_string()))?;
+	let mut body = vec![0; content_length];
+	reader.read_exact(&mut body)?;
+
+	String::from_utf8(body)
+		.map(Some)
+		.map_err(|_| invalid_data("the message isn't valid UTF-8".to_string()))
+}
+
+/// Writes a message, and flushes the writer so the client receives it right away
+pub(crate) fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+	let body = message.to_string();
+	write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+	writer.flush()
+}
+
+fn invalid_data(message: String) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod test {
+	use super::{read_message, write_message};
+	use serde_json::json;
+	use std::io::BufReader;
+
+	#[test]
+	fn reads_the_messages_it_writes() {
+		let mut buffer = vec![];
+		write_message(&mut buffer, &json!({ "jsonrpc": "2.0", "method": "é" })).unwrap();
+		write_message(&mut buffer, &json!({ "jsonrpc": "2.0", "method": "exit" })).unwrap();
+
+		let mut reader = BufReader::new(buffer.as_slice());
+		assert_eq!(
+			read_message(&mut reader).unwrap().as_deref(),
+			Some(r#"{"jsonrpc":"2.0","method":"é"}"#)
+		);
+		assert_eq!(
+			read_message(&mut reader).unwrap().as_deref(),
+			Some(r#"{"jsonrpc":"2.0","method":"exit"}"#)
+		);
+		assert_eq!(read_message(&mut reader).unwrap(), None);
+	}
+}
@@ -0,0 +1,430 @@
+use rome_core::fs::MemoryFileSystem;
+use rome_core::{create_app, App};
+use rome_lsp::Server;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+/// The end of a pipe that's written to
+struct PipeWriter(Sender<Vec<u8>>);
+
+impl Write for PipeWriter {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.0
+			.send(buf.to_vec())
+			.map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+/// The end of a pipe that's read from, which reaches its end once the writer is dropped
+struct PipeReader {
+	receiver: Receiver<Vec<u8>>,
+	buffer: VecDeque<u8>,
+}
+
+impl Read for PipeReader {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if self.buffer.is_empty() {
+			match self.receiver.recv() {
+				Ok(bytes) => self.buffer.extend(bytes),
+				Err(_) => return Ok(0),
+			}
+		}
+
+		let len = buf.len().min(self.buffer.len());
+		for (byte, value) in buf.iter_mut().zip(self.buffer.drain(..len)) {
+			*byte = value;
+		}
+		Ok(len)
+	}
+}
+
+fn pipe() -> (PipeReader, PipeWriter) {
+	let (sender, receiver) = channel();
+	let reader = PipeReader {
+		receiver,
+		buffer: VecDeque::new(),
+	};
+	(reader, PipeWriter(sender))
+}
+
+/// A client that talks to a server running on another thread of the test
+struct Client {
+	writer: PipeWriter,
+	reader: BufReader<PipeReader>,
+	notifications: VecDeque<Value>,
+	next_id: u64,
+	server: JoinHandle<io::Result<bool>>,
+}
+
+impl Client {
+	fn start() -> Self {
+		Self::start_with_app(create_app())
+	}
+
+	fn start_with_app(app: App) -> Self {
+		let (server_reader, writer) = pipe();
+		let (reader, server_writer) = pipe();
+
+		let server = thread::spawn(move || {
+			let mut server = Server::new(&app);
+			server.run(BufReader::new(server_reader), server_writer)?;
+			Ok(server.shutdown_requested())
+		});
+
+		Self {
+			writer,
+			reader: BufReader::new(reader),
+			notifications: VecDeque::new(),
+			next_id: 0,
+			server,
+		}
+	}
+
+	fn send(&mut self, message: Value) {
+		let body = message.to_string();
+		write!(
+			self.writer,
+			"Content-Length: {}\r\n\r\n{}",
+			body.len(),
+			body
+		)
+		.unwrap();
+	}
+
+	fn receive(&mut self) -> Value {
+		let mut content_length = 0;
+		loop {
+			let mut header = String::new();
+			self.reader.read_line(&mut header).unwrap();
+			let header = header.trim_end();
+			if header.is_empty() {
+				break;
+			}
+			if let Some(value) = header.strip_prefix("Content-Length: ") {
+				content_length = value.parse().unwrap();
+			}
+		}
+
+		let mut body = vec![0; content_length];
+		self.reader.read_exact(&mut body).unwrap();
+		serde_json::from_slice(&body).unwrap()
+	}
+
+	/// Sends a request and returns its response, keeping the notifications received meanwhile
+	fn request(&mut self, method: &str, params: Value) -> Value {
+		self.next_id += 1;
+		let id = self.next_id;
+		self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+
+		loop {
+			let message = self.receive();
+			if message["id"] == id {
+				return message;
+			}
+			self.notifications.push_back(message);
+		}
+	}
+
+	fn notify(&mut self, method: &str, params: Value) {
+		self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+	}
+
+	fn notification(&mut self) -> Value {
+		match self.notifications.pop_front() {
+			Some(notification) => notification,
+			None => self.receive(),
+		}
+	}
+
+	/// Shuts the server down, and returns whether it exited cleanly
+	fn shutdown(mut self) -> bool {
+		let response = self.request("shutdown", Value::Null);
+		assert_eq!(response["result"], Value::Null);
+		self.notify("exit", Value::Null);
+		self.server.join().unwrap().unwrap()
+	}
+}
+
+const URI: &str = "file:///project/src/index.js";
+
+#[test]
+fn test_initialize() {
+	let mut client = Client::start();
+
+	let response = client.request("initialize", json!({ "capabilities": {} }));
+	let capabilities = &response["result"]["capabilities"];
+	assert_eq!(capabilities["textDocumentSync"], 2);
+	assert_eq!(capabilities["documentFormattingProvider"], true);
+	assert_eq!(capabilities["documentRangeFormattingProvider"], true);
+	assert_eq!(
+		capabilities["codeActionProvider"]["codeActionKinds"],
+		json!(["quickfix", "source.fixAll.rome"])
+	);
+	assert_eq!(response["result"]["serverInfo"]["name"], "rome");
+
+	let response = client.request("textDocument/hover", json!({}));
+	assert_eq!(response["error"]["code"], -32601);
+
+	assert!(client.shutdown(), "the server should exit after a shutdown");
+}
+
+#[test]
+fn test_publish_diagnostics() {
+	let mut client = Client::start();
+	client.request("initialize", json!({ "capabilities": {} }));
+
+	client.notify(
+		"textDocument/didOpen",
+		json!({
+			"textDocument": { "uri": URI, "languageId": "javascript", "version": 1, "text": "let a = ;\n" }
+		}),
+	);
+	let notification = client.notification();
+	assert_eq!(notification["method"], "textDocument/publishDiagnostics");
+	assert_eq!(notification["params"]["uri"], URI);
+	assert_eq!(notification["params"]["version"], 1);
+	let diagnostic = &notification["params"]["diagnostics"][0];
+	assert_eq!(
+		diagnostic["message"],
+		"Expected an expression, but found none"
+	);
+	assert_eq!(diagnostic["code"], "SyntaxError");
+	assert_eq!(diagnostic["severity"], 1);
+	assert_eq!(
+		diagnostic["range"],
+		json!({ "start": { "line": 0, "character": 8 }, "end": { "line": 0, "character": 9 } })
+	);
+
+	// Fixes the error with an incremental change
+	client.notify(
+		"textDocument/didChange",
+		json!({
+			"textDocument": { "uri": URI, "version": 2 },
+			"contentChanges": [{
+				"range": { "start": { "line": 0, "character": 8 }, "end": { "line": 0, "character": 8 } },
+				"text": "1",
+			}],
+		}),
+	);
+	let notification = client.notification();
+	assert_eq!(notification["params"]["version"], 2);
+	assert_eq!(notification["params"]["diagnostics"], json!([]));
+
+	client.notify(
+		"textDocument/didClose",
+		json!({ "textDocument": { "uri": URI } }),
+	);
+	let notification = client.notification();
+	assert_eq!(notification["params"]["diagnostics"], json!([]));
+
+	// Opening the document again starts from its new text
+	client.notify(
+		"textDocument/didOpen",
+		json!({
+			"textDocument": { "uri": URI, "languageId": "javascript", "version": 1, "text": "let b = ;\n" }
+		}),
+	);
+	let notification = client.notification();
+	assert_eq!(notification["params"]["version"], 1);
+	assert_eq!(
+		notification["params"]["diagnostics"][0]["message"],
+		"Expected an expression, but found none"
+	);
+
+	assert!(client.shutdown());
+}
+
+#[test]
+fn test_formatting() {
+	let mut client = Client::start();
+	client.request("initialize", json!({ "capabilities": {} }));
+	client.notify(
+		"textDocument/didOpen",
+		json!({
+			"textDocument": {
+				"uri": URI,
+				"languageId": "javascript",
+				"version": 1,
+				"text": "let a  =  1;\nlet b = 2;\nif(a){b}\n",
+			}
+		}),
+	);
+	client.notification();
+
+	let response = client.request(
+		"textDocument/formatting",
+		json!({
+			"textDocument": { "uri": URI },
+			"options": { "tabSize": 2, "insertSpaces": true },
+		}),
+	);
+	assert_eq!(
+		response["result"],
+		json!([
+			{
+				"range": { "start": { "line": 0, "character": 0 }, "end": { "line": 1, "character": 0 } },
+				"newText": "let a = 1;\n",
+			},
+			{
+				"range": { "start": { "line": 2, "character": 0 }, "end": { "line": 3, "character": 0 } },
+				"newText": "if (a) {\n  b;\n}\n",
+			},
+		])
+	);
+
+	let response = client.request(
+		"textDocument/rangeFormatting",
+		json!({
+			"textDocument": { "uri": URI },
+			"range": { "start": { "line": 2, "character": 0 }, "end": { "line": 2, "character": 3 } },
+			"options": { "tabSize": 4, "insertSpaces": false },
+		}),
+	);
+	assert_eq!(
+		response["result"],
+		json!([
+			{
+				"range": { "start": { "line": 2, "character": 0 }, "end": { "line": 2, "character": 8 } },
+				"newText": "if (a) {\n\tb;\n}",
+			},
+		])
+	);
+
+	let response = client.request(
+		"textDocument/formatting",
+		json!({
+			"textDocument": { "uri": "file:///project/src/unknown.js" },
+			"options": { "tabSize": 2, "insertSpaces": true },
+		}),
+	);
+	assert_eq!(response["error"]["code"], -32602);
+
+	assert!(client.shutdown());
+}
+
+#[test]
+fn test_code_actions() {
+	let mut client = Client::start();
+	client.request("initialize", json!({ "capabilities": {} }));
+	client.notify(
+		"textDocument/didOpen",
+		json!({
+			"textDocument": { "uri": URI, "languageId": "javascript", "version": 1, "text": "let a = ;\n" }
+		}),
+	);
+	let diagnostics = client.notification()["params"]["diagnostics"].clone();
+
+	// The syntax errors don't have suggestions
+	let response = client.request(
+		"textDocument/codeAction",
+		json!({
+			"textDocument": { "uri": URI },
+			"range": { "start": { "line": 0, "character": 0 }, "end": { "line": 1, "character": 0 } },
+			"context": { "diagnostics": diagnostics },
+		}),
+	);
+	assert_eq!(response["result"], json!([]));
+
+	let response = client.request(
+		"textDocument/codeAction",
+		json!({
+			"textDocument": { "uri": URI },
+			"range": { "start": { "line": 3, "character": 0 }, "end": { "line": 3, "character": 0 } },
+			"context": { "diagnostics": [], "only": ["source.fixAll"] },
+		}),
+	);
+	assert_eq!(
+		response["error"]["code"], -32602,
+		"the range should be inside of the document"
+	);
+
+	assert!(client.shutdown());
+}
+
+#[test]
+fn test_semantic_tokens() {
+	let mut client = Client::start();
+	let response = client.request("initialize", json!({ "capabilities": {} }));
+	let legend = &response["result"]["capabilities"]["semanticTokensProvider"]["legend"];
+	let token_type = |name: &str| {
+		legend["tokenTypes"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.position(|token_type| token_type == name)
+			.unwrap()
+	};
+	let (keyword, variable, operator, number) = (
+		token_type("keyword"),
+		token_type("variable"),
+		token_type("operator"),
+		token_type("number"),
+	);
+	assert_eq!(
+		legend["tokenModifiers"],
+		json!(["declaration", "readonly", "static", "async"])
+	);
+
+	client.notify(
+		"textDocument/didOpen",
+		json!({
+			"textDocument": { "uri": URI, "languageId": "javascript", "version": 1, "text": "const a = 1;\na;\n" }
+		}),
+	);
+	client.notification();
+
+	let response = client.request(
+		"textDocument/semanticTokens/full",
+		json!({ "textDocument": { "uri": URI } }),
+	);
+	// Each token is encoded as its line and start relative to the previous token, its length,
+	// its type and its modifiers
+	let tokens = [
+		[0, 0, 5, keyword, 0],
+		[0, 6, 1, variable, 0b11],
+		[0, 2, 1, operator, 0],
+		[0, 2, 1, number, 0],
+		[1, 0, 1, variable, 0],
+	];
+	assert_eq!(response["result"]["data"], json!(tokens.concat()));
+
+	let response = client.request(
+		"textDocument/semanticTokens/range",
+		json!({
+			"textDocument": { "uri": URI },
+			"range": { "start": { "line": 1, "character": 0 }, "end": { "line": 2, "character": 0 } },
+		}),
+	);
+	assert_eq!(response["result"]["data"], json!([1, 0, 1, variable, 0]));
+
+	assert!(client.shutdown());
+}
+
+#[test]
+fn test_virtual_files() {
+	// The kind of module of the document comes from a package.json that only exists in memory
+	let fs = MemoryFileSystem::default();
+	fs.insert("/project/package.json", r#"{ "type": "commonjs" }"#);
+	let mut client = Client::start_with_app(create_app().with_file_system(fs));
+	client.request("initialize", json!({ "capabilities": {} }));
+
+	client.notify(
+		"textDocument/didOpen",
+		json!({
+			"textDocument": { "uri": URI, "languageId": "javascript", "version": 1, "text": "return;\n" }
+		}),
+	);
+	let notification = client.notification();
+	assert_eq!(notification["params"]["uri"], URI);
+	assert_eq!(notification["params"]["diagnostics"], json!([]));
+
+	assert!(client.shutdown());
+}
@@ -2,13 +2,21 @@
 //! It is a small wrapper around [path::PathBuf] but it is also able to
 //! give additional information around the the file that holds:
 //! - the [FileHandlers] for the specific file
-//! - shortcuts to open/write to the file
-use rome_core::{file_handlers::ExtensionHandler, App};
-use std::{fs::File, io::Write, ops::Deref, path::PathBuf};
+//! - the [FileId] used in the diagnostics of the file
+//! - shortcuts to read/write the file through the [FileSystem] of the [App]
+use rome_core::{
+	file_handlers::ExtensionHandler,
+	fs::{FileSystem, OsFileSystem},
+	App,
+};
+use rslint_errors::file::FileId;
+use std::{io, ops::Deref, path::PathBuf};
 
 pub struct RomePath<'handler> {
 	file: PathBuf,
 	handler: Option<&'handler dyn ExtensionHandler>,
+	fs: Option<&'handler dyn FileSystem>,
+	file_id: FileId,
 }
 
 impl<'handler> Deref for RomePath<'handler> {
@@ -20,15 +28,32 @@ impl<'handler> Deref for RomePath<'handler> {
 }
 
 impl<'handler> RomePath<'handler> {
-	pub fn new(path_to_file: &str) -> Self {
+	pub fn new(path_to_file: impl Into<PathBuf>) -> Self {
 		Self {
-			file: PathBuf::from(path_to_file),
+			file: path_to_file.into(),
 			handler: None,
+			fs: None,
+			file_id: 0,
 		}
 	}
 
+	/// Uses `file_id` to identify the file in its diagnostics, usually the id of the file in a
+	/// [Workspace](rome_core::workspace::Workspace)
+	pub fn with_file_id(mut self, file_id: FileId) -> Self {
+		self.file_id = file_id;
+		self
+	}
+
+	/// The id that identifies the file in its diagnostics, `0` unless it was set with
+	/// [RomePath::with_file_id]
+	pub fn file_id(&self) -> FileId {
+		self.file_id
+	}
+
 	/// Deduce the file handler based on the extension of the file.
 	///
+	/// The file is then read and written through the file system of `app`.
+	///
 	/// Any error will default to the base file handler for now.
 	///
 	///
@@ -42,7 +67,7 @@ impl<'handler> RomePath<'handler> {
 	/// let app = create_app();
 	/// let file = RomePath::new("file.js").deduce_handler(&app);
 	/// let handler = file.get_handler();
-	/// let expected = JsFileHandler {};
+	/// let expected = JsFileHandler::default();
 	/// assert_eq!(
 	///   handler.unwrap().capabilities().format,
 	///   expected.capabilities().format
@@ -53,6 +78,7 @@ impl<'handler> RomePath<'handler> {
 	/// )
 	/// ```
 	pub fn deduce_handler(mut self, app: &'handler App) -> Self {
+		self.fs = Some(app.fs());
 		if self.extension().is_none() {
 			return self;
 		}
@@ -68,17 +94,22 @@ impl<'handler> RomePath<'handler> {
 		self
 	}
 
-	// TODO: handle error with diagnostic?
-	/// Opens a file and returns a [File] in write mode
-	pub fn open(&self) -> File {
-		File::open(&self.file).expect("cannot open the file to format")
+	/// The file system that the file is read from and written to.
+	///
+	/// It's the one of the [App] passed to [deduce_handler], or the file system of the operating
+	/// system if there was none.
+	pub fn fs(&self) -> &dyn FileSystem {
+		self.fs.unwrap_or(&OsFileSystem)
 	}
 
-	/// Accepts a file opened in read mode and saves into it
-	pub fn save(&mut self, content: &str) -> Result<(), std::io::Error> {
-		let mut file_to_write = File::create(&self.file).unwrap();
-		// TODO: handle error with diagnostic
-		file_to_write.write_all(content.as_bytes())
+	/// Reads the whole content of the file
+	pub fn read_to_string(&self) -> io::Result<String> {
+		self.fs().read_to_string(&self.file)
+	}
+
+	/// Replaces the content of the file
+	pub fn save(&mut self, content: &str) -> io::Result<()> {
+		self.fs().write(&self.file, content)
 	}
 
 	/// Returns the current handler associated to the file.
@@ -96,6 +127,7 @@ mod test {
 	use rome_core::{
 		create_app,
 		file_handlers::{javascript::JsFileHandler, ExtensionHandler},
+		fs::MemoryFileSystem,
 	};
 
 	#[test]
@@ -103,7 +135,7 @@ mod test {
 		let app = create_app();
 		let file = RomePath::new("file.js").deduce_handler(&app);
 		let handler = file.get_handler();
-		let expected = JsFileHandler {};
+		let expected = JsFileHandler::default();
 		assert_eq!(
 			handler.unwrap().capabilities().format,
 			expected.capabilities().format
@@ -113,4 +145,17 @@ mod test {
 			expected.capabilities().lint
 		)
 	}
+
+	#[test]
+	fn reads_and_saves_through_the_file_system_of_the_app() {
+		let fs = MemoryFileSystem::default();
+		fs.insert("virtual/file.js", "let a = 1;");
+		let app = create_app().with_file_system(fs);
+		let mut file = RomePath::new("virtual/file.js").deduce_handler(&app);
+
+		assert_eq!(file.read_to_string().unwrap(), "let a = 1;");
+		file.save("let a = 2;").unwrap();
+		assert_eq!(file.read_to_string().unwrap(), "let a = 2;");
+		assert!(RomePath::new("virtual/file.js").read_to_string().is_err());
+	}
 }
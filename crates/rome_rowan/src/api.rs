@@ -123,7 +123,7 @@ impl<L: Language> SyntaxTriviaPiece<L> {
 	/// ```
 	pub fn text(&self) -> &str {
 		let txt = self.raw.text();
-		let start = self.offset - self.raw.offset();
+		let start = self.offset - self.raw.text_range().start();
 		let end = start + self.text_len();
 
 		&txt[start.into()..end.into()]
@@ -168,6 +168,8 @@ impl<L: Language> SyntaxTriviaPiece<L> {
 	/// });
 	/// let pieces: Vec<_> = node.first_leading_trivia().unwrap().pieces().collect();
 	/// assert_eq!(TextRange::new(0.into(), 3.into()), pieces[0].text_range());
+	/// let pieces: Vec<_> = node.last_trailing_trivia().unwrap().pieces().collect();
+	/// assert_eq!(TextRange::new(10.into(), 13.into()), pieces[0].text_range());
 	/// ```
 	pub fn text_range(&self) -> TextRange {
 		TextRange::at(self.offset, self.text_len())
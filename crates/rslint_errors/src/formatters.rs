@@ -2,18 +2,30 @@ use crate::termcolor::{ColorChoice, StandardStream, WriteColor};
 use crate::*;
 use codespan::files::Error;
 use colored::*;
-use file::Files;
+use file::{FileId, Files};
 use std::collections::HashSet;
 use std::io;
 
+mod checkstyle;
+mod github;
+mod gitlab;
+mod json;
+mod sarif;
+
+pub use checkstyle::CheckstyleFormatter;
+pub use github::GithubFormatter;
+pub use gitlab::GitlabFormatter;
+pub use json::JsonFormatter;
+pub use sarif::SarifFormatter;
+
 /// A trait describing a struct which can render diagnostics to a writer such as stderr.
 ///
 /// Each formatter may rely on behavior specific to a batch of diagnostics, therefore
 /// you should collect all diagnostics and then call the appropriate formatter
 pub trait Formatter {
 	fn emit_stdout(&mut self, diagnostics: &[Diagnostic], files: &dyn Files) -> io::Result<()> {
-		let stderr = StandardStream::stderr(ColorChoice::Always);
-		let mut out = stderr.lock();
+		let stdout = StandardStream::stdout(ColorChoice::Always);
+		let mut out = stdout.lock();
 		self.emit_with_writer(diagnostics, files, &mut out)
 	}
 
@@ -152,3 +164,37 @@ impl Formatter for LongFormatter {
 		Ok(())
 	}
 }
+
+/// A position inside a file, as shown to the users
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Position {
+	/// The line, starting from 1
+	line: usize,
+	/// The column, starting from 1, in Unicode code points
+	column: usize,
+}
+
+impl Position {
+	/// Computes the position of a byte index inside a file
+	fn new(files: &dyn Files, file: FileId, byte_index: usize) -> Option<Self> {
+		let line_index = files.line_index(file, byte_index)?;
+		let line_start = files.line_range(file, line_index)?.start;
+		let source = files.source(file)?;
+
+		Some(Self {
+			line: line_index + 1,
+			column: source.get(line_start..byte_index)?.chars().count() + 1,
+		})
+	}
+}
+
+/// The name of a severity in the machine readable formats
+fn severity_name(severity: Severity) -> &'static str {
+	match severity {
+		Severity::Bug => "bug",
+		Severity::Error => "error",
+		Severity::Warning => "warning",
+		Severity::Note => "note",
+		Severity::Help => "help",
+	}
+}
@@ -0,0 +1,175 @@
+use super::{Formatter, Position};
+use crate::file::{FileId, FileSpan, Files};
+use crate::termcolor::WriteColor;
+use crate::{Diagnostic, Severity};
+use std::io;
+
+/// Renders the diagnostics as a [Checkstyle](https://checkstyle.org) XML report.
+///
+/// The diagnostics are grouped by file. Secondary labels and suggestions are reported as
+/// additional `info` entries, since the format has no notion of them.
+#[derive(Debug, Copy, Clone)]
+pub struct CheckstyleFormatter;
+
+impl Formatter for CheckstyleFormatter {
+	fn emit_with_writer(
+		&mut self,
+		diagnostics: &[Diagnostic],
+		files: &dyn Files,
+		writer: &mut dyn WriteColor,
+	) -> io::Result<()> {
+		// The files are reported in the order their first diagnostic appears
+		let mut ids: Vec<FileId> = vec![];
+		for diagnostic in diagnostics {
+			if !ids.contains(&diagnostic.file_id) {
+				ids.push(diagnostic.file_id);
+			}
+		}
+
+		writeln!(writer, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+		writeln!(writer, r#"<checkstyle version="4.3">"#)?;
+
+		for id in ids {
+			let name = files.name(id).unwrap_or_default();
+			writeln!(writer, r#"  <file name="{}">"#, escape(name))?;
+
+			for diagnostic in diagnostics.iter().filter(|d| d.file_id == id) {
+				let source = diagnostic.code.as_deref().unwrap_or("rome");
+				let message = match &diagnostic.primary {
+					Some(primary) if !primary.msg.is_empty() => {
+						format!("{}: {}", diagnostic.title, primary.msg)
+					}
+					_ => diagnostic.title.clone(),
+				};
+
+				write_error(
+					writer,
+					files,
+					diagnostic.primary.as_ref().map(|primary| &primary.span),
+					severity(diagnostic.severity),
+					&message,
+					source,
+				)?;
+
+				for child in &diagnostic.children {
+					write_error(writer, files, Some(&child.span), "info", &child.msg, source)?;
+				}
+
+				for suggestion in &diagnostic.suggestions {
+					let message = match suggestion.replacement(files) {
+						Some(replacement) => {
+							format!("{}, replace with: {}", suggestion.msg, replacement)
+						}
+						None => suggestion.msg.clone(),
+					};
+					write_error(
+						writer,
+						files,
+						Some(&suggestion.span),
+						"info",
+						&message,
+						source,
+					)?;
+				}
+			}
+
+			writeln!(writer, "  </file>")?;
+		}
+
+		writeln!(writer, "</checkstyle>")
+	}
+}
+
+fn write_error(
+	writer: &mut dyn WriteColor,
+	files: &dyn Files,
+	span: Option<&FileSpan>,
+	severity: &str,
+	message: &str,
+	source: &str,
+) -> io::Result<()> {
+	write!(writer, "    <error")?;
+	if let Some(position) = span.and_then(|span| Position::new(files, span.file, span.range.start))
+	{
+		write!(
+			writer,
+			r#" line="{}" column="{}""#,
+			position.line, position.column
+		)?;
+	}
+	writeln!(
+		writer,
+		r#" severity="{}" message="{}" source="{}"/>"#,
+		severity,
+		escape(message),
+		escape(source)
+	)
+}
+
+fn severity(severity: Severity) -> &'static str {
+	match severity {
+		Severity::Bug | Severity::Error => "error",
+		Severity::Warning => "warning",
+		Severity::Note | Severity::Help => "info",
+	}
+}
+
+/// Escapes a string for use inside of an XML attribute
+fn escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			'\n' => escaped.push_str("&#10;"),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+#[cfg(test)]
+mod test {
+	use super::CheckstyleFormatter;
+	use crate::file::SimpleFile;
+	use crate::termcolor::NoColor;
+	use crate::{Applicability, Diagnostic, Formatter};
+
+	#[test]
+	fn groups_errors_by_file() {
+		let file = SimpleFile::new("index.js".into(), "let a = 1;\nif (a < b) {}\n".into());
+		let diagnostics = [
+			Diagnostic::error(0, "Lint", "`b` is not defined")
+				.primary(19usize..20, "")
+				.suggestion(
+					19usize..20,
+					"use `a` instead",
+					"a",
+					Applicability::MaybeIncorrect,
+				),
+			Diagnostic::warning(0, "Lint", "comparison with <").secondary(17usize..18, "here"),
+		];
+
+		let mut output = NoColor::new(Vec::new());
+		CheckstyleFormatter
+			.emit_with_writer(&diagnostics, &file, &mut output)
+			.unwrap();
+
+		assert_eq!(
+			String::from_utf8(output.into_inner()).unwrap(),
+			r#"<?xml version="1.0" encoding="utf-8"?>
+<checkstyle version="4.3">
+  <file name="index.js">
+    <error line="2" column="9" severity="error" message="`b` is not defined" source="Lint"/>
+    <error line="2" column="9" severity="info" message="use `a` instead, replace with: a" source="Lint"/>
+    <error severity="warning" message="comparison with &lt;" source="Lint"/>
+    <error line="2" column="7" severity="info" message="here" source="Lint"/>
+  </file>
+</checkstyle>
+"#
+		);
+	}
+}
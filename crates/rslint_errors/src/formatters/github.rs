@@ -0,0 +1,123 @@
+use super::{Formatter, Position};
+use crate::file::Files;
+use crate::termcolor::WriteColor;
+use crate::{Diagnostic, Severity};
+use std::io;
+
+/// Renders the diagnostics as [GitHub Actions workflow commands](https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions),
+/// which show up as annotations on the pull requests:
+///
+/// ```text
+/// ::error file=index.js,line=1,endLine=1,col=9,endColumn=10,title=SyntaxError::Expected an expression, but found none
+/// ```
+///
+/// The commands must be printed to the standard output of the workflow step.
+#[derive(Debug, Copy, Clone)]
+pub struct GithubFormatter;
+
+impl Formatter for GithubFormatter {
+	fn emit_with_writer(
+		&mut self,
+		diagnostics: &[Diagnostic],
+		files: &dyn Files,
+		writer: &mut dyn WriteColor,
+	) -> io::Result<()> {
+		for diagnostic in diagnostics {
+			let mut properties = vec![];
+			let file = diagnostic
+				.primary
+				.as_ref()
+				.map_or(diagnostic.file_id, |primary| primary.span.file);
+			if let Some(name) = files.name(file) {
+				properties.push(format!("file={}", escape_property(name)));
+			}
+
+			if let Some(primary) = &diagnostic.primary {
+				let span = &primary.span;
+				let start = Position::new(files, span.file, span.range.start);
+				let end = Position::new(files, span.file, span.range.end);
+				if let (Some(start), Some(end)) = (start, end) {
+					properties.push(format!("line={}", start.line));
+					properties.push(format!("endLine={}", end.line));
+					properties.push(format!("col={}", start.column));
+					properties.push(format!("endColumn={}", end.column));
+				}
+			}
+
+			if let Some(code) = &diagnostic.code {
+				properties.push(format!("title={}", escape_property(code)));
+			}
+
+			let mut message = diagnostic.title.clone();
+			if let Some(primary) = diagnostic.primary.as_ref().filter(|p| !p.msg.is_empty()) {
+				message.push('\n');
+				message.push_str(&primary.msg);
+			}
+
+			writeln!(
+				writer,
+				"::{} {}::{}",
+				command(diagnostic.severity),
+				properties.join(","),
+				escape_data(&message)
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
+fn command(severity: Severity) -> &'static str {
+	match severity {
+		Severity::Bug | Severity::Error => "error",
+		Severity::Warning => "warning",
+		Severity::Note | Severity::Help => "notice",
+	}
+}
+
+/// Escapes the message of a command
+fn escape_data(data: &str) -> String {
+	data.replace('%', "%25")
+		.replace('\r', "%0D")
+		.replace('\n', "%0A")
+}
+
+/// Escapes the value of a property of a command, which can't contain the separators of the
+/// properties either
+fn escape_property(property: &str) -> String {
+	escape_data(property)
+		.replace(':', "%3A")
+		.replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+	use super::GithubFormatter;
+	use crate::file::SimpleFile;
+	use crate::termcolor::NoColor;
+	use crate::{Diagnostic, Formatter};
+
+	#[test]
+	fn renders_workflow_commands() {
+		let file = SimpleFile::new("src/a,b.js".into(), "let a = ;\nlet b;\n".into());
+		let diagnostics = [
+			Diagnostic::error(0, "SyntaxError", "Expected an expression, but found none")
+				.primary(8usize..9, "Expected an expression here"),
+			Diagnostic::warning(0, "Lint", "100% unused").primary(14usize..15, ""),
+			Diagnostic::note(0, "Lint", "no location"),
+		];
+
+		let mut output = NoColor::new(Vec::new());
+		GithubFormatter
+			.emit_with_writer(&diagnostics, &file, &mut output)
+			.unwrap();
+
+		assert_eq!(
+			String::from_utf8(output.into_inner()).unwrap(),
+			"::error file=src/a%2Cb.js,line=1,endLine=1,col=9,endColumn=10,title=SyntaxError::Expected an expression, but found none%0AExpected an expression here
+::warning file=src/a%2Cb.js,line=2,endLine=2,col=5,endColumn=6,title=Lint::100%25 unused
+::notice file=src/a%2Cb.js,title=Lint::no location
+"
+		);
+	}
+}
@@ -0,0 +1,158 @@
+use super::{Formatter, Position};
+use crate::file::Files;
+use crate::termcolor::WriteColor;
+use crate::{Diagnostic, Severity};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io;
+
+/// Renders the diagnostics as a [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool)
+/// report, which GitLab shows on the merge requests when it's uploaded as an artifact.
+///
+/// The fingerprint of an issue only depends on its file, code, title, message and the code it
+/// points to, so that GitLab can track it across commits that move it around.
+#[derive(Debug, Copy, Clone)]
+pub struct GitlabFormatter;
+
+impl Formatter for GitlabFormatter {
+	fn emit_with_writer(
+		&mut self,
+		diagnostics: &[Diagnostic],
+		files: &dyn Files,
+		writer: &mut dyn WriteColor,
+	) -> io::Result<()> {
+		// Counts the issues with the same fingerprint, so the fingerprints stay unique
+		let mut occurrences: HashMap<u64, u64> = HashMap::new();
+
+		let issues: Vec<Value> = diagnostics
+			.iter()
+			.map(|diagnostic| {
+				let file = diagnostic
+					.primary
+					.as_ref()
+					.map_or(diagnostic.file_id, |primary| primary.span.file);
+				let path = files.name(file).unwrap_or_default();
+
+				let mut description = diagnostic.title.clone();
+				let mut snippet = "";
+				let mut positions = None;
+
+				if let Some(primary) = &diagnostic.primary {
+					let span = &primary.span;
+					if !primary.msg.is_empty() {
+						description = format!("{}: {}", description, primary.msg);
+					}
+					snippet = files
+						.source(span.file)
+						.and_then(|source| source.get(span.range.clone()))
+						.unwrap_or_default();
+					positions = Position::new(files, span.file, span.range.start)
+						.zip(Position::new(files, span.file, span.range.end));
+				}
+
+				let code = diagnostic.code.as_deref().unwrap_or_default();
+				let mut fingerprint = Fingerprint::default();
+				for part in [path, code, &description, snippet] {
+					fingerprint.write(part);
+				}
+				let occurrence = occurrences.entry(fingerprint.0).or_default();
+				fingerprint.write(&occurrence.to_string());
+				*occurrence += 1;
+
+				let location = match positions {
+					Some((start, end)) => json!({
+						"path": path,
+						"positions": {
+							"begin": { "line": start.line, "column": start.column },
+							"end": { "line": end.line, "column": end.column },
+						},
+					}),
+					// Issues without a location are reported on the first line of their file
+					None => json!({
+						"path": path,
+						"lines": { "begin": 1 },
+					}),
+				};
+
+				json!({
+					"type": "issue",
+					"check_name": code,
+					"description": description,
+					"severity": severity(diagnostic.severity),
+					"fingerprint": format!("{:016x}", fingerprint.0),
+					"location": location,
+				})
+			})
+			.collect();
+
+		serde_json::to_writer_pretty(&mut *writer, &issues)?;
+		writeln!(writer)
+	}
+}
+
+fn severity(severity: Severity) -> &'static str {
+	match severity {
+		Severity::Bug => "critical",
+		Severity::Error => "major",
+		Severity::Warning => "minor",
+		Severity::Note | Severity::Help => "info",
+	}
+}
+
+/// A 64 bits FNV-1a hash, which unlike the hashers of the standard library is guaranteed to
+/// stay the same across versions of Rust
+struct Fingerprint(u64);
+
+impl Default for Fingerprint {
+	fn default() -> Self {
+		Self(0xcbf2_9ce4_8422_2325)
+	}
+}
+
+impl Fingerprint {
+	fn write(&mut self, part: &str) {
+		// The separator keeps ("ab", "c") and ("a", "bc") apart
+		for byte in part.bytes().chain(std::iter::once(0)) {
+			self.0 ^= u64::from(byte);
+			self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::GitlabFormatter;
+	use crate::file::SimpleFile;
+	use crate::termcolor::NoColor;
+	use crate::{Diagnostic, Formatter};
+	use serde_json::{json, Value};
+
+	#[test]
+	fn renders_issues_with_unique_fingerprints() {
+		let file = SimpleFile::new("index.js".into(), "let a;\nlet a;\n".into());
+		let diagnostics = [
+			Diagnostic::warning(0, "Lint", "unused variable").primary(4usize..5, ""),
+			Diagnostic::warning(0, "Lint", "unused variable").primary(11usize..12, ""),
+		];
+
+		let mut output = NoColor::new(Vec::new());
+		GitlabFormatter
+			.emit_with_writer(&diagnostics, &file, &mut output)
+			.unwrap();
+		let output: Value = serde_json::from_slice(&output.into_inner()).unwrap();
+
+		assert_eq!(output[0]["check_name"], "Lint");
+		assert_eq!(output[0]["severity"], "minor");
+		assert_eq!(
+			output[1]["location"],
+			json!({
+				"path": "index.js",
+				"positions": {
+					"begin": { "line": 2, "column": 5 },
+					"end": { "line": 2, "column": 6 },
+				},
+			})
+		);
+		assert_ne!(output[0]["fingerprint"], output[1]["fingerprint"]);
+	}
+}
@@ -0,0 +1,166 @@
+use super::{severity_name, Formatter, Position};
+use crate::file::{FileSpan, Files};
+use crate::termcolor::WriteColor;
+use crate::{Applicability, Diagnostic, SubDiagnostic};
+use serde_json::{json, Value};
+use std::io;
+
+/// Renders the diagnostics as a JSON document, meant to be consumed by other tools.
+///
+/// ```json
+/// {
+///   "diagnostics": [
+///     {
+///       "file": "index.js",
+///       "severity": "error",
+///       "code": "SyntaxError",
+///       "title": "Expected an expression, but found none",
+///       "primary": {
+///         "severity": "error",
+///         "message": "Expected an expression here",
+///         "location": {
+///           "file": "index.js",
+///           "range": { "start": 8, "end": 9 },
+///           "start": { "line": 1, "column": 9 },
+///           "end": { "line": 1, "column": 10 }
+///         }
+///       },
+///       "secondary": [],
+///       "footers": [],
+///       "suggestions": []
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Lines and columns start from 1, columns count Unicode code points. Ranges are byte offsets.
+#[derive(Debug, Copy, Clone)]
+pub struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+	fn emit_with_writer(
+		&mut self,
+		diagnostics: &[Diagnostic],
+		files: &dyn Files,
+		writer: &mut dyn WriteColor,
+	) -> io::Result<()> {
+		let diagnostics: Vec<Value> = diagnostics
+			.iter()
+			.map(|diagnostic| diagnostic_to_json(diagnostic, files))
+			.collect();
+
+		serde_json::to_writer_pretty(&mut *writer, &json!({ "diagnostics": diagnostics }))?;
+		writeln!(writer)
+	}
+}
+
+fn diagnostic_to_json(diagnostic: &Diagnostic, files: &dyn Files) -> Value {
+	json!({
+		"file": files.name(diagnostic.file_id),
+		"severity": severity_name(diagnostic.severity),
+		"code": diagnostic.code,
+		"title": diagnostic.title,
+		"primary": diagnostic.primary.as_ref().map(|primary| label_to_json(primary, files)),
+		"secondary": diagnostic
+			.children
+			.iter()
+			.map(|child| label_to_json(child, files))
+			.collect::<Vec<_>>(),
+		"footers": diagnostic
+			.footers
+			.iter()
+			.map(|footer| json!({
+				"severity": severity_name(footer.severity),
+				"message": footer.msg,
+			}))
+			.collect::<Vec<_>>(),
+		"suggestions": diagnostic
+			.suggestions
+			.iter()
+			.map(|suggestion| json!({
+				"message": suggestion.msg,
+				"applicability": applicability_name(suggestion.applicability),
+				"location": location_to_json(&suggestion.span, files),
+				"replacement": suggestion.replacement(files),
+			}))
+			.collect::<Vec<_>>(),
+	})
+}
+
+fn label_to_json(label: &SubDiagnostic, files: &dyn Files) -> Value {
+	json!({
+		"severity": severity_name(label.severity),
+		"message": label.msg,
+		"location": location_to_json(&label.span, files),
+	})
+}
+
+fn location_to_json(span: &FileSpan, files: &dyn Files) -> Value {
+	let position_to_json = |byte_index| {
+		Position::new(files, span.file, byte_index)
+			.map(|position| json!({ "line": position.line, "column": position.column }))
+	};
+
+	json!({
+		"file": files.name(span.file),
+		"range": { "start": span.range.start, "end": span.range.end },
+		"start": position_to_json(span.range.start),
+		"end": position_to_json(span.range.end),
+	})
+}
+
+fn applicability_name(applicability: Applicability) -> &'static str {
+	match applicability {
+		Applicability::Always => "always",
+		Applicability::MaybeIncorrect => "maybeIncorrect",
+		Applicability::HasPlaceholders => "hasPlaceholders",
+		Applicability::Unspecified => "unspecified",
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::JsonFormatter;
+	use crate::file::SimpleFile;
+	use crate::termcolor::NoColor;
+	use crate::{Applicability, Diagnostic, Formatter};
+	use serde_json::{json, Value};
+
+	#[test]
+	fn renders_labels_and_suggestions() {
+		let file = SimpleFile::new("index.js".into(), "let a = 1;\nlet é = a;\n".into());
+		let diagnostic = Diagnostic::warning(0, "Lint", "unused variable")
+			.primary(15usize..17, "this variable is never used")
+			.secondary(4usize..5, "declared here")
+			.suggestion(
+				15usize..17,
+				"rename the variable",
+				"_e",
+				Applicability::MaybeIncorrect,
+			);
+
+		let mut output = NoColor::new(Vec::new());
+		JsonFormatter
+			.emit_with_writer(&[diagnostic], &file, &mut output)
+			.unwrap();
+		let output: Value = serde_json::from_slice(&output.into_inner()).unwrap();
+
+		let diagnostic = &output["diagnostics"][0];
+		assert_eq!(diagnostic["severity"], "warning");
+		assert_eq!(diagnostic["code"], "Lint");
+		assert_eq!(
+			diagnostic["primary"]["location"]["start"],
+			json!({ "line": 2, "column": 5 })
+		);
+		assert_eq!(
+			diagnostic["primary"]["location"]["end"],
+			json!({ "line": 2, "column": 6 })
+		);
+		assert_eq!(diagnostic["secondary"][0]["message"], "declared here");
+		assert_eq!(diagnostic["suggestions"][0]["replacement"], "_e");
+		assert_eq!(
+			diagnostic["suggestions"][0]["applicability"],
+			"maybeIncorrect"
+		);
+	}
+}
@@ -0,0 +1,229 @@
+use super::{Formatter, Position};
+use crate::file::{FileSpan, Files};
+use crate::termcolor::WriteColor;
+use crate::{Diagnostic, Severity};
+use serde_json::{json, Value};
+use std::io;
+
+/// Renders the diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log, with a single run.
+///
+/// The codes of the diagnostics are the ids of the rules of the tool, the secondary labels are
+/// related locations, and the suggestions are fixes.
+#[derive(Debug, Clone)]
+pub struct SarifFormatter {
+	tool_name: String,
+	tool_version: String,
+}
+
+impl SarifFormatter {
+	/// Creates a formatter that reports the diagnostics as emitted by the given tool
+	pub fn new(tool_name: impl Into<String>, tool_version: impl Into<String>) -> Self {
+		Self {
+			tool_name: tool_name.into(),
+			tool_version: tool_version.into(),
+		}
+	}
+}
+
+impl Formatter for SarifFormatter {
+	fn emit_with_writer(
+		&mut self,
+		diagnostics: &[Diagnostic],
+		files: &dyn Files,
+		writer: &mut dyn WriteColor,
+	) -> io::Result<()> {
+		// The rules are listed in the order they're first reported
+		let mut rules: Vec<&str> = vec![];
+		for code in diagnostics.iter().filter_map(|d| d.code.as_deref()) {
+			if !rules.contains(&code) {
+				rules.push(code);
+			}
+		}
+
+		let results: Vec<Value> = diagnostics
+			.iter()
+			.map(|diagnostic| {
+				let mut result = json!({
+					"level": level(diagnostic.severity),
+					"message": { "text": diagnostic.title },
+					"locations": [primary_location(diagnostic, files)],
+				});
+
+				if let Some(code) = &diagnostic.code {
+					result["ruleId"] = json!(code);
+					result["ruleIndex"] = json!(rules.iter().position(|rule| rule == code));
+				}
+
+				if !diagnostic.children.is_empty() {
+					result["relatedLocations"] = diagnostic
+						.children
+						.iter()
+						.enumerate()
+						.map(|(id, child)| {
+							json!({
+								"id": id,
+								"message": { "text": child.msg },
+								"physicalLocation": physical_location(&child.span, files),
+							})
+						})
+						.collect();
+				}
+
+				if !diagnostic.suggestions.is_empty() {
+					result["fixes"] = diagnostic
+						.suggestions
+						.iter()
+						.filter_map(|suggestion| {
+							Some(json!({
+								"description": { "text": suggestion.msg },
+								"artifactChanges": [{
+									"artifactLocation": artifact_location(suggestion.span.file, files),
+									"replacements": [{
+										"deletedRegion": region(&suggestion.span, files)?,
+										"insertedContent": { "text": suggestion.replacement(files)? },
+									}],
+								}],
+							}))
+						})
+						.collect();
+				}
+
+				result
+			})
+			.collect();
+
+		let log = json!({
+			"$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+			"version": "2.1.0",
+			"runs": [{
+				"tool": {
+					"driver": {
+						"name": self.tool_name,
+						"version": self.tool_version,
+						"rules": rules
+							.iter()
+							.map(|rule| json!({ "id": rule }))
+							.collect::<Vec<_>>(),
+					},
+				},
+				"columnKind": "unicodeCodePoints",
+				"results": results,
+			}],
+		});
+
+		serde_json::to_writer_pretty(&mut *writer, &log)?;
+		writeln!(writer)
+	}
+}
+
+fn level(severity: Severity) -> &'static str {
+	match severity {
+		Severity::Bug | Severity::Error => "error",
+		Severity::Warning => "warning",
+		Severity::Note | Severity::Help => "note",
+	}
+}
+
+/// The location of the primary label, or only the file if the diagnostic doesn't have one
+fn primary_location(diagnostic: &Diagnostic, files: &dyn Files) -> Value {
+	match &diagnostic.primary {
+		Some(primary) => {
+			let mut location =
+				json!({ "physicalLocation": physical_location(&primary.span, files) });
+			if !primary.msg.is_empty() {
+				location["message"] = json!({ "text": primary.msg });
+			}
+			location
+		}
+		None => json!({
+			"physicalLocation": {
+				"artifactLocation": artifact_location(diagnostic.file_id, files),
+			},
+		}),
+	}
+}
+
+fn physical_location(span: &FileSpan, files: &dyn Files) -> Value {
+	let mut location = json!({ "artifactLocation": artifact_location(span.file, files) });
+	if let Some(region) = region(span, files) {
+		location["region"] = region;
+	}
+	location
+}
+
+fn artifact_location(file: usize, files: &dyn Files) -> Value {
+	// SARIF uses URIs, which always use forward slashes
+	json!({ "uri": files.name(file).map(|name| name.replace('\\', "/")) })
+}
+
+/// The region of a span, if it points inside of its file. The end column is exclusive, like the
+/// end of the span.
+fn region(span: &FileSpan, files: &dyn Files) -> Option<Value> {
+	let start = Position::new(files, span.file, span.range.start)?;
+	let end = Position::new(files, span.file, span.range.end)?;
+
+	Some(json!({
+		"startLine": start.line,
+		"startColumn": start.column,
+		"endLine": end.line,
+		"endColumn": end.column,
+	}))
+}
+
+#[cfg(test)]
+mod test {
+	use super::SarifFormatter;
+	use crate::file::SimpleFile;
+	use crate::termcolor::NoColor;
+	use crate::{Applicability, Diagnostic, Formatter};
+	use serde_json::{json, Value};
+
+	#[test]
+	fn renders_results_with_rules_and_fixes() {
+		let file = SimpleFile::new("src/index.js".into(), "let a = ;\n".into());
+		let diagnostics = [
+			Diagnostic::error(0, "SyntaxError", "Expected an expression, but found none")
+				.primary(8usize..9, "Expected an expression here")
+				.suggestion(8usize..8, "insert a value", "1", Applicability::Always),
+			Diagnostic::warning(0, "Lint", "unused variable").secondary(4usize..5, "declared here"),
+		];
+
+		let mut output = NoColor::new(Vec::new());
+		SarifFormatter::new("Rome", "0.0.0")
+			.emit_with_writer(&diagnostics, &file, &mut output)
+			.unwrap();
+		let output: Value = serde_json::from_slice(&output.into_inner()).unwrap();
+
+		let run = &output["runs"][0];
+		assert_eq!(output["version"], "2.1.0");
+		assert_eq!(
+			run["tool"]["driver"]["rules"],
+			json!([{ "id": "SyntaxError" }, { "id": "Lint" }])
+		);
+
+		let result = &run["results"][0];
+		assert_eq!(result["level"], "error");
+		assert_eq!(result["ruleIndex"], 0);
+		assert_eq!(
+			result["locations"][0]["physicalLocation"]["region"]["startColumn"],
+			9
+		);
+		assert_eq!(
+			result["fixes"][0]["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+			"1"
+		);
+
+		let result = &run["results"][1];
+		assert_eq!(result["level"], "warning");
+		assert_eq!(result["ruleIndex"], 1);
+		assert_eq!(
+			result["relatedLocations"][0]["message"]["text"],
+			"declared here"
+		);
+		assert_eq!(
+			result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+			"src/index.js"
+		);
+	}
+}
@@ -4,9 +4,11 @@ use crate::file::Files;
 use crate::*;
 
 use lsp_types::{
-	DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag as LspTag, Location,
-	NumberOrString, Url,
+	CodeAction, CodeActionKind, DiagnosticRelatedInformation, DiagnosticSeverity,
+	DiagnosticTag as LspTag, Location, NumberOrString, TextEdit as LspTextEdit, Url, WorkspaceEdit,
 };
+use rslint_text_edit::TextEdit;
+use std::collections::HashMap;
 
 pub fn convert_to_lsp_diagnostic(
 	diagnostic: Diagnostic,
@@ -83,6 +85,102 @@ pub fn severity_to_lsp_severity(severity: Severity) -> DiagnosticSeverity {
 	}
 }
 
+/// Converts the suggestions of a diagnostic to quick fixes.
+///
+/// The suggestions that always apply are the preferred fixes. `lsp_diagnostic` is the diagnostic
+/// that the fixes resolve, see [convert_to_lsp_diagnostic].
+pub fn convert_to_lsp_code_actions(
+	diagnostic: &Diagnostic,
+	lsp_diagnostic: Option<lsp_types::Diagnostic>,
+	files: &impl Files,
+	document_id: usize,
+	uri: &Url,
+) -> Vec<CodeAction> {
+	diagnostic
+		.suggestions
+		.iter()
+		.filter(|suggestion| suggestion.span.file == document_id)
+		.filter_map(|suggestion| {
+			let edits = text_edit_to_lsp(files, document_id, &suggestion.text_edit()).ok()?;
+
+			Some(CodeAction {
+				title: suggestion.msg.clone(),
+				kind: Some(CodeActionKind::QUICKFIX),
+				diagnostics: lsp_diagnostic.clone().map(|diagnostic| vec![diagnostic]),
+				edit: Some(workspace_edit(uri, edits)),
+				command: None,
+				is_preferred: Some(suggestion.applicability == Applicability::Always),
+			})
+		})
+		.collect()
+}
+
+/// Creates a code action of the given kind that applies all the suggestions of the diagnostics
+/// that always apply.
+///
+/// The suggestions that overlap with a suggestion that comes before them are left out. Returns
+/// `None` if there's no suggestion to apply.
+pub fn fix_all_code_action(
+	diagnostics: &[Diagnostic],
+	files: &impl Files,
+	document_id: usize,
+	uri: &Url,
+	kind: CodeActionKind,
+) -> Option<CodeAction> {
+	let mut edit = TextEdit::default();
+
+	let suggestions = diagnostics
+		.iter()
+		.flat_map(|diagnostic| &diagnostic.suggestions)
+		.filter(|suggestion| {
+			suggestion.span.file == document_id && suggestion.applicability == Applicability::Always
+		});
+	for suggestion in suggestions {
+		let _ = edit.union(suggestion.text_edit());
+	}
+
+	if edit.is_empty() {
+		return None;
+	}
+
+	Some(CodeAction {
+		title: "Apply all safe fixes".to_string(),
+		kind: Some(kind),
+		diagnostics: None,
+		edit: Some(workspace_edit(
+			uri,
+			text_edit_to_lsp(files, document_id, &edit).ok()?,
+		)),
+		command: None,
+		is_preferred: None,
+	})
+}
+
+/// Converts an edit of the source of a file to LSP text edits
+pub fn text_edit_to_lsp<F>(
+	files: &F,
+	file_id: usize,
+	edit: &TextEdit,
+) -> Result<Vec<LspTextEdit>, Error>
+where
+	F: Files,
+{
+	edit.iter()
+		.map(|indel| {
+			Ok(LspTextEdit {
+				range: byte_span_to_range(files, file_id, indel.delete.as_range())?,
+				new_text: indel.insert.clone(),
+			})
+		})
+		.collect()
+}
+
+fn workspace_edit(uri: &Url, edits: Vec<LspTextEdit>) -> WorkspaceEdit {
+	let mut changes = HashMap::new();
+	changes.insert(uri.clone(), edits);
+	WorkspaceEdit::new(changes)
+}
+
 // This code below is taken from codespan-lsp but adapted to use rslint_errors Files
 
 use lsp_types::{Position as LspPosition, Range as LspRange};
@@ -304,8 +402,18 @@ where
 {
 	let source = files.source(file_id).ok_or(Error::MissingFile)?;
 
-	let line_span = files.line_range(file_id, position.line as usize).unwrap();
-	let line_str = source.get(line_span.clone()).unwrap();
+	let line_span = files
+		.line_range(file_id, position.line as usize)
+		.ok_or_else(|| LineIndexOutOfBoundsError {
+			given: position.line as usize,
+			max: source.lines().count(),
+		})?;
+	let line_str = source
+		.get(line_span.clone())
+		.ok_or_else(|| SpanOutOfBoundsError {
+			given: line_span.clone(),
+			span: 0..source.len(),
+		})?;
 
 	let byte_offset = character_to_line_offset(line_str, position.character)?;
 
@@ -323,3 +431,83 @@ where
 	Ok(position_to_byte_index(files, file_id, &range.start)?
 		..position_to_byte_index(files, file_id, &range.end)?)
 }
+
+#[cfg(test)]
+mod test {
+	use super::{convert_to_lsp_code_actions, fix_all_code_action};
+	use crate::file::SimpleFile;
+	use crate::{Applicability, Diagnostic};
+	use lsp_types::{CodeActionKind, Position, Range, TextEdit, Url};
+	use rslint_text_edit::{Indel, TextRange, TextSize};
+
+	fn edits(action: &lsp_types::CodeAction, uri: &Url) -> Vec<TextEdit> {
+		action.edit.as_ref().unwrap().changes.as_ref().unwrap()[uri].clone()
+	}
+
+	#[test]
+	fn converts_suggestions_to_code_actions() {
+		let uri = Url::parse("file:///index.js").unwrap();
+		let file = SimpleFile::new("index.js".into(), "let a = 1\nvar b = a\n".into());
+		let diagnostic = Diagnostic::error(0, "Lint", "missing semicolon")
+			.primary(9usize..9, "")
+			.suggestion(9usize..9, "insert a semicolon", ";", Applicability::Always)
+			.indel_suggestion(
+				vec![Indel::replace(
+					TextRange::new(TextSize::from(0), TextSize::from(3)),
+					"let".to_string(),
+				)],
+				10usize..19,
+				"use let",
+				Applicability::MaybeIncorrect,
+			);
+
+		let actions = convert_to_lsp_code_actions(&diagnostic, None, &file, 0, &uri);
+		assert_eq!(actions.len(), 2);
+		assert_eq!(actions[0].title, "insert a semicolon");
+		assert_eq!(actions[0].kind, Some(CodeActionKind::QUICKFIX));
+		assert_eq!(actions[0].is_preferred, Some(true));
+		assert_eq!(
+			edits(&actions[0], &uri),
+			[TextEdit::new(
+				Range::new(Position::new(0, 9), Position::new(0, 9)),
+				";".to_string()
+			)]
+		);
+		assert_eq!(actions[1].is_preferred, Some(false));
+		assert_eq!(
+			edits(&actions[1], &uri),
+			[TextEdit::new(
+				Range::new(Position::new(1, 0), Position::new(1, 3)),
+				"let".to_string()
+			)]
+		);
+
+		let other = Diagnostic::error(0, "Lint", "missing semicolon").suggestion(
+			19usize..19,
+			"insert a semicolon",
+			";",
+			Applicability::Always,
+		);
+		let action = fix_all_code_action(
+			&[diagnostic, other],
+			&file,
+			0,
+			&uri,
+			CodeActionKind::from("source.fixAll.test"),
+		)
+		.unwrap();
+		assert_eq!(
+			edits(&action, &uri),
+			[
+				TextEdit::new(
+					Range::new(Position::new(0, 9), Position::new(0, 9)),
+					";".to_string()
+				),
+				TextEdit::new(
+					Range::new(Position::new(1, 9), Position::new(1, 9)),
+					";".to_string()
+				),
+			]
+		);
+	}
+}
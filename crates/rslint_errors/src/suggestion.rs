@@ -1,5 +1,8 @@
-use crate::{file::FileSpan, *};
-use rslint_text_edit::Indel;
+use crate::{
+	file::{FileSpan, Files},
+	*,
+};
+use rslint_text_edit::{apply_indels, Indel, TextEdit, TextRange, TextSize};
 use std::ops::Range;
 
 /// A Suggestion that is provided by rslint, and
@@ -17,6 +20,45 @@ pub struct CodeSuggestion {
 	pub labels: Vec<Range<usize>>,
 }
 
+impl CodeSuggestion {
+	/// Returns the code that replaces the code of the span once the suggestion is applied.
+	///
+	/// Returns `None` if the span doesn't point to a file of `files`.
+	pub fn replacement(&self, files: &dyn Files) -> Option<String> {
+		match &self.substitution {
+			SuggestionChange::Indels(indels) => {
+				let mut old = files
+					.source(self.span.file)?
+					.get(self.span.range.clone())?
+					.to_owned();
+				apply_indels(indels, &mut old);
+				Some(old)
+			}
+			SuggestionChange::String(string) => Some(string.clone()),
+		}
+	}
+
+	/// Returns the edit that applies the suggestion to the source of its file
+	pub fn text_edit(&self) -> TextEdit {
+		let start = TextSize::from(self.span.range.start as u32);
+		let end = TextSize::from(self.span.range.end as u32);
+
+		match &self.substitution {
+			// The ranges of the indels are relative to the start of the span
+			SuggestionChange::Indels(indels) => {
+				let mut builder = TextEdit::builder();
+				for indel in indels {
+					builder.replace(indel.delete + start, indel.insert.clone());
+				}
+				builder.finish()
+			}
+			SuggestionChange::String(string) => {
+				TextEdit::replace(TextRange::new(start, end), string.clone())
+			}
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SuggestionChange {
 	Indels(Vec<Indel>),
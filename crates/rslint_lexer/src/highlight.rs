@@ -2,22 +2,27 @@ use crate::*;
 
 pub use ansi_term::{self, ANSIGenericString, Color, Style};
 use atty::is;
+use std::fmt::Write;
+use std::ops::Range;
 
 /// A structure for syntax highlighting pieces of JavaScript source code
-/// using ANSI.
+/// using ANSI or HTML.
 ///
 /// The highlighter will auto detect if stderr or stdout are terminals, if
-/// they are not then it will return the original uncolored source code.
-/// All errors encountered while lexing are ignored.
+/// they are not then [`Highlighter::color`] will return the original uncolored source code.
+/// All errors encountered while lexing are ignored, the text of invalid tokens is kept.
 ///
 /// The highlighter is iterator based, which allows for coloring a part of code
 /// at a time.
 /// The highlighter's position can be controlled through various methods which allows
 /// for reuse of the highlighter without the need to rescan the source code
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Highlighter<'s> {
 	pub source: &'s str,
 	tokens: Vec<Token>,
+	theme: Theme,
+	/// The byte range of the source being highlighted
+	range: Range<usize>,
 	/// Current token position
 	cur: usize,
 	/// Current byte index in source
@@ -38,33 +43,126 @@ impl<'s> Highlighter<'s> {
 		Self {
 			source,
 			tokens,
+			theme: Theme::default(),
+			range: 0..source.len(),
 			cur: 0,
 			cur_idx: 0,
 		}
 	}
 
+	/// Use a theme other than the default one for the ANSI colors
+	pub fn with_theme(mut self, theme: Theme) -> Self {
+		self.theme = theme;
+		self
+	}
+
 	fn check_terminal(&self) -> bool {
 		is(atty::Stream::Stderr) && is(atty::Stream::Stdout)
 	}
 
-	/// Reset the highlighter to the start of the source code
-	pub fn reset(&mut self) {}
+	/// Reset the highlighter to the start of the source code, and highlight the whole source again
+	pub fn reset(&mut self) {
+		self.cur = 0;
+		self.cur_idx = 0;
+		self.range = 0..self.source.len();
+	}
+
+	/// Only highlight the given byte range of the source code, moving the highlighter to its
+	/// start. The tokens crossing the bounds of the range are cut to fit in it.
+	///
+	/// # Panics
+	///
+	/// If the bounds of the range aren't on char boundaries of the source.
+	pub fn set_range(&mut self, range: Range<usize>) {
+		let end = range.end.min(self.source.len());
+		let start = range.start.min(end);
+		assert!(
+			self.source.is_char_boundary(start) && self.source.is_char_boundary(end),
+			"the range {:?} isn't on char boundaries",
+			range
+		);
+
+		self.cur = 0;
+		self.cur_idx = 0;
+		self.range = start..end;
+
+		while let Some(token) = self.tokens.get(self.cur) {
+			if self.cur_idx + token.len > start {
+				break;
+			}
+			self.cur_idx += token.len;
+			self.cur += 1;
+		}
+	}
 
 	/// Consume the rest of the highlighter's tokens and turn them into an ANSI colored string.
 	/// This returns an unaltered string if stdout and stderr are not terminals.
 	pub fn color(&mut self) -> String {
 		if !self.check_terminal() {
-			let ret = self.source[self.cur_idx..self.source.len()].to_string();
-			self.cur = self.tokens.len();
-			self.cur_idx = self.source.len();
+			let mut ret = String::new();
+			while let Some((_, text)) = self.next_token() {
+				ret.push_str(text);
+			}
 			return ret;
 		}
 
 		self.map(|x| x.to_string()).collect()
 	}
 
-	fn src(&self) -> &'s str {
-		&self.source[self.cur_idx..self.cur_idx + self.tokens.get(self.cur).unwrap().len]
+	/// Consume the rest of the highlighter's tokens and turn them into HTML.
+	///
+	/// Each token is wrapped in a `<span>` with the classes `token` and the name of its
+	/// [`TokenClass`], e.g. `<span class="token keyword">let</span>`, the whitespace is left as is.
+	/// [`Theme::css`] returns the matching style sheet.
+	pub fn html(&mut self) -> String {
+		let mut html = String::new();
+		while let Some((class, text)) = self.next_token() {
+			if class == TokenClass::Plain {
+				push_escaped(&mut html, text);
+			} else {
+				html.push_str("<span class=\"token ");
+				html.push_str(class.as_str());
+				html.push_str("\">");
+				push_escaped(&mut html, text);
+				html.push_str("</span>");
+			}
+		}
+		html
+	}
+
+	/// Returns the next token in the range with its class, and advances the highlighter
+	fn next_token(&mut self) -> Option<(TokenClass, &'s str)> {
+		loop {
+			let token = self.tokens.get(self.cur)?;
+			let start = self.cur_idx;
+			let end = start + token.len;
+			if start >= self.range.end {
+				return None;
+			}
+
+			self.cur_idx = end;
+			self.cur += 1;
+
+			let text = &self.source[start.max(self.range.start)..end.min(self.range.end)];
+			if !text.is_empty() {
+				let class = TokenClass::new(token.kind, &self.source[start..end]);
+				return Some((class, text));
+			}
+		}
+	}
+}
+
+/// Escapes the characters of `text` that have a meaning in HTML
+fn push_escaped(html: &mut String, text: &str) {
+	for c in text.chars() {
+		match c {
+			'&' => html.push_str("&amp;"),
+			'<' => html.push_str("&lt;"),
+			'>' => html.push_str("&gt;"),
+			'"' => html.push_str("&quot;"),
+			'\'' => html.push_str("&#39;"),
+			c => html.push(c),
+		}
 	}
 }
 
@@ -99,41 +197,224 @@ const BUILTINS: [&str; 27] = [
 	"JSON",
 ];
 
-impl<'s> Iterator for Highlighter<'s> {
-	/// An individual colored token, you can see the color used by checking the string's style foreground
-	type Item = ANSIGenericString<'s, str>;
+/// The class of a highlighted token, which decides its style in a [`Theme`] and its CSS class
+/// in HTML
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenClass {
+	/// Braces and parentheses
+	Bracket,
+	/// `import` and `from`
+	Import,
+	Keyword,
+	/// The global objects of JavaScript, like `Math` or `Promise`
+	Builtin,
+	Identifier,
+	Punctuation,
+	String,
+	Number,
+	Regex,
+	/// The `${` starting an expression in a template
+	Interpolation,
+	Comment,
+	/// An invalid token
+	Error,
+	/// Whitespace and any other token
+	Plain,
+}
 
-	fn next(&mut self) -> Option<Self::Item> {
-		if self.tokens.get(self.cur) == None {
-			return None;
-		}
+impl TokenClass {
+	/// All the token classes, in the order of their discriminant.
+	pub const ALL: [TokenClass; 13] = [
+		TokenClass::Bracket,
+		TokenClass::Import,
+		TokenClass::Keyword,
+		TokenClass::Builtin,
+		TokenClass::Identifier,
+		TokenClass::Punctuation,
+		TokenClass::String,
+		TokenClass::Number,
+		TokenClass::Regex,
+		TokenClass::Interpolation,
+		TokenClass::Comment,
+		TokenClass::Error,
+		TokenClass::Plain,
+	];
 
-		let color = match self.tokens.get(self.cur)?.kind {
-			T!['{'] | T!['}'] | T!['('] | T![')'] => rgb![255, 215, 0],
-			T![import] => rgb![97, 175, 239],
-			T![ident] if PURPLE_IDENT.contains(&self.src()) => rgb![198, 120, 221],
-			T![ident] if self.src() == "from" => rgb![97, 175, 239],
-			T![ident] if BUILTINS.contains(&self.src()) => rgb![229, 192, 123],
-			T![ident] => rgb![224, 108, 117],
+	/// Classify a token from its kind and its text
+	pub fn new(kind: SyntaxKind, text: &str) -> Self {
+		match kind {
+			T!['{'] | T!['}'] | T!['('] | T![')'] => TokenClass::Bracket,
+			T![import] => TokenClass::Import,
+			T![ident] if PURPLE_IDENT.contains(&text) => TokenClass::Keyword,
+			T![ident] if text == "from" => TokenClass::Import,
+			T![ident] if BUILTINS.contains(&text) => TokenClass::Builtin,
+			T![ident] => TokenClass::Identifier,
 			T![instanceof] | T![new] | T![?] | T![delete] | T![:] | T![const] => {
-				rgb![198, 120, 221]
+				TokenClass::Keyword
 			}
-			t if t.is_punct() => rgb![86, 182, 194],
-			t if t.is_keyword() => rgb![198, 120, 221],
+			t if t.is_punct() => TokenClass::Punctuation,
+			t if t.is_keyword() => TokenClass::Keyword,
 			SyntaxKind::JS_STRING_LITERAL | SyntaxKind::BACKTICK | SyntaxKind::TEMPLATE_CHUNK => {
-				rgb![152, 195, 121]
+				TokenClass::String
+			}
+			SyntaxKind::JS_NUMBER_LITERAL | SyntaxKind::JS_BIG_INT_LITERAL => TokenClass::Number,
+			SyntaxKind::JS_REGEX_LITERAL => TokenClass::Regex,
+			SyntaxKind::DOLLAR_CURLY => TokenClass::Interpolation,
+			// Unterminated strings and comments are still highlighted as such, so that the
+			// rest of the source isn't shown as an error
+			SyntaxKind::ERROR_TOKEN if text.starts_with(&['"', '\''][..]) => TokenClass::String,
+			SyntaxKind::ERROR_TOKEN if text.starts_with("/*") => TokenClass::Comment,
+			SyntaxKind::ERROR_TOKEN => TokenClass::Error,
+			SyntaxKind::COMMENT => TokenClass::Comment,
+			_ => TokenClass::Plain,
+		}
+	}
+
+	/// The name of the class, used as a CSS class in HTML
+	pub fn as_str(self) -> &'static str {
+		match self {
+			TokenClass::Bracket => "bracket",
+			TokenClass::Import => "import",
+			TokenClass::Keyword => "keyword",
+			TokenClass::Builtin => "builtin",
+			TokenClass::Identifier => "identifier",
+			TokenClass::Punctuation => "punctuation",
+			TokenClass::String => "string",
+			TokenClass::Number => "number",
+			TokenClass::Regex => "regex",
+			TokenClass::Interpolation => "interpolation",
+			TokenClass::Comment => "comment",
+			TokenClass::Error => "error",
+			TokenClass::Plain => "plain",
+		}
+	}
+}
+
+/// The styles used to highlight each [`TokenClass`].
+///
+/// ```
+/// use rslint_lexer::{Color, Highlighter, Style, Theme, TokenClass};
+///
+/// let theme = Theme::plain().with_style(TokenClass::Keyword, Color::Red.bold());
+/// assert_eq!(theme.css(), ".token.keyword { color: red; font-weight: bold; }\n");
+///
+/// let mut highlighter = Highlighter::new("let a = 1;").with_theme(theme);
+/// assert_eq!(highlighter.next(), Some(Color::Red.bold().paint("let")));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+	styles: [Style; TokenClass::ALL.len()],
+}
+
+impl Theme {
+	/// A theme without any style
+	pub fn plain() -> Self {
+		Self {
+			styles: [Style::new(); TokenClass::ALL.len()],
+		}
+	}
+
+	/// The default theme, inspired by One Dark
+	pub fn one_dark() -> Self {
+		let mut theme = Self::plain();
+		let colors = [
+			(TokenClass::Bracket, rgb![255, 215, 0]),
+			(TokenClass::Import, rgb![97, 175, 239]),
+			(TokenClass::Keyword, rgb![198, 120, 221]),
+			(TokenClass::Builtin, rgb![229, 192, 123]),
+			(TokenClass::Identifier, rgb![224, 108, 117]),
+			(TokenClass::Punctuation, rgb![86, 182, 194]),
+			(TokenClass::String, rgb![152, 195, 121]),
+			(TokenClass::Number, rgb![209, 154, 102]),
+			(TokenClass::Regex, rgb![86, 182, 194]),
+			(TokenClass::Interpolation, rgb![198, 120, 221]),
+			(TokenClass::Comment, rgb![127, 132, 142]),
+			(TokenClass::Error, rgb![244, 71, 71]),
+			(TokenClass::Plain, Color::White),
+		];
+		for (class, color) in colors {
+			theme.styles[class as usize] = color.normal();
+		}
+		theme
+	}
+
+	/// Changes the style of a class of tokens
+	pub fn with_style(mut self, class: TokenClass, style: Style) -> Self {
+		self.styles[class as usize] = style;
+		self
+	}
+
+	pub fn style(&self, class: TokenClass) -> Style {
+		self.styles[class as usize]
+	}
+
+	/// Returns a CSS style sheet matching the HTML of [`Highlighter::html`].
+	///
+	/// Only the foreground colors, bold, italic and underline styles are supported, the classes
+	/// without any of them are omitted.
+	pub fn css(&self) -> String {
+		let mut css = String::new();
+		for class in TokenClass::ALL {
+			let style = self.style(class);
+			let mut declarations = Vec::new();
+			if let Some(color) = style.foreground.and_then(css_color) {
+				declarations.push(format!("color: {};", color));
+			}
+			if style.is_bold {
+				declarations.push("font-weight: bold;".to_string());
+			}
+			if style.is_italic {
+				declarations.push("font-style: italic;".to_string());
 			}
-			SyntaxKind::JS_NUMBER_LITERAL => rgb![209, 154, 102],
-			SyntaxKind::DOLLAR_CURLY => rgb![198, 120, 221],
-			SyntaxKind::ERROR_TOKEN => rgb![244, 71, 71],
-			SyntaxKind::COMMENT => rgb![127, 132, 142],
-			_ => Color::White,
-		};
-
-		let string = self.src();
-		self.cur_idx += self.tokens.get(self.cur).unwrap().len;
-		self.cur += 1;
-		Some(color.paint(string))
+			if style.is_underline {
+				declarations.push("text-decoration: underline;".to_string());
+			}
+
+			if !declarations.is_empty() {
+				// Writing to a string can't fail
+				writeln!(
+					css,
+					".token.{} {{ {} }}",
+					class.as_str(),
+					declarations.join(" ")
+				)
+				.unwrap();
+			}
+		}
+		css
+	}
+}
+
+impl Default for Theme {
+	fn default() -> Self {
+		Self::one_dark()
+	}
+}
+
+/// Returns the CSS equivalent of a color, the 256 colors palette isn't supported
+fn css_color(color: Color) -> Option<String> {
+	let name = match color {
+		Color::Black => "black",
+		Color::Red => "red",
+		Color::Green => "green",
+		Color::Yellow => "yellow",
+		Color::Blue => "blue",
+		Color::Purple => "purple",
+		Color::Cyan => "cyan",
+		Color::White => "white",
+		Color::RGB(r, g, b) => return Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+		Color::Fixed(_) => return None,
+	};
+	Some(name.to_string())
+}
+
+impl<'s> Iterator for Highlighter<'s> {
+	/// An individual colored token, you can see the color used by checking the string's style foreground
+	type Item = ANSIGenericString<'s, str>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (class, text) = self.next_token()?;
+		Some(self.theme.style(class).paint(text))
 	}
 }
 
@@ -142,3 +423,88 @@ impl<'s> Iterator for Highlighter<'s> {
 pub fn color(source: &str) -> String {
 	Highlighter::new(source).color()
 }
+
+/// Highlights a piece of source code using HTML, see [`Highlighter::html`].
+pub fn html(source: &str) -> String {
+	Highlighter::new(source).html()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn html() {
+		assert_eq!(
+			Highlighter::new("if (a < 1) {}").html(),
+			"<span class=\"token keyword\">if</span> \
+			<span class=\"token bracket\">(</span>\
+			<span class=\"token identifier\">a</span> \
+			<span class=\"token punctuation\">&lt;</span> \
+			<span class=\"token number\">1</span>\
+			<span class=\"token bracket\">)</span> \
+			<span class=\"token bracket\">{</span>\
+			<span class=\"token bracket\">}</span>"
+		);
+	}
+
+	#[test]
+	fn lexer_errors_keep_the_text() {
+		assert_eq!(
+			Highlighter::new("a = \"abc").html(),
+			"<span class=\"token identifier\">a</span> \
+			<span class=\"token punctuation\">=</span> \
+			<span class=\"token string\">&quot;abc</span>"
+		);
+		assert_eq!(
+			Highlighter::new("1e ¤").html(),
+			"<span class=\"token error\">1e</span> <span class=\"token error\">¤</span>"
+		);
+	}
+
+	#[test]
+	fn reset_and_range() {
+		let mut highlighter = Highlighter::new("let abc = 'def';");
+		assert_eq!(highlighter.by_ref().take(2).count(), 2);
+
+		highlighter.reset();
+		let theme = Theme::one_dark();
+		assert_eq!(
+			highlighter.next(),
+			Some(theme.style(TokenClass::Keyword).paint("let"))
+		);
+
+		// The range starts and ends in the middle of tokens
+		highlighter.set_range(5..13);
+		let texts: Vec<_> = highlighter.map(|t| t.to_string()).collect();
+		assert_eq!(
+			texts,
+			[
+				theme.style(TokenClass::Identifier).paint("bc").to_string(),
+				Style::default().fg(Color::White).paint(" ").to_string(),
+				theme.style(TokenClass::Punctuation).paint("=").to_string(),
+				Style::default().fg(Color::White).paint(" ").to_string(),
+				theme.style(TokenClass::String).paint("'de").to_string(),
+			]
+		);
+
+		let mut highlighter = Highlighter::new("let abc = 'def';");
+		highlighter.set_range(4..7);
+		assert_eq!(
+			highlighter.html(),
+			"<span class=\"token identifier\">abc</span>"
+		);
+	}
+
+	#[test]
+	fn css() {
+		let theme = Theme::plain()
+			.with_style(TokenClass::String, Color::RGB(152, 195, 121).bold())
+			.with_style(TokenClass::Comment, Style::new().italic());
+		assert_eq!(
+			theme.css(),
+			".token.string { color: #98c379; font-weight: bold; }\n\
+			.token.comment { font-style: italic; }\n"
+		);
+	}
+}
@@ -6,7 +6,7 @@
 //! therefore making a lexer from a `&[u8]` is unsafe since you must make sure the bytes are valid utf8.
 //! Do not use this to learn how to lex JavaScript, this is just needlessly fast and demonic because i can't control myself :)
 //!
-//! basic ANSI syntax highlighting is also offered through the `highlight` feature.
+//! basic syntax highlighting to ANSI or HTML, with configurable themes, is also offered through the `highlight` feature.
 //!
 //! # Warning ⚠️
 //!
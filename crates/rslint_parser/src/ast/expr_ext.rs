@@ -383,6 +383,16 @@ impl JsArrowFunctionExpression {
 }
 
 impl Template {
+	/// The closing tick, which has the same kind as the opening tick
+	pub fn r_tick_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|e| e.into_token())
+			.filter(|t| t.kind() == BACKTICK)
+			.nth(1)
+			.ok_or_else(|| SyntaxError::MissingRequiredChild(self.syntax().clone()))
+	}
+
 	/// The string chunks of the template. aka:
 	/// `foo ${bar} foo` breaks down into:
 	/// `QUASIS ELEMENT{EXPR} QUASIS`
@@ -444,6 +444,7 @@ impl JsClassDeclaration {
 		support::required_token(&self.syntax, T![class])
 	}
 	pub fn id(&self) -> SyntaxResult<JsAnyBinding> { support::required_node(&self.syntax) }
+	pub fn type_parameters(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn implements_clause(&self) -> Option<TsImplementsClause> { support::node(&self.syntax) }
 	pub fn extends_clause(&self) -> Option<JsExtendsClause> { support::node(&self.syntax) }
 	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -463,6 +464,7 @@ impl JsClassExpression {
 		support::required_token(&self.syntax, T![class])
 	}
 	pub fn id(&self) -> Option<JsAnyBinding> { support::node(&self.syntax) }
+	pub fn type_parameters(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn extends_clause(&self) -> Option<JsExtendsClause> { support::node(&self.syntax) }
 	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['{'])
@@ -678,6 +680,7 @@ impl JsExtendsClause {
 	pub fn super_class(&self) -> SyntaxResult<JsAnyExpression> {
 		support::required_node(&self.syntax)
 	}
+	pub fn type_arguments(&self) -> Option<TsTypeArgs> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsFinallyClause {
@@ -1442,6 +1445,7 @@ impl JsRestParameter {
 	pub fn binding(&self) -> SyntaxResult<JsAnyBindingPattern> {
 		support::required_node(&self.syntax)
 	}
+	pub fn type_annotation(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsReturnStatement {
@@ -1803,13 +1807,12 @@ pub struct Template {
 	pub(crate) syntax: SyntaxNode,
 }
 impl Template {
+	pub fn tag(&self) -> Option<JsAnyExpression> { support::node(&self.syntax) }
+	pub fn type_args(&self) -> Option<TsTypeArgs> { support::node(&self.syntax) }
 	pub fn l_tick_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['`'])
 	}
 	pub fn elements(&self) -> TemplateElementList { support::list(&self.syntax) }
-	pub fn r_tick_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T!['`'])
-	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TemplateChunkElement {
@@ -1856,7 +1859,7 @@ pub struct TsAny {
 }
 impl TsAny {
 	pub fn any_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![any])
+		support::required_token(&self.syntax, IDENT)
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -1864,10 +1867,10 @@ pub struct TsArray {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsArray {
+	pub fn element_type(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['['])
 	}
-	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![']'])
 	}
@@ -1892,40 +1895,44 @@ pub struct TsBigint {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsBigint {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn bigint_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsBoolean {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsBoolean {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn boolean_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsCallSignatureDecl {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsCallSignatureDecl {
-	pub fn type_params(&self) -> SyntaxResult<TsTypeParams> { support::required_node(&self.syntax) }
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn parameters(&self) -> SyntaxResult<JsParameters> { support::required_node(&self.syntax) }
-	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [:])
+	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
+	pub fn return_type(&self) -> Option<TsType> { support::node(&self.syntax) }
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [;], T ! [,]])
 	}
-	pub fn return_type(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsConditionalType {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsConditionalType {
-	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn extends(&self) -> SyntaxResult<TsExtends> { support::required_node(&self.syntax) }
 	pub fn question_mark_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [?])
 	}
 	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [:])
 	}
-	pub fn extends(&self) -> SyntaxResult<TsExtends> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsConstAssertion {
@@ -1962,20 +1969,26 @@ impl TsConstructSignatureDecl {
 	pub fn new_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![new])
 	}
-	pub fn type_params(&self) -> SyntaxResult<TsTypeParams> { support::required_node(&self.syntax) }
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn parameters(&self) -> SyntaxResult<JsParameters> { support::required_node(&self.syntax) }
 	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
-	pub fn return_type(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn return_type(&self) -> Option<TsType> { support::node(&self.syntax) }
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [;], T ! [,]])
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsConstructorParam {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsConstructorParam {
+	pub fn access_modifier(&self) -> Option<TsAccessibility> { support::node(&self.syntax) }
 	pub fn readonly_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T![readonly])
 	}
-	pub fn pat(&self) -> SyntaxResult<JsAnyBindingPattern> { support::required_node(&self.syntax) }
+	pub fn parameter(&self) -> SyntaxResult<TsAnyConstructorParamParameter> {
+		support::required_node(&self.syntax)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsConstructorType {
@@ -1985,11 +1998,12 @@ impl TsConstructorType {
 	pub fn new_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![new])
 	}
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn params(&self) -> SyntaxResult<JsParameters> { support::required_node(&self.syntax) }
-	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [:])
+	pub fn fat_arrow_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T ! [=>])
 	}
-	pub fn return_type(&self) -> Option<TsType> { support::node(&self.syntax) }
+	pub fn return_type(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsDefault {
@@ -2006,11 +2020,12 @@ pub struct TsEnum {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsEnum {
+	pub fn declare_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![declare]) }
 	pub fn const_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![const]) }
 	pub fn enum_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![enum])
 	}
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn name(&self) -> SyntaxResult<JsName> { support::required_node(&self.syntax) }
 	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['{'])
 	}
@@ -2024,11 +2039,11 @@ pub struct TsEnumMember {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsEnumMember {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
-	pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [=])
+	pub fn name(&self) -> SyntaxResult<SyntaxToken> {
+		support::find_required_token(&self.syntax, &[IDENT, JS_STRING_LITERAL])
 	}
-	pub fn value(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
+	pub fn eq_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [=]) }
+	pub fn value(&self) -> Option<JsAnyExpression> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsExportAssignment {
@@ -2050,7 +2065,7 @@ pub struct TsExprWithTypeArgs {
 }
 impl TsExprWithTypeArgs {
 	pub fn item(&self) -> SyntaxResult<TsEntityName> { support::required_node(&self.syntax) }
-	pub fn type_params(&self) -> SyntaxResult<TsTypeArgs> { support::required_node(&self.syntax) }
+	pub fn type_args(&self) -> Option<TsTypeArgs> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsExtends {
@@ -2085,11 +2100,12 @@ pub struct TsFnType {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsFnType {
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn params(&self) -> SyntaxResult<JsParameters> { support::required_node(&self.syntax) }
 	pub fn fat_arrow_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [=>])
 	}
-	pub fn return_type(&self) -> Option<TsType> { support::node(&self.syntax) }
+	pub fn return_type(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsImplementsClause {
@@ -2109,15 +2125,15 @@ impl TsImport {
 	pub fn import_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![import])
 	}
-	pub fn type_args(&self) -> SyntaxResult<TsTypeArgs> { support::required_node(&self.syntax) }
-	pub fn dot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [.]) }
 	pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['('])
 	}
-	pub fn qualifier(&self) -> SyntaxResult<TsEntityName> { support::required_node(&self.syntax) }
 	pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![')'])
 	}
+	pub fn dot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [.]) }
+	pub fn qualifier(&self) -> Option<TsEntityName> { support::node(&self.syntax) }
+	pub fn type_args(&self) -> Option<TsTypeArgs> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsImportEqualsDecl {
@@ -2148,13 +2164,21 @@ impl TsIndexSignature {
 	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['['])
 	}
-	pub fn pat(&self) -> SyntaxResult<JsAnyBinding> { support::required_node(&self.syntax) }
+	pub fn parameter(&self) -> SyntaxResult<JsIdentifierBinding> {
+		support::required_node(&self.syntax)
+	}
+	pub fn parameter_type(&self) -> SyntaxResult<TsTypeAnnotation> {
+		support::required_node(&self.syntax)
+	}
+	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![']'])
+	}
 	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [:])
 	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
-	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![']'])
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [;], T ! [,]])
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2165,7 +2189,6 @@ impl TsIndexedArray {
 	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['['])
 	}
-	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![']'])
 	}
@@ -2178,7 +2201,7 @@ impl TsInfer {
 	pub fn infer_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![infer])
 	}
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn name(&self) -> SyntaxResult<JsName> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsInterfaceDecl {
@@ -2187,15 +2210,15 @@ pub struct TsInterfaceDecl {
 impl TsInterfaceDecl {
 	pub fn declare_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![declare]) }
 	pub fn interface_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![interface])
+		support::required_token(&self.syntax, IDENT)
 	}
-	pub fn type_params(&self) -> SyntaxResult<TsTypeParams> { support::required_node(&self.syntax) }
+	pub fn name(&self) -> SyntaxResult<JsName> { support::required_node(&self.syntax) }
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn extends_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![extends]) }
-	pub fn extends(&self) -> Option<TsExprWithTypeArgs> { support::node(&self.syntax) }
 	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['{'])
 	}
-	pub fn members(&self) -> SyntaxResult<TsTypeElement> { support::required_node(&self.syntax) }
+	pub fn members(&self) -> TsObjectMemberList { support::list(&self.syntax) }
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
 	}
@@ -2205,14 +2228,20 @@ pub struct TsIntersection {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsIntersection {
-	pub fn types(&self) -> TsTypeList { support::list(&self.syntax) }
+	pub fn leading_separator_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [&])
+	}
+	pub fn types(&self) -> TsIntersectionTypeElementList { support::list(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsLiteral {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsLiteral {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn minus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [-]) }
+	pub fn literal(&self) -> SyntaxResult<JsAnyLiteralExpression> {
+		support::required_node(&self.syntax)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsMappedType {
@@ -2223,41 +2252,51 @@ impl TsMappedType {
 		support::required_token(&self.syntax, T!['{'])
 	}
 	pub fn readonly_modifier(&self) -> Option<TsMappedTypeReadonly> { support::node(&self.syntax) }
-	pub fn minus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [-]) }
-	pub fn plus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [+]) }
+	pub fn param(&self) -> SyntaxResult<TsMappedTypeParam> { support::required_node(&self.syntax) }
+	pub fn optional_modifier_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [+], T ! [-]])
+	}
 	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T ! [?])
 	}
-	pub fn param(&self) -> SyntaxResult<TsMappedTypeParam> { support::required_node(&self.syntax) }
 	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [:])
 	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
 	}
-	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsMappedTypeParam {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsMappedTypeParam {
-	pub fn l_brack_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['[']) }
-	pub fn name(&self) -> Option<TsTypeName> { support::node(&self.syntax) }
-	pub fn r_brack_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![']']) }
-	pub fn ident(&self) -> Option<Ident> { support::node(&self.syntax) }
-	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['['])
+	}
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
+	pub fn in_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![in])
+	}
+	pub fn keys(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![']'])
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsMappedTypeReadonly {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsMappedTypeReadonly {
-	pub fn minus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [-]) }
-	pub fn plus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [+]) }
-	pub fn readonly_token(&self) -> Option<SyntaxToken> {
-		support::token(&self.syntax, T![readonly])
+	pub fn operator_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [+], T ! [-]])
+	}
+	pub fn readonly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![readonly])
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2265,19 +2304,19 @@ pub struct TsMethodSignature {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsMethodSignature {
-	pub fn readonly_token(&self) -> Option<SyntaxToken> {
-		support::token(&self.syntax, T![readonly])
+	pub fn name(&self) -> SyntaxResult<TsAnyPropertySignatureName> {
+		support::required_node(&self.syntax)
 	}
-	pub fn key(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
-	pub fn type_params(&self) -> SyntaxResult<TsTypeParams> { support::required_node(&self.syntax) }
-	pub fn parameters(&self) -> SyntaxResult<JsParameters> { support::required_node(&self.syntax) }
 	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T ! [?])
 	}
-	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [:])
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
+	pub fn parameters(&self) -> SyntaxResult<JsParameters> { support::required_node(&self.syntax) }
+	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
+	pub fn return_type(&self) -> Option<TsType> { support::node(&self.syntax) }
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [;], T ! [,]])
 	}
-	pub fn return_type(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsModuleBlock {
@@ -2343,7 +2382,7 @@ pub struct TsNever {
 }
 impl TsNever {
 	pub fn never_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![never])
+		support::required_token(&self.syntax, IDENT)
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2370,14 +2409,18 @@ pub struct TsNumber {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsNumber {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn number_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsObject {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsObject {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn object_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsObjectType {
@@ -2410,8 +2453,7 @@ pub struct TsPredicate {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsPredicate {
-	pub fn lhs(&self) -> SyntaxResult<TsThisOrMore> { support::required_node(&self.syntax) }
-	pub fn rhs(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn parameter_name(&self) -> Option<TsThisOrMore> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsPropertySignature {
@@ -2421,14 +2463,17 @@ impl TsPropertySignature {
 	pub fn readonly_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T![readonly])
 	}
-	pub fn prop(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
-	pub fn question_mark_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [?])
+	pub fn name(&self) -> SyntaxResult<TsAnyPropertySignatureName> {
+		support::required_node(&self.syntax)
 	}
-	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [:])
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
+	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
+	pub fn ty(&self) -> Option<TsType> { support::node(&self.syntax) }
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		support::find_token(&self.syntax, &[T ! [;], T ! [,]])
 	}
-	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsQualifiedPath {
@@ -2439,29 +2484,32 @@ impl TsQualifiedPath {
 	pub fn dot_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [.])
 	}
-	pub fn rhs(&self) -> SyntaxResult<TsTypeName> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsString {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsString {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn string_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsSymbol {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsSymbol {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn symbol_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTemplate {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTemplate {
-	pub fn elements(&self) -> SyntaxResult<TsTemplateElement> {
-		support::required_node(&self.syntax)
+	pub fn l_tick_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['`'])
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2469,6 +2517,9 @@ pub struct TsTemplateElement {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTemplateElement {
+	pub fn dollar_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, DOLLAR_CURLY)
+	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
@@ -2491,7 +2542,6 @@ impl TsTuple {
 	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['['])
 	}
-	pub fn elements(&self) -> SyntaxResult<TsTupleElement> { support::required_node(&self.syntax) }
 	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![']'])
 	}
@@ -2501,14 +2551,9 @@ pub struct TsTupleElement {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTupleElement {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
-	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [:])
-	}
-	pub fn question_mark_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T ! [?])
-	}
 	pub fn dotdotdot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [...]) }
+	pub fn name(&self) -> Option<JsName> { support::node(&self.syntax) }
+	pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [:]) }
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2516,14 +2561,17 @@ pub struct TsTypeAliasDecl {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeAliasDecl {
+	pub fn declare_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![declare]) }
 	pub fn type_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![type])
+		support::required_token(&self.syntax, IDENT)
 	}
-	pub fn type_params(&self) -> SyntaxResult<TsTypeParams> { support::required_node(&self.syntax) }
+	pub fn name(&self) -> SyntaxResult<JsName> { support::required_node(&self.syntax) }
+	pub fn type_params(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [=])
 	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeAnnotation {
@@ -2543,7 +2591,7 @@ impl TsTypeArgs {
 	pub fn l_angle_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [<])
 	}
-	pub fn ts_type_arg_list(&self) -> TsTypeArgList { support::list(&self.syntax) }
+	pub fn args(&self) -> TsTypeArgList { support::list(&self.syntax) }
 	pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [>])
 	}
@@ -2553,13 +2601,18 @@ pub struct TsTypeName {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeName {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeOperator {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeOperator {
+	pub fn operator_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::find_required_token(&self.syntax, &[T![keyof], T![unique], T![readonly]])
+	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2567,18 +2620,33 @@ pub struct TsTypeParam {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeParam {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
-	pub fn constraint(&self) -> SyntaxResult<TsConstraint> { support::required_node(&self.syntax) }
-	pub fn default(&self) -> SyntaxResult<TsDefault> { support::required_node(&self.syntax) }
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, IDENT)
+	}
+	pub fn constraint(&self) -> Option<TsConstraint> { support::node(&self.syntax) }
+	pub fn default(&self) -> Option<TsDefault> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeParams {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeParams {
-	pub fn l_angle_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [<]) }
-	pub fn params(&self) -> SyntaxResult<TsTypeParam> { support::required_node(&self.syntax) }
-	pub fn r_angle_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [>]) }
+	pub fn l_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T ! [<])
+	}
+	pub fn params(&self) -> TsTypeParamList { support::list(&self.syntax) }
+	pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T ! [>])
+	}
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsTypeQuery {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsTypeQuery {
+	pub fn typeof_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![typeof])
+	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeRef {
@@ -2586,7 +2654,21 @@ pub struct TsTypeRef {
 }
 impl TsTypeRef {
 	pub fn name(&self) -> SyntaxResult<TsEntityName> { support::required_node(&self.syntax) }
-	pub fn type_args(&self) -> SyntaxResult<TsTypeArgs> { support::required_node(&self.syntax) }
+	pub fn type_args(&self) -> Option<TsTypeArgs> { support::node(&self.syntax) }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsTypedParameter {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsTypedParameter {
+	pub fn binding(&self) -> SyntaxResult<JsAnyBindingPattern> {
+		support::required_node(&self.syntax)
+	}
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
+	pub fn type_annotation(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
+	pub fn initializer(&self) -> Option<JsInitializerClause> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsUndefined {
@@ -2594,7 +2676,7 @@ pub struct TsUndefined {
 }
 impl TsUndefined {
 	pub fn undefined_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![undefined])
+		support::required_token(&self.syntax, IDENT)
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2602,7 +2684,10 @@ pub struct TsUnion {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsUnion {
-	pub fn types(&self) -> TsTypeList { support::list(&self.syntax) }
+	pub fn leading_separator_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [|])
+	}
+	pub fn types(&self) -> TsUnionTypeVariantList { support::list(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsUnknown {
@@ -2610,7 +2695,7 @@ pub struct TsUnknown {
 }
 impl TsUnknown {
 	pub fn unknown_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![unknown])
+		support::required_token(&self.syntax, IDENT)
 	}
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2718,6 +2803,7 @@ pub enum JsAnyConstructorParameter {
 	JsAnyBindingPattern(JsAnyBindingPattern),
 	JsBindingPatternWithDefault(JsBindingPatternWithDefault),
 	TsConstructorParam(TsConstructorParam),
+	TsTypedParameter(TsTypedParameter),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum JsAnyExportDeclaration {
@@ -2853,6 +2939,7 @@ pub enum JsAnyParameter {
 	JsAnyBindingPattern(JsAnyBindingPattern),
 	JsBindingPatternWithDefault(JsBindingPatternWithDefault),
 	JsRestParameter(JsRestParameter),
+	TsTypedParameter(TsTypedParameter),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum JsAnyRoot {
@@ -2896,6 +2983,24 @@ pub enum JsAnySwitchClause {
 	JsDefaultClause(JsDefaultClause),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub enum TsAnyConstructorParamParameter {
+	JsAnyBindingPattern(JsAnyBindingPattern),
+	JsBindingPatternWithDefault(JsBindingPatternWithDefault),
+	TsTypedParameter(TsTypedParameter),
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum TsAnyPropertySignatureName {
+	JsComputedMemberName(JsComputedMemberName),
+	JsName(JsName),
+	JsNumberLiteralExpression(JsNumberLiteralExpression),
+	JsStringLiteralExpression(JsStringLiteralExpression),
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum TsAnyTemplateElement {
+	TemplateChunkElement(TemplateChunkElement),
+	TsTemplateElement(TsTemplateElement),
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum TsEntityName {
 	TsQualifiedPath(TsQualifiedPath),
 	TsTypeName(TsTypeName),
@@ -2943,6 +3048,7 @@ pub enum TsType {
 	TsThis(TsThis),
 	TsTuple(TsTuple),
 	TsTypeOperator(TsTypeOperator),
+	TsTypeQuery(TsTypeQuery),
 	TsTypeRef(TsTypeRef),
 	TsUndefined(TsUndefined),
 	TsUnion(TsUnion),
@@ -3757,6 +3863,10 @@ impl std::fmt::Debug for JsClassDeclaration {
 				&support::DebugSyntaxResult(self.class_token()),
 			)
 			.field("id", &support::DebugSyntaxResult(self.id()))
+			.field(
+				"type_parameters",
+				&support::DebugOptionalElement(self.type_parameters()),
+			)
 			.field(
 				"implements_clause",
 				&support::DebugOptionalElement(self.implements_clause()),
@@ -3796,6 +3906,10 @@ impl std::fmt::Debug for JsClassExpression {
 				&support::DebugSyntaxResult(self.class_token()),
 			)
 			.field("id", &support::DebugOptionalElement(self.id()))
+			.field(
+				"type_parameters",
+				&support::DebugOptionalElement(self.type_parameters()),
+			)
 			.field(
 				"extends_clause",
 				&support::DebugOptionalElement(self.extends_clause()),
@@ -4242,6 +4356,10 @@ impl std::fmt::Debug for JsExtendsClause {
 				"super_class",
 				&support::DebugSyntaxResult(self.super_class()),
 			)
+			.field(
+				"type_arguments",
+				&support::DebugOptionalElement(self.type_arguments()),
+			)
 			.finish()
 	}
 }
@@ -5708,6 +5826,10 @@ impl std::fmt::Debug for JsRestParameter {
 				&support::DebugSyntaxResult(self.dotdotdot_token()),
 			)
 			.field("binding", &support::DebugSyntaxResult(self.binding()))
+			.field(
+				"type_annotation",
+				&support::DebugOptionalElement(self.type_annotation()),
+			)
 			.finish()
 	}
 }
@@ -6389,6 +6511,11 @@ impl AstNode for Template {
 impl std::fmt::Debug for Template {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("Template")
+			.field("tag", &support::DebugOptionalElement(self.tag()))
+			.field(
+				"type_args",
+				&support::DebugOptionalElement(self.type_args()),
+			)
 			.field(
 				"l_tick_token",
 				&support::DebugSyntaxResult(self.l_tick_token()),
@@ -6509,11 +6636,14 @@ impl AstNode for TsArray {
 impl std::fmt::Debug for TsArray {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsArray")
+			.field(
+				"element_type",
+				&support::DebugSyntaxResult(self.element_type()),
+			)
 			.field(
 				"l_brack_token",
 				&support::DebugSyntaxResult(self.l_brack_token()),
 			)
-			.field("ty", &support::DebugSyntaxResult(self.ty()))
 			.field(
 				"r_brack_token",
 				&support::DebugSyntaxResult(self.r_brack_token()),
@@ -6563,7 +6693,10 @@ impl AstNode for TsBigint {
 impl std::fmt::Debug for TsBigint {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsBigint")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"bigint_token",
+				&support::DebugSyntaxResult(self.bigint_token()),
+			)
 			.finish()
 	}
 }
@@ -6581,7 +6714,10 @@ impl AstNode for TsBoolean {
 impl std::fmt::Debug for TsBoolean {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsBoolean")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"boolean_token",
+				&support::DebugSyntaxResult(self.boolean_token()),
+			)
 			.finish()
 	}
 }
@@ -6601,16 +6737,20 @@ impl std::fmt::Debug for TsCallSignatureDecl {
 		f.debug_struct("TsCallSignatureDecl")
 			.field(
 				"type_params",
-				&support::DebugSyntaxResult(self.type_params()),
+				&support::DebugOptionalElement(self.type_params()),
 			)
 			.field("parameters", &support::DebugSyntaxResult(self.parameters()))
 			.field(
 				"colon_token",
-				&support::DebugSyntaxResult(self.colon_token()),
+				&support::DebugOptionalElement(self.colon_token()),
 			)
 			.field(
 				"return_type",
-				&support::DebugSyntaxResult(self.return_type()),
+				&support::DebugOptionalElement(self.return_type()),
+			)
+			.field(
+				"separator_token",
+				&support::DebugOptionalElement(self.separator_token()),
 			)
 			.finish()
 	}
@@ -6629,16 +6769,18 @@ impl AstNode for TsConditionalType {
 impl std::fmt::Debug for TsConditionalType {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsConditionalType")
-			.field("ty", &support::DebugSyntaxResult(self.ty()))
+			.field("check_type", &support::DebugSyntaxResult(self.check_type()))
+			.field("extends", &support::DebugSyntaxResult(self.extends()))
 			.field(
 				"question_mark_token",
 				&support::DebugSyntaxResult(self.question_mark_token()),
 			)
+			.field("true_type", &support::DebugSyntaxResult(self.true_type()))
 			.field(
 				"colon_token",
 				&support::DebugSyntaxResult(self.colon_token()),
 			)
-			.field("extends", &support::DebugSyntaxResult(self.extends()))
+			.field("false_type", &support::DebugSyntaxResult(self.false_type()))
 			.finish()
 	}
 }
@@ -6712,7 +6854,7 @@ impl std::fmt::Debug for TsConstructSignatureDecl {
 			.field("new_token", &support::DebugSyntaxResult(self.new_token()))
 			.field(
 				"type_params",
-				&support::DebugSyntaxResult(self.type_params()),
+				&support::DebugOptionalElement(self.type_params()),
 			)
 			.field("parameters", &support::DebugSyntaxResult(self.parameters()))
 			.field(
@@ -6721,7 +6863,11 @@ impl std::fmt::Debug for TsConstructSignatureDecl {
 			)
 			.field(
 				"return_type",
-				&support::DebugSyntaxResult(self.return_type()),
+				&support::DebugOptionalElement(self.return_type()),
+			)
+			.field(
+				"separator_token",
+				&support::DebugOptionalElement(self.separator_token()),
 			)
 			.finish()
 	}
@@ -6740,11 +6886,15 @@ impl AstNode for TsConstructorParam {
 impl std::fmt::Debug for TsConstructorParam {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsConstructorParam")
+			.field(
+				"access_modifier",
+				&support::DebugOptionalElement(self.access_modifier()),
+			)
 			.field(
 				"readonly_token",
 				&support::DebugOptionalElement(self.readonly_token()),
 			)
-			.field("pat", &support::DebugSyntaxResult(self.pat()))
+			.field("parameter", &support::DebugSyntaxResult(self.parameter()))
 			.finish()
 	}
 }
@@ -6763,14 +6913,18 @@ impl std::fmt::Debug for TsConstructorType {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsConstructorType")
 			.field("new_token", &support::DebugSyntaxResult(self.new_token()))
+			.field(
+				"type_params",
+				&support::DebugOptionalElement(self.type_params()),
+			)
 			.field("params", &support::DebugSyntaxResult(self.params()))
 			.field(
-				"colon_token",
-				&support::DebugSyntaxResult(self.colon_token()),
+				"fat_arrow_token",
+				&support::DebugSyntaxResult(self.fat_arrow_token()),
 			)
 			.field(
 				"return_type",
-				&support::DebugOptionalElement(self.return_type()),
+				&support::DebugSyntaxResult(self.return_type()),
 			)
 			.finish()
 	}
@@ -6808,12 +6962,16 @@ impl AstNode for TsEnum {
 impl std::fmt::Debug for TsEnum {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsEnum")
+			.field(
+				"declare_token",
+				&support::DebugOptionalElement(self.declare_token()),
+			)
 			.field(
 				"const_token",
 				&support::DebugOptionalElement(self.const_token()),
 			)
 			.field("enum_token", &support::DebugSyntaxResult(self.enum_token()))
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field("name", &support::DebugSyntaxResult(self.name()))
 			.field(
 				"l_curly_token",
 				&support::DebugSyntaxResult(self.l_curly_token()),
@@ -6840,9 +6998,9 @@ impl AstNode for TsEnumMember {
 impl std::fmt::Debug for TsEnumMember {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsEnumMember")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
-			.field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
-			.field("value", &support::DebugSyntaxResult(self.value()))
+			.field("name", &support::DebugSyntaxResult(self.name()))
+			.field("eq_token", &support::DebugOptionalElement(self.eq_token()))
+			.field("value", &support::DebugOptionalElement(self.value()))
 			.finish()
 	}
 }
@@ -6889,8 +7047,8 @@ impl std::fmt::Debug for TsExprWithTypeArgs {
 		f.debug_struct("TsExprWithTypeArgs")
 			.field("item", &support::DebugSyntaxResult(self.item()))
 			.field(
-				"type_params",
-				&support::DebugSyntaxResult(self.type_params()),
+				"type_args",
+				&support::DebugOptionalElement(self.type_args()),
 			)
 			.finish()
 	}
@@ -6964,6 +7122,10 @@ impl AstNode for TsFnType {
 impl std::fmt::Debug for TsFnType {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsFnType")
+			.field(
+				"type_params",
+				&support::DebugOptionalElement(self.type_params()),
+			)
 			.field("params", &support::DebugSyntaxResult(self.params()))
 			.field(
 				"fat_arrow_token",
@@ -6971,7 +7133,7 @@ impl std::fmt::Debug for TsFnType {
 			)
 			.field(
 				"return_type",
-				&support::DebugOptionalElement(self.return_type()),
+				&support::DebugSyntaxResult(self.return_type()),
 			)
 			.finish()
 	}
@@ -7016,20 +7178,27 @@ impl std::fmt::Debug for TsImport {
 				"import_token",
 				&support::DebugSyntaxResult(self.import_token()),
 			)
-			.field("type_args", &support::DebugSyntaxResult(self.type_args()))
-			.field(
-				"dot_token",
-				&support::DebugOptionalElement(self.dot_token()),
-			)
 			.field(
 				"l_paren_token",
 				&support::DebugSyntaxResult(self.l_paren_token()),
 			)
-			.field("qualifier", &support::DebugSyntaxResult(self.qualifier()))
+			.field("arg_token", &support::DebugSyntaxResult(self.arg_token()))
 			.field(
 				"r_paren_token",
 				&support::DebugSyntaxResult(self.r_paren_token()),
 			)
+			.field(
+				"dot_token",
+				&support::DebugOptionalElement(self.dot_token()),
+			)
+			.field(
+				"qualifier",
+				&support::DebugOptionalElement(self.qualifier()),
+			)
+			.field(
+				"type_args",
+				&support::DebugOptionalElement(self.type_args()),
+			)
 			.finish()
 	}
 }
@@ -7087,15 +7256,23 @@ impl std::fmt::Debug for TsIndexSignature {
 				"l_brack_token",
 				&support::DebugSyntaxResult(self.l_brack_token()),
 			)
-			.field("pat", &support::DebugSyntaxResult(self.pat()))
+			.field("parameter", &support::DebugSyntaxResult(self.parameter()))
+			.field(
+				"parameter_type",
+				&support::DebugSyntaxResult(self.parameter_type()),
+			)
+			.field(
+				"r_brack_token",
+				&support::DebugSyntaxResult(self.r_brack_token()),
+			)
 			.field(
 				"colon_token",
 				&support::DebugSyntaxResult(self.colon_token()),
 			)
 			.field("ty", &support::DebugSyntaxResult(self.ty()))
 			.field(
-				"r_brack_token",
-				&support::DebugSyntaxResult(self.r_brack_token()),
+				"separator_token",
+				&support::DebugOptionalElement(self.separator_token()),
 			)
 			.finish()
 	}
@@ -7114,11 +7291,15 @@ impl AstNode for TsIndexedArray {
 impl std::fmt::Debug for TsIndexedArray {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsIndexedArray")
+			.field(
+				"object_type",
+				&support::DebugSyntaxResult(self.object_type()),
+			)
 			.field(
 				"l_brack_token",
 				&support::DebugSyntaxResult(self.l_brack_token()),
 			)
-			.field("ty", &support::DebugSyntaxResult(self.ty()))
+			.field("index_type", &support::DebugSyntaxResult(self.index_type()))
 			.field(
 				"r_brack_token",
 				&support::DebugSyntaxResult(self.r_brack_token()),
@@ -7144,7 +7325,7 @@ impl std::fmt::Debug for TsInfer {
 				"infer_token",
 				&support::DebugSyntaxResult(self.infer_token()),
 			)
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field("name", &support::DebugSyntaxResult(self.name()))
 			.finish()
 	}
 }
@@ -7170,20 +7351,20 @@ impl std::fmt::Debug for TsInterfaceDecl {
 				"interface_token",
 				&support::DebugSyntaxResult(self.interface_token()),
 			)
+			.field("name", &support::DebugSyntaxResult(self.name()))
 			.field(
 				"type_params",
-				&support::DebugSyntaxResult(self.type_params()),
+				&support::DebugOptionalElement(self.type_params()),
 			)
 			.field(
 				"extends_token",
 				&support::DebugOptionalElement(self.extends_token()),
 			)
-			.field("extends", &support::DebugOptionalElement(self.extends()))
 			.field(
 				"l_curly_token",
 				&support::DebugSyntaxResult(self.l_curly_token()),
 			)
-			.field("members", &support::DebugSyntaxResult(self.members()))
+			.field("members", &self.members())
 			.field(
 				"r_curly_token",
 				&support::DebugSyntaxResult(self.r_curly_token()),
@@ -7205,6 +7386,10 @@ impl AstNode for TsIntersection {
 impl std::fmt::Debug for TsIntersection {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsIntersection")
+			.field(
+				"leading_separator_token",
+				&support::DebugOptionalElement(self.leading_separator_token()),
+			)
 			.field("types", &self.types())
 			.finish()
 	}
@@ -7223,7 +7408,11 @@ impl AstNode for TsLiteral {
 impl std::fmt::Debug for TsLiteral {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsLiteral")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"minus_token",
+				&support::DebugOptionalElement(self.minus_token()),
+			)
+			.field("literal", &support::DebugSyntaxResult(self.literal()))
 			.finish()
 	}
 }
@@ -7249,32 +7438,28 @@ impl std::fmt::Debug for TsMappedType {
 				"readonly_modifier",
 				&support::DebugOptionalElement(self.readonly_modifier()),
 			)
+			.field("param", &support::DebugSyntaxResult(self.param()))
 			.field(
-				"minus_token",
-				&support::DebugOptionalElement(self.minus_token()),
-			)
-			.field(
-				"plus_token",
-				&support::DebugOptionalElement(self.plus_token()),
+				"optional_modifier_token",
+				&support::DebugOptionalElement(self.optional_modifier_token()),
 			)
 			.field(
 				"question_mark_token",
 				&support::DebugOptionalElement(self.question_mark_token()),
 			)
-			.field("param", &support::DebugSyntaxResult(self.param()))
 			.field(
 				"colon_token",
 				&support::DebugSyntaxResult(self.colon_token()),
 			)
 			.field("ty", &support::DebugSyntaxResult(self.ty()))
-			.field(
-				"r_curly_token",
-				&support::DebugSyntaxResult(self.r_curly_token()),
-			)
 			.field(
 				"semicolon_token",
 				&support::DebugOptionalElement(self.semicolon_token()),
 			)
+			.field(
+				"r_curly_token",
+				&support::DebugSyntaxResult(self.r_curly_token()),
+			)
 			.finish()
 	}
 }
@@ -7294,15 +7479,17 @@ impl std::fmt::Debug for TsMappedTypeParam {
 		f.debug_struct("TsMappedTypeParam")
 			.field(
 				"l_brack_token",
-				&support::DebugOptionalElement(self.l_brack_token()),
+				&support::DebugSyntaxResult(self.l_brack_token()),
 			)
-			.field("name", &support::DebugOptionalElement(self.name()))
+			.field("name_token", &support::DebugSyntaxResult(self.name_token()))
+			.field("in_token", &support::DebugSyntaxResult(self.in_token()))
+			.field("keys", &support::DebugSyntaxResult(self.keys()))
+			.field("as_token", &support::DebugOptionalElement(self.as_token()))
+			.field("alias", &support::DebugOptionalElement(self.alias()))
 			.field(
 				"r_brack_token",
-				&support::DebugOptionalElement(self.r_brack_token()),
+				&support::DebugSyntaxResult(self.r_brack_token()),
 			)
-			.field("ident", &support::DebugOptionalElement(self.ident()))
-			.field("ty", &support::DebugSyntaxResult(self.ty()))
 			.finish()
 	}
 }
@@ -7321,16 +7508,12 @@ impl std::fmt::Debug for TsMappedTypeReadonly {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsMappedTypeReadonly")
 			.field(
-				"minus_token",
-				&support::DebugOptionalElement(self.minus_token()),
-			)
-			.field(
-				"plus_token",
-				&support::DebugOptionalElement(self.plus_token()),
+				"operator_token",
+				&support::DebugOptionalElement(self.operator_token()),
 			)
 			.field(
 				"readonly_token",
-				&support::DebugOptionalElement(self.readonly_token()),
+				&support::DebugSyntaxResult(self.readonly_token()),
 			)
 			.finish()
 	}
@@ -7349,27 +7532,27 @@ impl AstNode for TsMethodSignature {
 impl std::fmt::Debug for TsMethodSignature {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsMethodSignature")
+			.field("name", &support::DebugSyntaxResult(self.name()))
 			.field(
-				"readonly_token",
-				&support::DebugOptionalElement(self.readonly_token()),
+				"question_mark_token",
+				&support::DebugOptionalElement(self.question_mark_token()),
 			)
-			.field("key", &support::DebugSyntaxResult(self.key()))
 			.field(
 				"type_params",
-				&support::DebugSyntaxResult(self.type_params()),
+				&support::DebugOptionalElement(self.type_params()),
 			)
 			.field("parameters", &support::DebugSyntaxResult(self.parameters()))
-			.field(
-				"question_mark_token",
-				&support::DebugOptionalElement(self.question_mark_token()),
-			)
 			.field(
 				"colon_token",
-				&support::DebugSyntaxResult(self.colon_token()),
+				&support::DebugOptionalElement(self.colon_token()),
 			)
 			.field(
 				"return_type",
-				&support::DebugSyntaxResult(self.return_type()),
+				&support::DebugOptionalElement(self.return_type()),
+			)
+			.field(
+				"separator_token",
+				&support::DebugOptionalElement(self.separator_token()),
 			)
 			.finish()
 	}
@@ -7565,7 +7748,10 @@ impl AstNode for TsNumber {
 impl std::fmt::Debug for TsNumber {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsNumber")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"number_token",
+				&support::DebugSyntaxResult(self.number_token()),
+			)
 			.finish()
 	}
 }
@@ -7583,7 +7769,10 @@ impl AstNode for TsObject {
 impl std::fmt::Debug for TsObject {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsObject")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"object_token",
+				&support::DebugSyntaxResult(self.object_token()),
+			)
 			.finish()
 	}
 }
@@ -7653,8 +7842,16 @@ impl AstNode for TsPredicate {
 impl std::fmt::Debug for TsPredicate {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsPredicate")
-			.field("lhs", &support::DebugSyntaxResult(self.lhs()))
-			.field("rhs", &support::DebugSyntaxResult(self.rhs()))
+			.field(
+				"asserts_token",
+				&support::DebugOptionalElement(self.asserts_token()),
+			)
+			.field(
+				"parameter_name",
+				&support::DebugOptionalElement(self.parameter_name()),
+			)
+			.field("is_token", &support::DebugOptionalElement(self.is_token()))
+			.field("ty", &support::DebugOptionalElement(self.ty()))
 			.finish()
 	}
 }
@@ -7676,16 +7873,20 @@ impl std::fmt::Debug for TsPropertySignature {
 				"readonly_token",
 				&support::DebugOptionalElement(self.readonly_token()),
 			)
-			.field("prop", &support::DebugSyntaxResult(self.prop()))
+			.field("name", &support::DebugSyntaxResult(self.name()))
 			.field(
 				"question_mark_token",
-				&support::DebugSyntaxResult(self.question_mark_token()),
+				&support::DebugOptionalElement(self.question_mark_token()),
 			)
 			.field(
 				"colon_token",
-				&support::DebugSyntaxResult(self.colon_token()),
+				&support::DebugOptionalElement(self.colon_token()),
+			)
+			.field("ty", &support::DebugOptionalElement(self.ty()))
+			.field(
+				"separator_token",
+				&support::DebugOptionalElement(self.separator_token()),
 			)
-			.field("ty", &support::DebugSyntaxResult(self.ty()))
 			.finish()
 	}
 }
@@ -7723,7 +7924,10 @@ impl AstNode for TsString {
 impl std::fmt::Debug for TsString {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsString")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"string_token",
+				&support::DebugSyntaxResult(self.string_token()),
+			)
 			.finish()
 	}
 }
@@ -7741,7 +7945,10 @@ impl AstNode for TsSymbol {
 impl std::fmt::Debug for TsSymbol {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsSymbol")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field(
+				"symbol_token",
+				&support::DebugSyntaxResult(self.symbol_token()),
+			)
 			.finish()
 	}
 }
@@ -7759,7 +7966,14 @@ impl AstNode for TsTemplate {
 impl std::fmt::Debug for TsTemplate {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTemplate")
-			.field("elements", &support::DebugSyntaxResult(self.elements()))
+			.field(
+				"l_tick_token",
+				&support::DebugSyntaxResult(self.l_tick_token()),
+			)
+			.field(
+				"r_tick_token",
+				&support::DebugSyntaxResult(self.r_tick_token()),
+			)
 			.finish()
 	}
 }
@@ -7777,6 +7991,10 @@ impl AstNode for TsTemplateElement {
 impl std::fmt::Debug for TsTemplateElement {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTemplateElement")
+			.field(
+				"dollar_curly_token",
+				&support::DebugSyntaxResult(self.dollar_curly_token()),
+			)
 			.field("ty", &support::DebugSyntaxResult(self.ty()))
 			.field(
 				"r_curly_token",
@@ -7821,7 +8039,6 @@ impl std::fmt::Debug for TsTuple {
 				"l_brack_token",
 				&support::DebugSyntaxResult(self.l_brack_token()),
 			)
-			.field("elements", &support::DebugSyntaxResult(self.elements()))
 			.field(
 				"r_brack_token",
 				&support::DebugSyntaxResult(self.r_brack_token()),
@@ -7843,20 +8060,24 @@ impl AstNode for TsTupleElement {
 impl std::fmt::Debug for TsTupleElement {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTupleElement")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
 			.field(
-				"colon_token",
-				&support::DebugSyntaxResult(self.colon_token()),
+				"dotdotdot_token",
+				&support::DebugOptionalElement(self.dotdotdot_token()),
 			)
+			.field("name", &support::DebugOptionalElement(self.name()))
 			.field(
-				"question_mark_token",
-				&support::DebugSyntaxResult(self.question_mark_token()),
+				"name_question_mark_token",
+				&support::DebugOptionalElement(self.name_question_mark_token()),
 			)
 			.field(
-				"dotdotdot_token",
-				&support::DebugOptionalElement(self.dotdotdot_token()),
+				"colon_token",
+				&support::DebugOptionalElement(self.colon_token()),
 			)
 			.field("ty", &support::DebugSyntaxResult(self.ty()))
+			.field(
+				"optional_token",
+				&support::DebugOptionalElement(self.optional_token()),
+			)
 			.finish()
 	}
 }
@@ -7874,13 +8095,22 @@ impl AstNode for TsTypeAliasDecl {
 impl std::fmt::Debug for TsTypeAliasDecl {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTypeAliasDecl")
+			.field(
+				"declare_token",
+				&support::DebugOptionalElement(self.declare_token()),
+			)
 			.field("type_token", &support::DebugSyntaxResult(self.type_token()))
+			.field("name", &support::DebugSyntaxResult(self.name()))
 			.field(
 				"type_params",
-				&support::DebugSyntaxResult(self.type_params()),
+				&support::DebugOptionalElement(self.type_params()),
 			)
 			.field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
 			.field("ty", &support::DebugSyntaxResult(self.ty()))
+			.field(
+				"semicolon_token",
+				&support::DebugOptionalElement(self.semicolon_token()),
+			)
 			.finish()
 	}
 }
@@ -7924,7 +8154,7 @@ impl std::fmt::Debug for TsTypeArgs {
 				"l_angle_token",
 				&support::DebugSyntaxResult(self.l_angle_token()),
 			)
-			.field("ts_type_arg_list", &self.ts_type_arg_list())
+			.field("args", &self.args())
 			.field(
 				"r_angle_token",
 				&support::DebugSyntaxResult(self.r_angle_token()),
@@ -7946,7 +8176,7 @@ impl AstNode for TsTypeName {
 impl std::fmt::Debug for TsTypeName {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTypeName")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
+			.field("name_token", &support::DebugSyntaxResult(self.name_token()))
 			.finish()
 	}
 }
@@ -7964,6 +8194,10 @@ impl AstNode for TsTypeOperator {
 impl std::fmt::Debug for TsTypeOperator {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTypeOperator")
+			.field(
+				"operator_token",
+				&support::DebugSyntaxResult(self.operator_token()),
+			)
 			.field("ty", &support::DebugSyntaxResult(self.ty()))
 			.finish()
 	}
@@ -7982,9 +8216,12 @@ impl AstNode for TsTypeParam {
 impl std::fmt::Debug for TsTypeParam {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTypeParam")
-			.field("ident", &support::DebugSyntaxResult(self.ident()))
-			.field("constraint", &support::DebugSyntaxResult(self.constraint()))
-			.field("default", &support::DebugSyntaxResult(self.default()))
+			.field("name_token", &support::DebugSyntaxResult(self.name_token()))
+			.field(
+				"constraint",
+				&support::DebugOptionalElement(self.constraint()),
+			)
+			.field("default", &support::DebugOptionalElement(self.default()))
 			.finish()
 	}
 }
@@ -8004,12 +8241,33 @@ impl std::fmt::Debug for TsTypeParams {
 		f.debug_struct("TsTypeParams")
 			.field(
 				"l_angle_token",
-				&support::DebugOptionalElement(self.l_angle_token()),
+				&support::DebugSyntaxResult(self.l_angle_token()),
 			)
-			.field("params", &support::DebugSyntaxResult(self.params()))
+			.field("params", &self.params())
 			.field(
 				"r_angle_token",
-				&support::DebugOptionalElement(self.r_angle_token()),
+				&support::DebugSyntaxResult(self.r_angle_token()),
+			)
+			.finish()
+	}
+}
+impl AstNode for TsTypeQuery {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TYPE_QUERY }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl std::fmt::Debug for TsTypeQuery {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("TsTypeQuery")
+			.field(
+				"typeof_token",
+				&support::DebugSyntaxResult(self.typeof_token()),
 			)
 			.finish()
 	}
@@ -8029,7 +8287,40 @@ impl std::fmt::Debug for TsTypeRef {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsTypeRef")
 			.field("name", &support::DebugSyntaxResult(self.name()))
-			.field("type_args", &support::DebugSyntaxResult(self.type_args()))
+			.field(
+				"type_args",
+				&support::DebugOptionalElement(self.type_args()),
+			)
+			.finish()
+	}
+}
+impl AstNode for TsTypedParameter {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TYPED_PARAMETER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl std::fmt::Debug for TsTypedParameter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("TsTypedParameter")
+			.field("binding", &support::DebugSyntaxResult(self.binding()))
+			.field(
+				"question_mark_token",
+				&support::DebugOptionalElement(self.question_mark_token()),
+			)
+			.field(
+				"type_annotation",
+				&support::DebugOptionalElement(self.type_annotation()),
+			)
+			.field(
+				"initializer",
+				&support::DebugOptionalElement(self.initializer()),
+			)
 			.finish()
 	}
 }
@@ -8068,6 +8359,10 @@ impl AstNode for TsUnion {
 impl std::fmt::Debug for TsUnion {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsUnion")
+			.field(
+				"leading_separator_token",
+				&support::DebugOptionalElement(self.leading_separator_token()),
+			)
 			.field("types", &self.types())
 			.finish()
 	}
@@ -8936,10 +9231,15 @@ impl From<TsConstructorParam> for JsAnyConstructorParameter {
 		JsAnyConstructorParameter::TsConstructorParam(node)
 	}
 }
+impl From<TsTypedParameter> for JsAnyConstructorParameter {
+	fn from(node: TsTypedParameter) -> JsAnyConstructorParameter {
+		JsAnyConstructorParameter::TsTypedParameter(node)
+	}
+}
 impl AstNode for JsAnyConstructorParameter {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
-			JS_BINDING_PATTERN_WITH_DEFAULT | TS_CONSTRUCTOR_PARAM => true,
+			JS_BINDING_PATTERN_WITH_DEFAULT | TS_CONSTRUCTOR_PARAM | TS_TYPED_PARAMETER => true,
 			k if JsAnyBindingPattern::can_cast(k) => true,
 			_ => false,
 		}
@@ -8954,6 +9254,9 @@ impl AstNode for JsAnyConstructorParameter {
 			TS_CONSTRUCTOR_PARAM => {
 				JsAnyConstructorParameter::TsConstructorParam(TsConstructorParam { syntax })
 			}
+			TS_TYPED_PARAMETER => {
+				JsAnyConstructorParameter::TsTypedParameter(TsTypedParameter { syntax })
+			}
 			_ => {
 				if let Some(js_any_binding_pattern) = JsAnyBindingPattern::cast(syntax) {
 					return Some(JsAnyConstructorParameter::JsAnyBindingPattern(
@@ -8969,6 +9272,7 @@ impl AstNode for JsAnyConstructorParameter {
 		match self {
 			JsAnyConstructorParameter::JsBindingPatternWithDefault(it) => &it.syntax,
 			JsAnyConstructorParameter::TsConstructorParam(it) => &it.syntax,
+			JsAnyConstructorParameter::TsTypedParameter(it) => &it.syntax,
 			JsAnyConstructorParameter::JsAnyBindingPattern(it) => it.syntax(),
 		}
 	}
@@ -8981,6 +9285,7 @@ impl std::fmt::Debug for JsAnyConstructorParameter {
 				std::fmt::Debug::fmt(it, f)
 			}
 			JsAnyConstructorParameter::TsConstructorParam(it) => std::fmt::Debug::fmt(it, f),
+			JsAnyConstructorParameter::TsTypedParameter(it) => std::fmt::Debug::fmt(it, f),
 		}
 	}
 }
@@ -9036,7 +9341,8 @@ impl AstNode for JsAnyExportDeclaration {
 				| JS_FUNCTION_DECLARATION
 				| JS_VARIABLE_STATEMENT
 				| TS_ENUM | TS_INTERFACE_DECL
-				| TS_MODULE_DECL | TS_NAMESPACE_DECL
+				| TS_MODULE_DECL
+				| TS_NAMESPACE_DECL
 				| TS_TYPE_ALIAS_DECL
 		)
 	}
@@ -10204,10 +10510,13 @@ impl From<JsBindingPatternWithDefault> for JsAnyParameter {
 impl From<JsRestParameter> for JsAnyParameter {
 	fn from(node: JsRestParameter) -> JsAnyParameter { JsAnyParameter::JsRestParameter(node) }
 }
+impl From<TsTypedParameter> for JsAnyParameter {
+	fn from(node: TsTypedParameter) -> JsAnyParameter { JsAnyParameter::TsTypedParameter(node) }
+}
 impl AstNode for JsAnyParameter {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
-			JS_BINDING_PATTERN_WITH_DEFAULT | JS_REST_PARAMETER => true,
+			JS_BINDING_PATTERN_WITH_DEFAULT | JS_REST_PARAMETER | TS_TYPED_PARAMETER => true,
 			k if JsAnyBindingPattern::can_cast(k) => true,
 			_ => false,
 		}
@@ -10218,6 +10527,7 @@ impl AstNode for JsAnyParameter {
 				JsAnyParameter::JsBindingPatternWithDefault(JsBindingPatternWithDefault { syntax })
 			}
 			JS_REST_PARAMETER => JsAnyParameter::JsRestParameter(JsRestParameter { syntax }),
+			TS_TYPED_PARAMETER => JsAnyParameter::TsTypedParameter(TsTypedParameter { syntax }),
 			_ => {
 				if let Some(js_any_binding_pattern) = JsAnyBindingPattern::cast(syntax) {
 					return Some(JsAnyParameter::JsAnyBindingPattern(js_any_binding_pattern));
@@ -10231,6 +10541,7 @@ impl AstNode for JsAnyParameter {
 		match self {
 			JsAnyParameter::JsBindingPatternWithDefault(it) => &it.syntax,
 			JsAnyParameter::JsRestParameter(it) => &it.syntax,
+			JsAnyParameter::TsTypedParameter(it) => &it.syntax,
 			JsAnyParameter::JsAnyBindingPattern(it) => it.syntax(),
 		}
 	}
@@ -10241,6 +10552,7 @@ impl std::fmt::Debug for JsAnyParameter {
 			JsAnyParameter::JsAnyBindingPattern(it) => std::fmt::Debug::fmt(it, f),
 			JsAnyParameter::JsBindingPatternWithDefault(it) => std::fmt::Debug::fmt(it, f),
 			JsAnyParameter::JsRestParameter(it) => std::fmt::Debug::fmt(it, f),
+			JsAnyParameter::TsTypedParameter(it) => std::fmt::Debug::fmt(it, f),
 		}
 	}
 }
@@ -10399,7 +10711,8 @@ impl AstNode for JsAnyStatement {
 				| JS_WHILE_STATEMENT
 				| JS_WITH_STATEMENT
 				| TS_ENUM | TS_INTERFACE_DECL
-				| TS_MODULE_DECL | TS_NAMESPACE_DECL
+				| TS_MODULE_DECL
+				| TS_NAMESPACE_DECL
 				| TS_TYPE_ALIAS_DECL
 		)
 	}
@@ -10555,6 +10868,175 @@ impl std::fmt::Debug for JsAnySwitchClause {
 		}
 	}
 }
+impl From<JsBindingPatternWithDefault> for TsAnyConstructorParamParameter {
+	fn from(node: JsBindingPatternWithDefault) -> TsAnyConstructorParamParameter {
+		TsAnyConstructorParamParameter::JsBindingPatternWithDefault(node)
+	}
+}
+impl From<TsTypedParameter> for TsAnyConstructorParamParameter {
+	fn from(node: TsTypedParameter) -> TsAnyConstructorParamParameter {
+		TsAnyConstructorParamParameter::TsTypedParameter(node)
+	}
+}
+impl AstNode for TsAnyConstructorParamParameter {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		match kind {
+			JS_BINDING_PATTERN_WITH_DEFAULT | TS_TYPED_PARAMETER => true,
+			k if JsAnyBindingPattern::can_cast(k) => true,
+			_ => false,
+		}
+	}
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			JS_BINDING_PATTERN_WITH_DEFAULT => {
+				TsAnyConstructorParamParameter::JsBindingPatternWithDefault(
+					JsBindingPatternWithDefault { syntax },
+				)
+			}
+			TS_TYPED_PARAMETER => {
+				TsAnyConstructorParamParameter::TsTypedParameter(TsTypedParameter { syntax })
+			}
+			_ => {
+				if let Some(js_any_binding_pattern) = JsAnyBindingPattern::cast(syntax) {
+					return Some(TsAnyConstructorParamParameter::JsAnyBindingPattern(
+						js_any_binding_pattern,
+					));
+				}
+				return None;
+			}
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			TsAnyConstructorParamParameter::JsBindingPatternWithDefault(it) => &it.syntax,
+			TsAnyConstructorParamParameter::TsTypedParameter(it) => &it.syntax,
+			TsAnyConstructorParamParameter::JsAnyBindingPattern(it) => it.syntax(),
+		}
+	}
+}
+impl std::fmt::Debug for TsAnyConstructorParamParameter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TsAnyConstructorParamParameter::JsAnyBindingPattern(it) => std::fmt::Debug::fmt(it, f),
+			TsAnyConstructorParamParameter::JsBindingPatternWithDefault(it) => {
+				std::fmt::Debug::fmt(it, f)
+			}
+			TsAnyConstructorParamParameter::TsTypedParameter(it) => std::fmt::Debug::fmt(it, f),
+		}
+	}
+}
+impl From<JsComputedMemberName> for TsAnyPropertySignatureName {
+	fn from(node: JsComputedMemberName) -> TsAnyPropertySignatureName {
+		TsAnyPropertySignatureName::JsComputedMemberName(node)
+	}
+}
+impl From<JsName> for TsAnyPropertySignatureName {
+	fn from(node: JsName) -> TsAnyPropertySignatureName { TsAnyPropertySignatureName::JsName(node) }
+}
+impl From<JsNumberLiteralExpression> for TsAnyPropertySignatureName {
+	fn from(node: JsNumberLiteralExpression) -> TsAnyPropertySignatureName {
+		TsAnyPropertySignatureName::JsNumberLiteralExpression(node)
+	}
+}
+impl From<JsStringLiteralExpression> for TsAnyPropertySignatureName {
+	fn from(node: JsStringLiteralExpression) -> TsAnyPropertySignatureName {
+		TsAnyPropertySignatureName::JsStringLiteralExpression(node)
+	}
+}
+impl AstNode for TsAnyPropertySignatureName {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		matches!(
+			kind,
+			JS_COMPUTED_MEMBER_NAME
+				| JS_NAME | JS_NUMBER_LITERAL_EXPRESSION
+				| JS_STRING_LITERAL_EXPRESSION
+		)
+	}
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			JS_COMPUTED_MEMBER_NAME => {
+				TsAnyPropertySignatureName::JsComputedMemberName(JsComputedMemberName { syntax })
+			}
+			JS_NAME => TsAnyPropertySignatureName::JsName(JsName { syntax }),
+			JS_NUMBER_LITERAL_EXPRESSION => {
+				TsAnyPropertySignatureName::JsNumberLiteralExpression(JsNumberLiteralExpression {
+					syntax,
+				})
+			}
+			JS_STRING_LITERAL_EXPRESSION => {
+				TsAnyPropertySignatureName::JsStringLiteralExpression(JsStringLiteralExpression {
+					syntax,
+				})
+			}
+			_ => return None,
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			TsAnyPropertySignatureName::JsComputedMemberName(it) => &it.syntax,
+			TsAnyPropertySignatureName::JsName(it) => &it.syntax,
+			TsAnyPropertySignatureName::JsNumberLiteralExpression(it) => &it.syntax,
+			TsAnyPropertySignatureName::JsStringLiteralExpression(it) => &it.syntax,
+		}
+	}
+}
+impl std::fmt::Debug for TsAnyPropertySignatureName {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TsAnyPropertySignatureName::JsComputedMemberName(it) => std::fmt::Debug::fmt(it, f),
+			TsAnyPropertySignatureName::JsName(it) => std::fmt::Debug::fmt(it, f),
+			TsAnyPropertySignatureName::JsNumberLiteralExpression(it) => {
+				std::fmt::Debug::fmt(it, f)
+			}
+			TsAnyPropertySignatureName::JsStringLiteralExpression(it) => {
+				std::fmt::Debug::fmt(it, f)
+			}
+		}
+	}
+}
+impl From<TemplateChunkElement> for TsAnyTemplateElement {
+	fn from(node: TemplateChunkElement) -> TsAnyTemplateElement {
+		TsAnyTemplateElement::TemplateChunkElement(node)
+	}
+}
+impl From<TsTemplateElement> for TsAnyTemplateElement {
+	fn from(node: TsTemplateElement) -> TsAnyTemplateElement {
+		TsAnyTemplateElement::TsTemplateElement(node)
+	}
+}
+impl AstNode for TsAnyTemplateElement {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		matches!(kind, TEMPLATE_CHUNK_ELEMENT | TS_TEMPLATE_ELEMENT)
+	}
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			TEMPLATE_CHUNK_ELEMENT => {
+				TsAnyTemplateElement::TemplateChunkElement(TemplateChunkElement { syntax })
+			}
+			TS_TEMPLATE_ELEMENT => {
+				TsAnyTemplateElement::TsTemplateElement(TsTemplateElement { syntax })
+			}
+			_ => return None,
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			TsAnyTemplateElement::TemplateChunkElement(it) => &it.syntax,
+			TsAnyTemplateElement::TsTemplateElement(it) => &it.syntax,
+		}
+	}
+}
+impl std::fmt::Debug for TsAnyTemplateElement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TsAnyTemplateElement::TemplateChunkElement(it) => std::fmt::Debug::fmt(it, f),
+			TsAnyTemplateElement::TsTemplateElement(it) => std::fmt::Debug::fmt(it, f),
+		}
+	}
+}
 impl From<TsQualifiedPath> for TsEntityName {
 	fn from(node: TsQualifiedPath) -> TsEntityName { TsEntityName::TsQualifiedPath(node) }
 }
@@ -10766,6 +11248,9 @@ impl From<TsTuple> for TsType {
 impl From<TsTypeOperator> for TsType {
 	fn from(node: TsTypeOperator) -> TsType { TsType::TsTypeOperator(node) }
 }
+impl From<TsTypeQuery> for TsType {
+	fn from(node: TsTypeQuery) -> TsType { TsType::TsTypeQuery(node) }
+}
 impl From<TsTypeRef> for TsType {
 	fn from(node: TsTypeRef) -> TsType { TsType::TsTypeRef(node) }
 }
@@ -10795,12 +11280,15 @@ impl AstNode for TsType {
 				| TS_LITERAL | TS_MAPPED_TYPE
 				| TS_NEVER | TS_NULL
 				| TS_NUMBER | TS_OBJECT
-				| TS_OBJECT_TYPE | TS_PAREN
-				| TS_PREDICATE | TS_STRING
-				| TS_SYMBOL | TS_TEMPLATE
+				| TS_OBJECT_TYPE
+				| TS_PAREN | TS_PREDICATE
+				| TS_STRING | TS_SYMBOL
+				| TS_TEMPLATE
 				| TS_THIS | TS_TUPLE
 				| TS_TYPE_OPERATOR
-				| TS_TYPE_REF | TS_UNDEFINED
+				| TS_TYPE_QUERY
+				| TS_TYPE_REF
+				| TS_UNDEFINED
 				| TS_UNION | TS_UNKNOWN
 				| TS_VOID
 		)
@@ -10833,6 +11321,7 @@ impl AstNode for TsType {
 			TS_THIS => TsType::TsThis(TsThis { syntax }),
 			TS_TUPLE => TsType::TsTuple(TsTuple { syntax }),
 			TS_TYPE_OPERATOR => TsType::TsTypeOperator(TsTypeOperator { syntax }),
+			TS_TYPE_QUERY => TsType::TsTypeQuery(TsTypeQuery { syntax }),
 			TS_TYPE_REF => TsType::TsTypeRef(TsTypeRef { syntax }),
 			TS_UNDEFINED => TsType::TsUndefined(TsUndefined { syntax }),
 			TS_UNION => TsType::TsUnion(TsUnion { syntax }),
@@ -10870,6 +11359,7 @@ impl AstNode for TsType {
 			TsType::TsThis(it) => &it.syntax,
 			TsType::TsTuple(it) => &it.syntax,
 			TsType::TsTypeOperator(it) => &it.syntax,
+			TsType::TsTypeQuery(it) => &it.syntax,
 			TsType::TsTypeRef(it) => &it.syntax,
 			TsType::TsUndefined(it) => &it.syntax,
 			TsType::TsUnion(it) => &it.syntax,
@@ -10907,6 +11397,7 @@ impl std::fmt::Debug for TsType {
 			TsType::TsThis(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsTuple(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsTypeOperator(it) => std::fmt::Debug::fmt(it, f),
+			TsType::TsTypeQuery(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsTypeRef(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsUndefined(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsUnion(it) => std::fmt::Debug::fmt(it, f),
@@ -11146,6 +11637,21 @@ impl std::fmt::Display for JsAnySwitchClause {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsAnyConstructorParamParameter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for TsAnyPropertySignatureName {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for TsAnyTemplateElement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsEntityName {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -12171,11 +12677,21 @@ impl std::fmt::Display for TsTypeParams {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsTypeQuery {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsTypeRef {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsTypedParameter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsUndefined {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -13099,23 +13615,59 @@ impl AstNode for TsEnumMemberList {
 	}
 	fn syntax(&self) -> &SyntaxNode { self.syntax_list.node() }
 }
-impl AstNodeList<TsEnumMember> for TsEnumMemberList {
+impl AstSeparatedList<TsEnumMember> for TsEnumMemberList {
 	fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
 }
 impl Debug for TsEnumMemberList {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		f.write_str("TsEnumMemberList ")?;
-		f.debug_list().entries(self.iter()).finish()
+		f.debug_list().entries(self.elements()).finish()
 	}
 }
+impl IntoIterator for TsEnumMemberList {
+	type Item = SyntaxResult<TsEnumMember>;
+	type IntoIter = AstSeparatedListNodesIterator<TsEnumMember>;
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
 impl IntoIterator for &TsEnumMemberList {
-	type Item = TsEnumMember;
-	type IntoIter = AstNodeListIterator<TsEnumMember>;
+	type Item = SyntaxResult<TsEnumMember>;
+	type IntoIter = AstSeparatedListNodesIterator<TsEnumMember>;
 	fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for TsEnumMemberList {
-	type Item = TsEnumMember;
-	type IntoIter = AstNodeListIterator<TsEnumMember>;
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TsIntersectionTypeElementList {
+	syntax_list: SyntaxList,
+}
+impl AstNode for TsIntersectionTypeElementList {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_INTERSECTION_TYPE_ELEMENT_LIST }
+	fn cast(syntax: SyntaxNode) -> Option<TsIntersectionTypeElementList> {
+		if Self::can_cast(syntax.kind()) {
+			Some(TsIntersectionTypeElementList {
+				syntax_list: syntax.into_list(),
+			})
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { self.syntax_list.node() }
+}
+impl AstSeparatedList<TsType> for TsIntersectionTypeElementList {
+	fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
+}
+impl Debug for TsIntersectionTypeElementList {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str("TsIntersectionTypeElementList ")?;
+		f.debug_list().entries(self.elements()).finish()
+	}
+}
+impl IntoIterator for TsIntersectionTypeElementList {
+	type Item = SyntaxResult<TsType>;
+	type IntoIter = AstSeparatedListNodesIterator<TsType>;
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl IntoIterator for &TsIntersectionTypeElementList {
+	type Item = SyntaxResult<TsType>;
+	type IntoIter = AstSeparatedListNodesIterator<TsType>;
 	fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -13262,6 +13814,42 @@ impl IntoIterator for &TsTypeParamList {
 	type IntoIter = AstSeparatedListNodesIterator<TsTypeParam>;
 	fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TsUnionTypeVariantList {
+	syntax_list: SyntaxList,
+}
+impl AstNode for TsUnionTypeVariantList {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_UNION_TYPE_VARIANT_LIST }
+	fn cast(syntax: SyntaxNode) -> Option<TsUnionTypeVariantList> {
+		if Self::can_cast(syntax.kind()) {
+			Some(TsUnionTypeVariantList {
+				syntax_list: syntax.into_list(),
+			})
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { self.syntax_list.node() }
+}
+impl AstSeparatedList<TsType> for TsUnionTypeVariantList {
+	fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
+}
+impl Debug for TsUnionTypeVariantList {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str("TsUnionTypeVariantList ")?;
+		f.debug_list().entries(self.elements()).finish()
+	}
+}
+impl IntoIterator for TsUnionTypeVariantList {
+	type Item = SyntaxResult<TsType>;
+	type IntoIter = AstSeparatedListNodesIterator<TsType>;
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl IntoIterator for &TsUnionTypeVariantList {
+	type Item = SyntaxResult<TsType>;
+	type IntoIter = AstSeparatedListNodesIterator<TsType>;
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
 pub struct DebugSyntaxElement(pub(crate) SyntaxElement);
 impl Debug for DebugSyntaxElement {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -13815,6 +14403,10 @@ impl Debug for DebugSyntaxElement {
 				TS_INTERSECTION => {
 					std::fmt::Debug::fmt(&TsIntersection::cast(node.clone()).unwrap(), f)
 				}
+				TS_INTERSECTION_TYPE_ELEMENT_LIST => std::fmt::Debug::fmt(
+					&TsIntersectionTypeElementList::cast(node.clone()).unwrap(),
+					f,
+				),
 				TS_LITERAL => std::fmt::Debug::fmt(&TsLiteral::cast(node.clone()).unwrap(), f),
 				TS_MAPPED_TYPE => {
 					std::fmt::Debug::fmt(&TsMappedType::cast(node.clone()).unwrap(), f)
@@ -13892,9 +14484,16 @@ impl Debug for DebugSyntaxElement {
 				TS_TYPE_PARAMS => {
 					std::fmt::Debug::fmt(&TsTypeParams::cast(node.clone()).unwrap(), f)
 				}
+				TS_TYPE_QUERY => std::fmt::Debug::fmt(&TsTypeQuery::cast(node.clone()).unwrap(), f),
 				TS_TYPE_REF => std::fmt::Debug::fmt(&TsTypeRef::cast(node.clone()).unwrap(), f),
+				TS_TYPED_PARAMETER => {
+					std::fmt::Debug::fmt(&TsTypedParameter::cast(node.clone()).unwrap(), f)
+				}
 				TS_UNDEFINED => std::fmt::Debug::fmt(&TsUndefined::cast(node.clone()).unwrap(), f),
 				TS_UNION => std::fmt::Debug::fmt(&TsUnion::cast(node.clone()).unwrap(), f),
+				TS_UNION_TYPE_VARIANT_LIST => {
+					std::fmt::Debug::fmt(&TsUnionTypeVariantList::cast(node.clone()).unwrap(), f)
+				}
 				TS_UNKNOWN => std::fmt::Debug::fmt(&TsUnknown::cast(node.clone()).unwrap(), f),
 				TS_VOID => std::fmt::Debug::fmt(&TsVoid::cast(node.clone()).unwrap(), f),
 				_ => std::fmt::Debug::fmt(node, f),
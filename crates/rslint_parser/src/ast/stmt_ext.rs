@@ -54,6 +54,13 @@ impl Specifier {
 	}
 }
 
+impl ExportWildcard {
+	/// The name of the namespace of `export * as name from "mod"`
+	pub fn alias(&self) -> Option<JsName> {
+		self.syntax().children().find_map(JsName::cast)
+	}
+}
+
 impl JsAnySwitchClause {
 	pub fn into_case(self) -> Option<JsCaseClause> {
 		if let JsAnySwitchClause::JsCaseClause(clause) = self {
@@ -34,22 +34,13 @@ impl AstNode for TsTypeQueryExpr {
 	}
 }
 
-impl TsImport {
-	pub fn arg(&self) -> Option<SyntaxToken> {
-		self.syntax()
-			.tokens()
-			.into_iter()
-			.find(|t| t.kind() == JS_STRING_LITERAL)
-	}
-}
-
 impl TsMappedTypeParam {
 	/// present for alias
 	pub fn as_token(&self) -> Option<SyntaxToken> {
 		self.syntax()
 			.children_with_tokens()
 			.filter_map(|x| x.into_token())
-			.find(|x| x.kind() == IDENT && x.text() == "as")
+			.find(|x| x.kind() == IDENT && x.text_trimmed() == "as")
 	}
 
 	pub fn alias(&self) -> Option<TsType> {
@@ -89,3 +80,171 @@ impl AstNode for TsModuleRef {
 		}
 	}
 }
+
+impl TsImport {
+	/// The string literal of the imported module
+	pub fn arg_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), JS_STRING_LITERAL)
+	}
+}
+
+impl TsTypeQuery {
+	/// The entity name or import whose type is queried
+	pub fn expr(&self) -> SyntaxResult<TsTypeQueryExpr> {
+		support::required_node(self.syntax())
+	}
+}
+
+impl TsQualifiedPath {
+	pub fn rhs(&self) -> SyntaxResult<TsTypeName> {
+		self.syntax()
+			.children()
+			.skip(1)
+			.find_map(TsTypeName::cast)
+			.ok_or_else(|| SyntaxError::MissingRequiredChild(self.syntax().clone()))
+	}
+}
+
+impl TsTuple {
+	pub fn elements(&self) -> impl Iterator<Item = TsTupleElement> {
+		support::children(self.syntax())
+	}
+
+	/// The `,` after the elements, including the trailing one
+	pub fn comma_tokens(&self) -> impl Iterator<Item = SyntaxToken> {
+		comma_tokens(self.syntax())
+	}
+}
+
+impl TsTupleElement {
+	/// The `?` of an optional named element: `[a?: string]`
+	pub fn name_question_mark_token(&self) -> Option<SyntaxToken> {
+		self.question_mark_tokens().find(|token| {
+			self.ty().map_or(true, |ty| {
+				token.text_range().end() <= ty.syntax().text_range().start()
+			})
+		})
+	}
+
+	/// The `?` of an optional unnamed element: `[string?]`
+	pub fn optional_token(&self) -> Option<SyntaxToken> {
+		let ty = self.ty().ok()?;
+		self.question_mark_tokens()
+			.find(|token| token.text_range().start() >= ty.syntax().text_range().end())
+	}
+
+	fn question_mark_tokens(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|element| element.into_token())
+			.filter(|token| token.kind() == QUESTION)
+	}
+}
+
+impl TsTemplate {
+	/// The closing tick, which has the same kind as the opening tick
+	pub fn r_tick_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|e| e.into_token())
+			.filter(|t| t.kind() == BACKTICK)
+			.nth(1)
+			.ok_or_else(|| SyntaxError::MissingRequiredChild(self.syntax().clone()))
+	}
+
+	pub fn elements(&self) -> impl Iterator<Item = TsAnyTemplateElement> {
+		self.syntax()
+			.children()
+			.find(|child| child.kind() == TEMPLATE_ELEMENT_LIST)
+			.into_iter()
+			.flat_map(|list| list.children())
+			.filter_map(TsAnyTemplateElement::cast)
+	}
+}
+
+impl TsIndexedArray {
+	pub fn object_type(&self) -> SyntaxResult<TsType> {
+		support::required_node(self.syntax())
+	}
+
+	pub fn index_type(&self) -> SyntaxResult<TsType> {
+		support::children(self.syntax())
+			.nth(1)
+			.ok_or_else(|| SyntaxError::MissingRequiredChild(self.syntax().clone()))
+	}
+}
+
+impl TsPredicate {
+	pub fn asserts_token(&self) -> Option<SyntaxToken> {
+		self.ident_token_with_text("asserts")
+	}
+
+	pub fn is_token(&self) -> Option<SyntaxToken> {
+		self.ident_token_with_text("is")
+	}
+
+	/// The type after `is`
+	pub fn ty(&self) -> Option<TsType> {
+		let is_token = self.is_token()?;
+		support::children::<TsType>(self.syntax())
+			.find(|ty| ty.syntax().text_range().start() >= is_token.text_range().end())
+	}
+
+	fn ident_token_with_text(&self, text: &str) -> Option<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|element| element.into_token())
+			.find(|token| token.kind() == IDENT && token.text_trimmed() == text)
+	}
+}
+
+impl TsConditionalType {
+	pub fn check_type(&self) -> SyntaxResult<TsType> {
+		self.nth_type(0)
+	}
+
+	pub fn true_type(&self) -> SyntaxResult<TsType> {
+		self.nth_type(1)
+	}
+
+	pub fn false_type(&self) -> SyntaxResult<TsType> {
+		self.nth_type(2)
+	}
+
+	fn nth_type(&self, n: usize) -> SyntaxResult<TsType> {
+		support::children(self.syntax())
+			.nth(n)
+			.ok_or_else(|| SyntaxError::MissingRequiredChild(self.syntax().clone()))
+	}
+}
+
+impl TsInterfaceDecl {
+	/// The interfaces listed in the `extends` clause
+	pub fn extends(&self) -> impl Iterator<Item = TsExprWithTypeArgs> {
+		support::children(self.syntax())
+	}
+
+	/// The `,` between the interfaces of the `extends` clause
+	pub fn extends_comma_tokens(&self) -> impl Iterator<Item = SyntaxToken> {
+		comma_tokens(self.syntax())
+	}
+}
+
+impl TsTypeElement {
+	/// The `;` or `,` after the member
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		match self {
+			TsTypeElement::TsCallSignatureDecl(member) => member.separator_token(),
+			TsTypeElement::TsConstructSignatureDecl(member) => member.separator_token(),
+			TsTypeElement::TsIndexSignature(member) => member.separator_token(),
+			TsTypeElement::TsMethodSignature(member) => member.separator_token(),
+			TsTypeElement::TsPropertySignature(member) => member.separator_token(),
+		}
+	}
+}
+
+fn comma_tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|element| element.into_token())
+		.filter(|token| token.kind() == COMMA)
+}
@@ -0,0 +1,548 @@
+//! Classification of tokens by their role in the source, used for semantic highlighting.
+//!
+//! The lexical class of a token (keyword, string, number, ...) is taken from the kind the lexer
+//! gave it, while identifiers are classified by looking at the syntax tree around them to tell
+//! declarations apart from references, properties from variables, and so on.
+
+use crate::{JsLanguage, SyntaxKind, SyntaxKind::*, SyntaxNode, SyntaxToken, TextRange};
+use bitflags::bitflags;
+
+/// The type of a classified token.
+///
+/// The names returned by [`TokenType::as_str`] match the standard semantic token types of the
+/// language server protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenType {
+	Namespace,
+	Type,
+	Class,
+	Enum,
+	Interface,
+	TypeParameter,
+	Parameter,
+	Variable,
+	Property,
+	EnumMember,
+	Function,
+	Method,
+	Keyword,
+	Comment,
+	String,
+	Number,
+	Regexp,
+	Operator,
+}
+
+impl TokenType {
+	/// All the token types, in the order of their discriminant.
+	pub const ALL: [TokenType; 18] = [
+		TokenType::Namespace,
+		TokenType::Type,
+		TokenType::Class,
+		TokenType::Enum,
+		TokenType::Interface,
+		TokenType::TypeParameter,
+		TokenType::Parameter,
+		TokenType::Variable,
+		TokenType::Property,
+		TokenType::EnumMember,
+		TokenType::Function,
+		TokenType::Method,
+		TokenType::Keyword,
+		TokenType::Comment,
+		TokenType::String,
+		TokenType::Number,
+		TokenType::Regexp,
+		TokenType::Operator,
+	];
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			TokenType::Namespace => "namespace",
+			TokenType::Type => "type",
+			TokenType::Class => "class",
+			TokenType::Enum => "enum",
+			TokenType::Interface => "interface",
+			TokenType::TypeParameter => "typeParameter",
+			TokenType::Parameter => "parameter",
+			TokenType::Variable => "variable",
+			TokenType::Property => "property",
+			TokenType::EnumMember => "enumMember",
+			TokenType::Function => "function",
+			TokenType::Method => "method",
+			TokenType::Keyword => "keyword",
+			TokenType::Comment => "comment",
+			TokenType::String => "string",
+			TokenType::Number => "number",
+			TokenType::Regexp => "regexp",
+			TokenType::Operator => "operator",
+		}
+	}
+}
+
+bitflags! {
+	/// Additional information about a classified token.
+	pub struct TokenModifiers: u32 {
+		/// The token is the name being declared, not a use of it.
+		const DECLARATION = 1 << 0;
+		/// The declared binding can't be reassigned, e.g. a `const` variable.
+		const READONLY = 1 << 1;
+		const STATIC = 1 << 2;
+		const ASYNC = 1 << 3;
+	}
+}
+
+impl TokenModifiers {
+	/// The names of the modifiers, indexed by the position of their bit.
+	pub const NAMES: [&'static str; 4] = ["declaration", "readonly", "static", "async"];
+}
+
+/// A token, or a comment, along with its classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassifiedToken {
+	/// The range of the token, excluding its trivia.
+	pub range: TextRange,
+	pub token_type: TokenType,
+	pub modifiers: TokenModifiers,
+}
+
+/// Classify all the tokens and comments of a tree, in source order.
+///
+/// If a `range` is given, only the tokens intersecting it are returned.
+///
+/// ```
+/// use rslint_parser::{classify, parse_module, TokenModifiers, TokenType};
+///
+/// let parse = parse_module("const a = b;", 0);
+/// let tokens = classify(&parse.syntax(), None);
+///
+/// assert_eq!(tokens[0].token_type, TokenType::Keyword);
+/// assert_eq!(tokens[1].token_type, TokenType::Variable);
+/// let readonly = TokenModifiers::DECLARATION | TokenModifiers::READONLY;
+/// assert_eq!(tokens[1].modifiers, readonly);
+/// assert_eq!(tokens[3].token_type, TokenType::Variable);
+/// assert!(tokens[3].modifiers.is_empty());
+/// ```
+pub fn classify(root: &SyntaxNode, range: Option<TextRange>) -> Vec<ClassifiedToken> {
+	let intersects = |token_range: TextRange| match range {
+		Some(range) => token_range.start() < range.end() && range.start() < token_range.end(),
+		None => true,
+	};
+
+	let mut tokens = Vec::new();
+	for token in root.descendants_tokens() {
+		if !intersects(token.text_range()) {
+			continue;
+		}
+
+		let comments = |trivia: rome_rowan::api::SyntaxTrivia<JsLanguage>| {
+			trivia
+				.pieces()
+				.filter_map(|piece| piece.as_comments())
+				.map(|comment| ClassifiedToken {
+					range: comment.text_range(),
+					token_type: TokenType::Comment,
+					modifiers: TokenModifiers::empty(),
+				})
+				.collect::<Vec<_>>()
+		};
+
+		tokens.extend(comments(token.leading_trivia()));
+		if let Some((token_type, modifiers)) = classify_token(&token) {
+			tokens.push(ClassifiedToken {
+				range: token.text_trimmed_range(),
+				token_type,
+				modifiers,
+			});
+		}
+		tokens.extend(comments(token.trailing_trivia()));
+	}
+
+	tokens.retain(|token| !token.range.is_empty() && intersects(token.range));
+	tokens
+}
+
+type Classification = (TokenType, TokenModifiers);
+
+fn classify_token(token: &SyntaxToken) -> Option<Classification> {
+	let kind = token.kind();
+	let token_type = match kind {
+		IDENT => return classify_ident(token),
+		JS_STRING_LITERAL | TEMPLATE_CHUNK | BACKTICK => TokenType::String,
+		JS_NUMBER_LITERAL | JS_BIG_INT_LITERAL => TokenType::Number,
+		JS_REGEX_LITERAL => TokenType::Regexp,
+		// The `#` of a private name is highlighted like the name itself
+		HASH => return classify_ident(&token.next_token()?),
+		_ if kind.is_keyword() => TokenType::Keyword,
+		L_ANGLE | R_ANGLE
+			if matches!(
+				token.parent().map(|parent| parent.kind()),
+				Some(TS_TYPE_PARAMS | TS_TYPE_ARGS)
+			) =>
+		{
+			return None
+		}
+		COLON if token.parent()?.kind() == JS_CONDITIONAL_EXPRESSION => TokenType::Operator,
+		SEMICOLON | COMMA | L_PAREN | R_PAREN | L_CURLY | R_CURLY | L_BRACK | R_BRACK | DOT
+		| COLON | AT => return None,
+		_ if kind.is_punct() => TokenType::Operator,
+		_ => return None,
+	};
+
+	Some((token_type, TokenModifiers::empty()))
+}
+
+fn classify_ident(token: &SyntaxToken) -> Option<Classification> {
+	let parent = token.parent()?;
+	match parent.kind() {
+		JS_IDENTIFIER_BINDING => Some(classify_binding(&parent)),
+		JS_REFERENCE_IDENTIFIER => Some(classify_reference(&parent)),
+		JS_IDENTIFIER_ASSIGNMENT | JS_LITERAL_EXPORT_NAME => {
+			Some((TokenType::Variable, TokenModifiers::empty()))
+		}
+		JS_NAME | JS_PRIVATE_NAME => classify_name(&parent),
+		JS_LITERAL_MEMBER_NAME | JS_PRIVATE_CLASS_MEMBER_NAME => {
+			Some(classify_member_name(&parent))
+		}
+		TS_TYPE_PARAM => Some((TokenType::TypeParameter, TokenModifiers::DECLARATION)),
+		TS_TYPE_NAME => Some((classify_type_name(token, &parent), TokenModifiers::empty())),
+		TS_ENUM_MEMBER => Some((
+			TokenType::EnumMember,
+			TokenModifiers::DECLARATION | TokenModifiers::READONLY,
+		)),
+		// Contextual keywords, e.g. `interface` or `type`, are lexed as identifiers
+		TS_INTERFACE_DECL | TS_TYPE_ALIAS_DECL | TS_NAMESPACE_DECL | TS_MODULE_DECL => {
+			Some((TokenType::Keyword, TokenModifiers::empty()))
+		}
+		TS_ANY | TS_UNKNOWN | TS_NUMBER | TS_OBJECT | TS_BOOLEAN | TS_BIGINT | TS_STRING
+		| TS_SYMBOL | TS_NEVER | TS_UNDEFINED => Some((TokenType::Type, TokenModifiers::empty())),
+		_ => None,
+	}
+}
+
+fn classify_binding(binding: &SyntaxNode) -> Classification {
+	let declaration = binding
+		.ancestors()
+		.skip(1)
+		.find(|node| !is_binding_pattern(node.kind()));
+	let declaration = match declaration {
+		Some(declaration) => declaration,
+		None => return (TokenType::Variable, TokenModifiers::DECLARATION),
+	};
+
+	match declaration.kind() {
+		JS_FUNCTION_DECLARATION | JS_FUNCTION_EXPRESSION => (
+			TokenType::Function,
+			TokenModifiers::DECLARATION | async_modifier(&declaration),
+		),
+		JS_CLASS_DECLARATION | JS_CLASS_EXPRESSION => {
+			(TokenType::Class, TokenModifiers::DECLARATION)
+		}
+		JS_PARAMETER_LIST
+		| JS_REST_PARAMETER
+		| JS_CONSTRUCTOR_PARAMETER_LIST
+		| JS_CONSTRUCTOR_PARAMETER
+		| TS_CONSTRUCTOR_PARAM
+		| JS_ARROW_FUNCTION_EXPRESSION => (TokenType::Parameter, TokenModifiers::DECLARATION),
+		JS_VARIABLE_DECLARATION if is_const(&declaration) => (
+			TokenType::Variable,
+			TokenModifiers::DECLARATION | TokenModifiers::READONLY,
+		),
+		_ => (TokenType::Variable, TokenModifiers::DECLARATION),
+	}
+}
+
+fn classify_reference(reference: &SyntaxNode) -> Classification {
+	let token_type = match reference.parent() {
+		Some(expression) if expression.kind() == JS_IDENTIFIER_EXPRESSION => {
+			match expression.parent() {
+				Some(parent) if is_callee(&parent, &expression) => TokenType::Function,
+				Some(parent) if parent.kind() == NEW_EXPR => TokenType::Class,
+				_ => TokenType::Variable,
+			}
+		}
+		_ => TokenType::Variable,
+	};
+
+	(token_type, TokenModifiers::empty())
+}
+
+fn classify_name(name: &SyntaxNode) -> Option<Classification> {
+	let parent = name.parent()?;
+	let classification = match parent.kind() {
+		JS_STATIC_MEMBER_EXPRESSION => match parent.parent() {
+			Some(call) if is_callee(&call, &parent) => (TokenType::Method, TokenModifiers::empty()),
+			_ => (TokenType::Property, TokenModifiers::empty()),
+		},
+		JS_STATIC_MEMBER_ASSIGNMENT => (TokenType::Property, TokenModifiers::empty()),
+		TS_INTERFACE_DECL => (TokenType::Interface, TokenModifiers::DECLARATION),
+		TS_ENUM => (TokenType::Enum, TokenModifiers::DECLARATION),
+		TS_TYPE_ALIAS_DECL => (TokenType::Type, TokenModifiers::DECLARATION),
+		TS_NAMESPACE_DECL | TS_MODULE_DECL => (TokenType::Namespace, TokenModifiers::DECLARATION),
+		TS_METHOD_SIGNATURE => (TokenType::Method, TokenModifiers::DECLARATION),
+		TS_PROPERTY_SIGNATURE => (TokenType::Property, TokenModifiers::DECLARATION),
+		_ => return None,
+	};
+
+	Some(classification)
+}
+
+fn classify_member_name(name: &SyntaxNode) -> Classification {
+	let member = match name.parent() {
+		Some(member) => member,
+		None => return (TokenType::Property, TokenModifiers::empty()),
+	};
+
+	let mut modifiers = TokenModifiers::DECLARATION;
+	if has_token(&member, STATIC_KW) {
+		modifiers |= TokenModifiers::STATIC;
+	}
+
+	match member.kind() {
+		JS_CONSTRUCTOR_CLASS_MEMBER => (TokenType::Keyword, TokenModifiers::empty()),
+		JS_METHOD_CLASS_MEMBER | JS_METHOD_OBJECT_MEMBER => {
+			(TokenType::Method, modifiers | async_modifier(&member))
+		}
+		JS_OBJECT_BINDING_PATTERN_PROPERTY => (TokenType::Property, TokenModifiers::empty()),
+		_ => (TokenType::Property, modifiers),
+	}
+}
+
+/// Type names are type parameters when a type parameter of an enclosing declaration has the
+/// same name, and namespaces when they qualify another name.
+fn classify_type_name(token: &SyntaxToken, name: &SyntaxNode) -> TokenType {
+	let qualified = name
+		.parent()
+		.map_or(false, |parent| parent.kind() == TS_QUALIFIED_PATH);
+	if qualified {
+		return if name.next_sibling().is_some() {
+			TokenType::Namespace
+		} else {
+			TokenType::Type
+		};
+	}
+
+	let is_type_parameter = name
+		.ancestors()
+		.flat_map(|node| node.children())
+		.filter(|node| node.kind() == TS_TYPE_PARAMS)
+		.flat_map(|params| params.descendants())
+		.filter(|param| param.kind() == TS_TYPE_PARAM)
+		.filter_map(|param| param.first_token())
+		.any(|param| param.text_trimmed() == token.text_trimmed());
+
+	if is_type_parameter {
+		TokenType::TypeParameter
+	} else {
+		TokenType::Type
+	}
+}
+
+fn is_binding_pattern(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		JS_ARRAY_BINDING_PATTERN
+			| JS_ARRAY_BINDING_PATTERN_ELEMENT_LIST
+			| JS_ARRAY_BINDING_PATTERN_REST_ELEMENT
+			| JS_BINDING_PATTERN_WITH_DEFAULT
+			| JS_OBJECT_BINDING_PATTERN
+			| JS_OBJECT_BINDING_PATTERN_PROPERTY
+			| JS_OBJECT_BINDING_PATTERN_PROPERTY_LIST
+			| JS_OBJECT_BINDING_PATTERN_REST
+			| JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+	)
+}
+
+/// Whether `node` is the expression being called by `call`.
+fn is_callee(call: &SyntaxNode, node: &SyntaxNode) -> bool {
+	call.kind() == CALL_EXPR && call.first_child().as_ref() == Some(node)
+}
+
+fn is_const(declaration: &SyntaxNode) -> bool {
+	declaration
+		.ancestors()
+		.find(|node| {
+			matches!(
+				node.kind(),
+				JS_VARIABLE_DECLARATIONS | JS_FOR_VARIABLE_DECLARATION
+			)
+		})
+		.and_then(|declarations| declarations.first_token())
+		.map_or(false, |token| token.kind() == CONST_KW)
+}
+
+fn async_modifier(node: &SyntaxNode) -> TokenModifiers {
+	if has_token(node, ASYNC_KW) {
+		TokenModifiers::ASYNC
+	} else {
+		TokenModifiers::empty()
+	}
+}
+
+fn has_token(node: &SyntaxNode, kind: SyntaxKind) -> bool {
+	node.children_with_tokens().any(|element| {
+		element
+			.as_token()
+			.map_or(false, |token| token.kind() == kind)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{parse, parse_module, Syntax};
+
+	fn classified(text: &str, syntax: Syntax) -> Vec<(String, TokenType, TokenModifiers)> {
+		classify(&parse(text, 0, syntax).syntax(), None)
+			.into_iter()
+			.map(|token| {
+				let range = std::ops::Range::<usize>::from(token.range);
+				(text[range].to_string(), token.token_type, token.modifiers)
+			})
+			.collect()
+	}
+
+	fn find(
+		tokens: &[(String, TokenType, TokenModifiers)],
+		text: &str,
+	) -> Vec<(TokenType, TokenModifiers)> {
+		tokens
+			.iter()
+			.filter(|(token, ..)| token == text)
+			.map(|(_, token_type, modifiers)| (*token_type, *modifiers))
+			.collect()
+	}
+
+	#[test]
+	fn lexical_tokens() {
+		let tokens = classified(
+			"// hi\nlet a = `x${1n}` + 'y' /* c */;",
+			Syntax::default().module(),
+		);
+		assert_eq!(
+			find(&tokens, "// hi"),
+			vec![(TokenType::Comment, TokenModifiers::empty())]
+		);
+		assert_eq!(
+			find(&tokens, "/* c */"),
+			vec![(TokenType::Comment, TokenModifiers::empty())]
+		);
+		assert_eq!(find(&tokens, "let")[0].0, TokenType::Keyword);
+		assert_eq!(find(&tokens, "x")[0].0, TokenType::String);
+		assert_eq!(find(&tokens, "1n")[0].0, TokenType::Number);
+		assert_eq!(find(&tokens, "'y'")[0].0, TokenType::String);
+		assert_eq!(find(&tokens, "+")[0].0, TokenType::Operator);
+		assert!(find(&tokens, ";").is_empty());
+	}
+
+	#[test]
+	fn declarations_and_references() {
+		let text = "import d from 'd';
+async function foo(p, ...r) { const c = /re/; let { v, w: x } = p; return foo(c).bar(v); }
+class A extends B { static m() {} #p = 1; get g() { return this.#p; } }
+new A();";
+		let tokens = classified(text, Syntax::default().module());
+
+		let declaration = TokenModifiers::DECLARATION;
+		assert_eq!(find(&tokens, "d")[0], (TokenType::Variable, declaration));
+		assert_eq!(
+			find(&tokens, "foo"),
+			vec![
+				(TokenType::Function, declaration | TokenModifiers::ASYNC),
+				(TokenType::Function, TokenModifiers::empty())
+			]
+		);
+		assert_eq!(find(&tokens, "p")[0], (TokenType::Parameter, declaration));
+		assert_eq!(find(&tokens, "r")[0], (TokenType::Parameter, declaration));
+		assert_eq!(
+			find(&tokens, "c")[0],
+			(TokenType::Variable, declaration | TokenModifiers::READONLY)
+		);
+		assert_eq!(
+			find(&tokens, "c")[1],
+			(TokenType::Variable, TokenModifiers::empty())
+		);
+		assert_eq!(find(&tokens, "/re/")[0].0, TokenType::Regexp);
+		assert_eq!(find(&tokens, "v")[0], (TokenType::Variable, declaration));
+		assert_eq!(
+			find(&tokens, "w")[0],
+			(TokenType::Property, TokenModifiers::empty())
+		);
+		assert_eq!(find(&tokens, "x")[0], (TokenType::Variable, declaration));
+		assert_eq!(
+			find(&tokens, "bar")[0],
+			(TokenType::Method, TokenModifiers::empty())
+		);
+		assert_eq!(
+			find(&tokens, "A"),
+			vec![
+				(TokenType::Class, declaration),
+				(TokenType::Class, TokenModifiers::empty())
+			]
+		);
+		assert_eq!(
+			find(&tokens, "B")[0],
+			(TokenType::Variable, TokenModifiers::empty())
+		);
+		assert_eq!(
+			find(&tokens, "m")[0],
+			(TokenType::Method, declaration | TokenModifiers::STATIC)
+		);
+		assert_eq!(
+			find(&tokens, "p")[1..],
+			[
+				(TokenType::Variable, TokenModifiers::empty()),
+				(TokenType::Property, declaration),
+				(TokenType::Property, TokenModifiers::empty())
+			]
+		);
+		assert_eq!(find(&tokens, "g")[0], (TokenType::Property, declaration));
+	}
+
+	#[test]
+	fn typescript_declarations() {
+		let text = "function id<T>(): N.Foo<T> {}
+interface I { m(): void; p: string }
+enum E { A }
+type Al = number;";
+		let tokens = classified(text, Syntax::default().typescript());
+
+		let declaration = TokenModifiers::DECLARATION;
+		assert_eq!(
+			find(&tokens, "T"),
+			vec![
+				(TokenType::TypeParameter, declaration),
+				(TokenType::TypeParameter, TokenModifiers::empty())
+			]
+		);
+		assert_eq!(find(&tokens, "N")[0].0, TokenType::Namespace);
+		assert_eq!(find(&tokens, "Foo")[0].0, TokenType::Type);
+		assert_eq!(find(&tokens, "interface")[0].0, TokenType::Keyword);
+		assert_eq!(find(&tokens, "I")[0], (TokenType::Interface, declaration));
+		assert_eq!(find(&tokens, "m")[0], (TokenType::Method, declaration));
+		assert_eq!(find(&tokens, "p")[0], (TokenType::Property, declaration));
+		assert_eq!(find(&tokens, "string")[0].0, TokenType::Type);
+		assert_eq!(find(&tokens, "E")[0], (TokenType::Enum, declaration));
+		assert_eq!(
+			find(&tokens, "A")[0],
+			(
+				TokenType::EnumMember,
+				declaration | TokenModifiers::READONLY
+			)
+		);
+		assert_eq!(find(&tokens, "Al")[0], (TokenType::Type, declaration));
+		assert_eq!(find(&tokens, "number")[0].0, TokenType::Type);
+	}
+
+	#[test]
+	fn range() {
+		let text = "let a = 1;\nlet b = 2;";
+		let root = parse_module(text, 0).syntax();
+		let tokens = classify(&root, Some(TextRange::new(11.into(), 16.into())));
+		let texts: Vec<_> = tokens
+			.iter()
+			.map(|token| &text[std::ops::Range::<usize>::from(token.range)])
+			.collect();
+		assert_eq!(texts, vec!["let", "b"]);
+	}
+}
@@ -59,6 +59,7 @@
 mod parser;
 #[macro_use]
 mod token_set;
+mod classify;
 mod event;
 mod lossless_tree_sink;
 mod lossy_tree_sink;
@@ -78,6 +79,7 @@ pub mod util;
 
 pub use crate::{
 	ast::{AstNode, AstNodeList, AstSeparatedList, AstToken, SyntaxError, SyntaxResult},
+	classify::{classify, ClassifiedToken, TokenModifiers, TokenType},
 	event::{process, Event},
 	lossless_tree_sink::LosslessTreeSink,
 	lossy_tree_sink::LossyTreeSink,
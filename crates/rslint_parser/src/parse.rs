@@ -1,6 +1,6 @@
 //! Utilities for high level parsing of js code.
 
-use crate::ast::{JsModule, JsScript};
+use crate::ast::{JsAnyRoot, JsModule, JsScript};
 use crate::{ast::JsAnyExpression, *};
 use rslint_errors::Severity;
 use std::marker::PhantomData;
@@ -231,6 +231,28 @@ pub fn parse_module(text: &str, file_id: usize) -> Parse<JsModule> {
 	Parse::new_module(green, parse_errors)
 }
 
+/// Losslessly parse text with the given [`Syntax`], the root is a [`JsScript`] for scripts and a
+/// [`JsModule`] for modules and TypeScript files.
+///
+/// ```
+/// use rslint_parser::{ast::JsModule, parse, AstNode, Syntax};
+///
+/// let parse = parse("import a from 'a';", 0, Syntax::default().module());
+/// assert!(parse.errors().is_empty());
+/// assert!(JsModule::can_cast(parse.syntax().kind()));
+/// ```
+pub fn parse(text: &str, file_id: usize, syntax: Syntax) -> Parse<JsAnyRoot> {
+	let (events, errors, tokens) = parse_common(text, file_id, syntax);
+	let mut tree_sink = LosslessTreeSink::new(text, &tokens);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, parse_errors) = tree_sink.finish();
+	let source_type = match syntax.file_kind {
+		FileKind::Script => JsSourceType::Script,
+		FileKind::Module | FileKind::TypeScript => JsSourceType::Module,
+	};
+	Parse::new(green, parse_errors, source_type)
+}
+
 /// Losslessly Parse text into an expression [`Parse`](Parse) which can then be turned into an untyped root [`SyntaxNode`](SyntaxNode).
 /// Or turned into a typed [`Expr`](Expr) with [`tree`](Parse::tree).
 pub fn parse_expr(text: &str, file_id: usize) -> Parse<JsAnyExpression> {
@@ -387,7 +387,6 @@ fn parse_class_member(p: &mut Parser) -> ParsedSyntax<ConditionalSyntax> {
 		member_is_valid = false
 	}
 
-	let accessibility_marker = p.start();
 	let (abstract_range, readonly_range) = abstract_readonly_modifiers(p);
 	let has_modifier = abstract_range
 		.clone()
@@ -411,9 +410,7 @@ fn parse_class_member(p: &mut Parser) -> ParsedSyntax<ConditionalSyntax> {
 			p.error(err);
 			member_is_valid = false;
 		}
-		accessibility_marker.complete(p, TS_ACCESSIBILITY);
 	} else {
-		accessibility_marker.abandon(p);
 		p.missing();
 	}
 
@@ -721,37 +718,34 @@ fn parse_property_class_member_body(
 	p: &mut Parser,
 	member_marker: Marker,
 ) -> ParsedSyntax<ConditionalSyntax> {
-	let parsed_syntax = optional_member_token(p);
-	let mut property_is_valid = if let Ok(optional_range) = parsed_syntax {
-		if p.at(T![!]) {
-			let range = p.cur_tok().range;
+	let optional_member = optional_member_token(p);
+	let mut property_is_valid = optional_member.is_err() || p.typescript();
 
+	if p.at(T![!]) {
+		let range = p.cur_tok().range;
+
+		if let Ok(optional_range) = optional_member {
 			let error = p
 				.err_builder("class properties cannot be both optional and definite")
 				.primary(range, "")
 				.secondary(optional_range, "");
 
 			p.error(error);
-			p.bump_any(); // Bump ! token
-			false
-		} else {
-			false
-		}
-	} else {
-		true
-	};
+			property_is_valid = false;
+		} else if !p.typescript() {
+			// test_err class_member_bang
+			// class B { foo!; }
+			let error = p
+				.err_builder("definite assignment assertions can only be used in TypeScript files")
+				.primary(range, "");
 
-	// test_err class_member_bang
-	// class B { foo!; }
-	if p.at(T![!]) {
-		let range = p.cur_tok().range;
-		let error = p
-			.err_builder("definite assignment assertions can only be used in TypeScript files")
-			.primary(range, "");
+			p.error(error);
+			property_is_valid = false;
+		}
 
-		p.error(error);
 		p.bump_any(); // Bump ! token
-		property_is_valid = false;
+	} else {
+		p.missing();
 	}
 
 	maybe_ts_type_annotation(p);
@@ -931,7 +925,6 @@ fn parse_constructor_parameter(p: &mut Parser) -> ParsedSyntax<CompletedMarker>
 				.primary(range, "");
 
 			p.error(err);
-			p.bump_any();
 		}
 		true
 	} else {
@@ -942,9 +935,7 @@ fn parse_constructor_parameter(p: &mut Parser) -> ParsedSyntax<CompletedMarker>
 		modifiers_marker.abandon(p);
 		parse_formal_param_pat(p)
 	} else {
-		if let Present(ref mut pat) = parse_formal_param_pat(p) {
-			pat.undo_completion(p).abandon(p);
-		}
+		parse_formal_param_pat(p).or_missing_with_error(p, js_parse_error::expected_binding);
 		Present(modifiers_marker.complete(p, TS_CONSTRUCTOR_PARAM))
 	}
 }
@@ -1025,6 +1016,7 @@ impl Modifiers {
 	pub fn consume(self, p: &mut Parser) -> bool {
 		let mut member_is_valid = true;
 		if self.accessibility {
+			let accessibility = p.start();
 			let kind = match p.cur_src() {
 				"public" => PUBLIC_KW,
 				"private" => PRIVATE_KW,
@@ -1041,9 +1033,11 @@ impl Modifiers {
 
 				p.error(err);
 				p.bump_any();
+				accessibility.abandon(p);
 				member_is_valid = false;
 			} else {
 				p.bump_remap(kind);
+				accessibility.complete(p, TS_ACCESSIBILITY);
 			}
 		} else {
 			p.missing();
@@ -7,6 +7,7 @@ use super::typescript::*;
 use crate::parser::ParsedSyntax::{Absent, Present};
 use crate::parser::ParserProgress;
 use crate::syntax::binding::parse_binding_pattern_with_optional_default;
+use crate::syntax::class::parse_initializer_clause;
 use crate::syntax::function::function_body;
 use crate::syntax::js_parse_error;
 use crate::syntax::js_parse_error::expected_binding;
@@ -24,7 +25,44 @@ pub(super) fn parse_formal_param_pat(p: &mut Parser) -> ParsedSyntax<CompletedMa
 		}
 	}
 
-	parse_binding_pattern_with_optional_default(p)
+	if p.typescript() {
+		parse_ts_typed_parameter(p)
+	} else {
+		parse_binding_pattern_with_optional_default(p)
+	}
+}
+
+/// Parses a parameter with an optional `?` marker, type annotation and default value.
+/// Parameters without any of the TypeScript specific syntax are parsed as plain binding patterns.
+fn parse_ts_typed_parameter(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	let binding = parse_binding_pattern(p);
+
+	if !p.at(T![?]) && !p.at(T![:]) {
+		return if p.at(T![=]) {
+			let m = binding.precede_or_missing_with_error(p, expected_binding);
+			p.bump_any();
+			if expr_or_assignment(p).is_none() {
+				p.missing();
+			}
+			Present(m.complete(p, JS_BINDING_PATTERN_WITH_DEFAULT))
+		} else {
+			binding
+		};
+	}
+
+	let m = binding.precede_or_missing_with_error(p, expected_binding);
+
+	if !p.eat(T![?]) {
+		p.missing();
+	}
+
+	if maybe_ts_type_annotation(p).is_none() {
+		p.missing();
+	}
+
+	parse_initializer_clause(p).or_missing(p);
+
+	Present(m.complete(p, TS_TYPED_PARAMETER))
 }
 
 // test parameter_list
@@ -84,12 +122,7 @@ pub(super) fn parse_parameters_list(
 			}
 
 			// type annotation `...foo: number[]`
-			if p.eat(T![:]) {
-				let complete = ts_type(p);
-				if let Some(mut res) = complete {
-					res.err_if_not_ts(p, "type annotations can only be used in TypeScript files");
-				}
-			}
+			maybe_ts_type_annotation(p);
 
 			if p.at(T![=]) {
 				let start = p.cur_tok().range.start;
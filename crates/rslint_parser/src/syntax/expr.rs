@@ -467,7 +467,6 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 	// test_err subscripts_err
 	// foo()?.baz[].
 	// BAR`b
-	let mut should_try_parsing_ts = true;
 	let mut progress = ParserProgress::default();
 	while !p.at(EOF) {
 		progress.assert_progressing(p);
@@ -510,7 +509,7 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 					comp
 				}
 			}
-			T![<] if p.typescript() && should_try_parsing_ts => {
+			T![<] if p.typescript() => {
 				let res = try_parse_ts(p, |p| {
 					let m = lhs.precede(p);
 					// TODO: handle generic async arrow function expressions
@@ -526,11 +525,14 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 						m.abandon(p);
 						Some(template(p, Some(lhs)))
 					} else {
+						m.abandon(p);
 						None
 					}
 				});
-				if res.is_none() {
-					should_try_parsing_ts = false;
+				match res {
+					// not type arguments, let the caller handle the `<`
+					None => return lhs,
+					Some(res) => lhs = res,
 				}
 			}
 			BACKTICK => lhs = template(p, Some(lhs)),
@@ -821,13 +823,14 @@ pub fn paren_or_arrow_expr(p: &mut Parser, can_be_arrow: bool) -> CompletedMarke
 			}
 
 			if p.at(T![:]) {
-				let complete = ts_type_or_type_predicate_ann(p, T![:]);
-				if let Some(mut complete) = complete {
+				let return_type = p.start();
+				if let Some(mut complete) = ts_type_or_type_predicate_ann(p, T![:]) {
 					complete.err_if_not_ts(
 						p,
 						"arrow functions can only have return types in TypeScript files",
 					);
 				}
+				return_type.complete(p, TS_TYPE_ANNOTATION);
 			} else {
 				p.missing();
 			}
@@ -961,13 +964,16 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 						}
 
 						if in_async_p.at(T![:]) {
-							let complete = ts_type_or_type_predicate_ann(in_async_p, T![:]);
-							if let Some(mut complete) = complete {
+							let return_type = in_async_p.start();
+							if let Some(mut complete) =
+								ts_type_or_type_predicate_ann(in_async_p, T![:])
+							{
 								complete.err_if_not_ts(
 									in_async_p,
-								"arrow functions can only have return types in TypeScript files",
-							);
+									"arrow functions can only have return types in TypeScript files",
+								);
 							}
+							return_type.complete(in_async_p, TS_TYPE_ANNOTATION);
 						} else {
 							in_async_p.missing(); // return type annotation
 						}
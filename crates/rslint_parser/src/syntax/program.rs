@@ -198,6 +198,13 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			p.bump_remap(T![as]);
 			identifier_name(p);
 			exports_ns = true;
+
+			// test export_namespace
+			// export * as ns from "ns";
+			if p.cur_src() == "from" {
+				from_clause_and_semi(p, start);
+				return m.complete(p, EXPORT_WILDCARD);
+			}
 		}
 	}
 
@@ -492,9 +492,16 @@ pub fn ts_type_member(p: &mut Parser) -> Option<CompletedMarker> {
 }
 
 fn ts_property_or_method_sig(p: &mut Parser, m: Marker, readonly: bool) -> Option<CompletedMarker> {
-	if p.eat(T!['[']) {
+	if p.at(T!['[']) {
+		let computed = p.start();
+		p.bump_any();
 		expr_or_assignment(p);
-		p.expect_no_recover(T![']'])?;
+		if p.expect_no_recover(T![']']).is_none() {
+			computed.abandon(p);
+			m.abandon(p);
+			return None;
+		}
+		computed.complete(p, JS_COMPUTED_MEMBER_NAME);
 	} else {
 		match p.cur() {
 			JS_STRING_LITERAL | JS_NUMBER_LITERAL => {
@@ -550,17 +557,20 @@ pub(crate) fn try_parse_index_signature(
 		return Err(m);
 	}
 
-	let pat_m = parse_binding(p).unwrap().undo_completion(p);
+	parse_binding(p).unwrap();
 
+	let type_annotation = p.start();
 	if p.expect_no_recover(T![:]).is_none() {
+		type_annotation.abandon(p);
 		return Err(m);
 	}
 
 	if ts_type(p).is_none() && p.state.no_recovery {
+		type_annotation.abandon(p);
 		return Err(m);
 	}
 
-	pat_m.complete(p, JS_IDENTIFIER_BINDING);
+	type_annotation.complete(p, TS_TYPE_ANNOTATION);
 
 	if p.expect_no_recover(T![']']).is_none() {
 		return Err(m);
@@ -847,21 +857,21 @@ fn intersection_or_union(
 	mut constituent: impl FnMut(&mut Parser) -> Option<CompletedMarker>,
 	op: SyntaxKind,
 ) -> Option<CompletedMarker> {
-	let kind = if intersection {
-		TS_INTERSECTION
+	let (kind, list_kind) = if intersection {
+		(TS_INTERSECTION, TS_INTERSECTION_TYPE_ELEMENT_LIST)
 	} else {
-		TS_UNION
+		(TS_UNION, TS_UNION_TYPE_VARIANT_LIST)
 	};
 	let m = p.start();
-	let types_list = p.start();
 	let saw_op = p.eat(op);
+	let types_list = p.start();
 	let ty = constituent(p);
 	if p.at(op) {
 		while p.eat(op) {
 			constituent(p);
 		}
 
-		types_list.complete(p, TS_TYPE_LIST);
+		types_list.complete(p, list_kind);
 		Some(m.complete(p, kind))
 	} else if !saw_op && ty.is_none() {
 		types_list.abandon(p);
@@ -872,7 +882,7 @@ fn intersection_or_union(
 		m.abandon(p);
 		ty
 	} else {
-		types_list.complete(p, TS_TYPE_LIST);
+		types_list.complete(p, list_kind);
 		Some(m.complete(p, kind))
 	}
 }
@@ -1355,7 +1365,7 @@ fn is_mapped_type_start(p: &Parser) -> bool {
 		return true;
 	}
 	let mut cur = 1;
-	if p.cur_src() == "readonly" {
+	if p.nth_src(1) == "readonly" {
 		cur += 1;
 	}
 	if !p.nth_at(cur, T!['[']) {
@@ -347,7 +347,9 @@ pub enum SyntaxKind {
 	TS_INDEXED_ARRAY,
 	TS_TYPE_OPERATOR,
 	TS_INTERSECTION,
+	TS_INTERSECTION_TYPE_ELEMENT_LIST,
 	TS_UNION,
+	TS_UNION_TYPE_VARIANT_LIST,
 	TS_TYPE_PARAM_LIST,
 	TS_TYPE_PARAMS,
 	TS_FN_TYPE,
@@ -370,6 +372,7 @@ pub enum SyntaxKind {
 	TS_MODULE_BLOCK,
 	TS_MODULE_DECL,
 	TS_CONSTRUCTOR_PARAM,
+	TS_TYPED_PARAMETER,
 	TS_CALL_SIGNATURE_DECL,
 	TS_CONSTRUCT_SIGNATURE_DECL,
 	TS_INDEX_SIGNATURE,